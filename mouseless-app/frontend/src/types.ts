@@ -25,7 +25,7 @@ export interface PredictionTarget {
   //TODO: Add user interaction history for this target
 }
 
-export type AnimationStyle = 'Instant' | 'Linear' | 'Smooth' | 'Bounce'
+export type AnimationStyle = 'Instant' | 'Linear' | 'Smooth' | 'Bounce' | 'Elastic'
 
 export type TargetType = 'Button' | 'Link' | 'TextField' | 'MenuItem' | 'Icon' | { Custom: string }
 
@@ -1,4 +1,4 @@
-use mouseless_core::{init, AppInfo, MouseService, Result};
+use mouseless_core::{init, AppInfo, MouseService, Result, ScreenManager, ScreenWatcher};
 use std::sync::{Arc, Mutex};
 use tauri::{
     menu::{Menu, MenuItem, PredefinedMenuItem},
@@ -83,6 +83,11 @@ fn setup_app(app: &mut App) -> std::result::Result<(), Box<dyn std::error::Error
     app.manage(mouse_service);
     info!("✅ Mouse Service initialized and managed");
 
+    // Watch for display hotplug and resolution changes
+    info!("🖥️ Watching screen configuration...");
+    app.manage(ScreenWatcher::start(ScreenWatcher::DEFAULT_INTERVAL));
+    spawn_screen_change_listener(app_handle.clone());
+
     // Hide the main window initially (it will be shown via system tray)
     if let Some(main_window) = app.get_webview_window("main") {
         main_window.hide()?;
@@ -122,6 +127,40 @@ fn setup_app(app: &mut App) -> std::result::Result<(), Box<dyn std::error::Error
     Ok(())
 }
 
+/// Re-layout overlays whenever the connected screens change
+fn spawn_screen_change_listener(app_handle: AppHandle) {
+    let mut screen_events = ScreenManager::subscribe();
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let event = match screen_events.recv().await {
+                Ok(event) => event,
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            };
+            let ui_manager_state = app_handle.state::<Arc<Mutex<Option<UIManager>>>>();
+
+            // Take the UI manager out of the state to avoid holding the lock across await
+            let ui_manager = match ui_manager_state.lock() {
+                Ok(mut guard) => guard.take(),
+                Err(e) => {
+                    warn!("⚠️ Failed to lock UI manager: {}", e);
+                    continue;
+                }
+            };
+
+            if let Some(mut ui_manager) = ui_manager {
+                if let Err(e) = ui_manager.handle_screen_change(&event).await {
+                    warn!("⚠️ Failed to apply screen change: {}", e);
+                }
+                if let Ok(mut guard) = ui_manager_state.lock() {
+                    *guard = Some(ui_manager);
+                }
+            }
+        }
+    });
+}
+
 fn handle_tray_event(app: &AppHandle, event: TrayIconEvent) {
     match event {
         TrayIconEvent::Click {
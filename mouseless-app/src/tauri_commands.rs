@@ -1,4 +1,4 @@
-use mouseless_core::{GridConfig, PredictionTarget, AnimationType, MouseService};
+use mouseless_core::{GridConfig, LabelPriority, PredictionTarget, AnimationType, MouseService};
 use tauri::{AppHandle, State};
 use tracing::{debug, error, info};
 use std::sync::{Arc, Mutex};
@@ -29,6 +29,8 @@ pub async fn show_grid_overlay(
         cell_padding: cell_padding.unwrap_or(2),
         border_width: border_width.unwrap_or(1),
         opacity: opacity.unwrap_or(0.8),
+        label_alphabet: None,
+        label_priority: LabelPriority::RowMajor,
     };
     
     info!("📋 Grid config created: {:?}", grid_config);
@@ -377,7 +379,7 @@ pub async fn move_mouse_to_position(
         .map(|_| info!("🎉 Mouse moved successfully to ({}, {})", x, y))
         .map_err(|e| {
             error!("❌ Failed to move mouse: {}", e);
-            e
+            e.to_string()
         })
 }
 
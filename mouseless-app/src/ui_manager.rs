@@ -1,6 +1,6 @@
 use mouseless_core::{
     GridConfig, GridManager, ModeManager, MouselessError, Position, PredictionTarget, Result,
-    ScreenBounds,
+    ScreenChangeEvent, ScreenManager,
 };
 use serde_json::json;
 use std::collections::HashMap;
@@ -13,6 +13,7 @@ pub struct UIManager {
     app_handle: AppHandle,
     overlay_windows: HashMap<String, WebviewWindow>,
     current_grid_manager: Option<GridManager>,
+    current_grid_config: Option<GridConfig>,
     mode_manager: Option<Arc<std::sync::Mutex<ModeManager>>>,
 }
 
@@ -24,6 +25,7 @@ impl UIManager {
             app_handle,
             overlay_windows: HashMap::new(),
             current_grid_manager: None,
+            current_grid_config: None,
             mode_manager: None,
         };
 
@@ -46,17 +48,8 @@ impl UIManager {
         // Close existing grid overlay if it exists
         self.hide_overlay("grid").await?;
 
-        //TODO: Get actual screen dimensions from ScreenManager
         //TODO: Support multi-monitor grid overlays
-        //TODO: Handle screen resolution changes dynamically
-        let screen_bounds = ScreenBounds {
-            id: 1,
-            x: 0,
-            y: 0,
-            width: 1920,  //TODO: Get from actual screen detection
-            height: 1080, //TODO: Get from actual screen detection
-            is_primary: true,
-        };
+        let screen_bounds = ScreenManager::get_primary_screen()?;
 
         // Create grid manager with the configuration
         let grid_manager = GridManager::new(grid_config.clone(), screen_bounds.clone())?;
@@ -129,6 +122,7 @@ impl UIManager {
         }
 
         self.current_grid_manager = Some(grid_manager);
+        self.current_grid_config = Some(grid_config);
         self.overlay_windows.insert("grid".to_string(), window);
         Ok(())
     }
@@ -192,17 +186,8 @@ impl UIManager {
         // Close existing area overlay if it exists
         self.hide_overlay("area").await?;
 
-        //TODO: Get actual screen dimensions from ScreenManager
         //TODO: Support multi-monitor area overlays
-        //TODO: Handle dynamic screen configuration changes
-        let screen_bounds = ScreenBounds {
-            id: 0,
-            x: 0,
-            y: 0,
-            width: 1920,  //TODO: Get from actual screen detection
-            height: 1080, //TODO: Get from actual screen detection
-            is_primary: true,
-        };
+        let screen_bounds = ScreenManager::get_primary_screen()?;
         let screen_width = screen_bounds.width as f64;
         let screen_height = screen_bounds.height as f64;
 
@@ -420,10 +405,39 @@ impl UIManager {
 
     /// Get the current screen dimensions for overlay sizing
     pub fn get_screen_dimensions(&self) -> Result<(f64, f64)> {
-        //TODO: Integrate with ScreenManager to get actual screen dimensions
-        //TODO: Support multi-monitor setups with proper screen detection
-        //TODO: Handle screen resolution changes dynamically
-        Ok((1920.0, 1080.0))
+        //TODO: Support multi-monitor setups with one overlay per screen
+        let screen = ScreenManager::get_primary_screen()?;
+        Ok((screen.width as f64, screen.height as f64))
+    }
+
+    /// Recompute the grid and re-layout open overlays after the screens changed
+    pub async fn handle_screen_change(&mut self, event: &ScreenChangeEvent) -> Result<()> {
+        info!(
+            "🖥️ Screens changed: {} added, {} removed, {} reconfigured",
+            event.added().len(),
+            event.removed().len(),
+            event.reconfigured().len()
+        );
+
+        if self.overlay_windows.contains_key("grid") {
+            if let Some(grid_config) = self.current_grid_config.clone() {
+                self.show_grid_overlay(grid_config).await?;
+            }
+        } else if let Some(grid_manager) = &mut self.current_grid_manager {
+            grid_manager.update_screen_bounds(ScreenManager::get_primary_screen()?)?;
+
+            if let Some(mode_manager) = &self.mode_manager {
+                if let Ok(mode_mgr) = mode_manager.lock() {
+                    mode_mgr.set_grid_manager(Some(grid_manager.clone()));
+                }
+            }
+        }
+
+        if self.overlay_windows.contains_key("area") {
+            self.show_area_overlay().await?;
+        }
+
+        Ok(())
     }
 
     /// Animate cursor movement with smooth transitions
@@ -548,12 +562,10 @@ impl UIManager {
         y: i32, 
         mouse_service: &mouseless_core::MouseService
     ) -> Result<()> {
-        mouse_service.move_to_position(x, y).await.map_err(|e| {
-            MouselessError::SystemError(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                e,
-            ))
-        })
+        mouse_service
+            .move_to_position(x, y)
+            .await
+            .map_err(MouselessError::from)
     }
 
     /// Move mouse to grid cell by key combination
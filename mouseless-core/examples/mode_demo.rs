@@ -78,7 +78,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  Active: {}", manager.is_active());
     println!("  Current Mode: {:?}", manager.get_current_mode());
     println!("  History: {:?}", manager.get_mode_history());
-    println!("  Movement Speed: {:.1}", manager.get_movement_speed());
+    println!("  Movement Speed: {:?}", manager.get_speed());
     println!("  Holding: {}", manager.is_holding());
 
     // Test mode switching
@@ -492,7 +492,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         controller.set_movement_speed(*speed);
         println!("Relative move with {:?} speed (right 60, down 30)", speed);
         let start_time = std::time::Instant::now();
-        controller.move_relative(60, 30)?;
+        controller.move_relative(60, 30, AnimationType::Smooth)?;
         println!("  Completed in: {}ms", start_time.elapsed().as_millis());
         thread::sleep(Duration::from_millis(300));
     }
@@ -1,4 +1,7 @@
-use crate::models::{AnimationType, MovementSpeed, Position};
+use crate::models::{
+    AnimationType, CubicBezierEasing, MovementSpeed, PathConfig, PathStyle, Position,
+};
+use std::sync::Arc;
 use std::time::Instant;
 
 /// Easing functions for smooth animations
@@ -56,6 +59,11 @@ impl EasingFunctions {
         }
     }
 
+    /// Minimum-jerk profile, the speed curve of natural hand movements
+    pub fn minimum_jerk(t: f32) -> f32 {
+        t * t * t * (10.0 - 15.0 * t + 6.0 * t * t)
+    }
+
     /// Get easing function for animation type
     pub fn get_easing_function(animation_type: AnimationType) -> fn(f32) -> f32 {
         match animation_type {
@@ -63,7 +71,289 @@ impl EasingFunctions {
             AnimationType::Linear => Self::linear,
             AnimationType::Smooth => Self::ease_out_cubic,
             AnimationType::Bounce => Self::ease_out_bounce,
+            AnimationType::Elastic => Self::ease_out_elastic,
+        }
+    }
+}
+
+impl CubicBezierEasing {
+    /// CSS `ease-in-out`
+    pub const EASE_IN_OUT: Self = Self {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 0.58,
+        y2: 1.0,
+    };
+
+    /// Evaluate the curve at time `t`
+    pub fn evaluate(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 || t == 1.0 {
+            return t;
+        }
+
+        // Find the curve parameter whose x is `t`, then return its y
+        let s = self.solve_x(t as f64);
+        Self::bezier(s, self.y1 as f64, self.y2 as f64) as f32
+    }
+
+    fn bezier(s: f64, p1: f64, p2: f64) -> f64 {
+        let inv = 1.0 - s;
+        3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+    }
+
+    fn solve_x(&self, x: f64) -> f64 {
+        let (x1, x2) = (self.x1 as f64, self.x2 as f64);
+
+        // Newton's method converges quickly for most curves
+        let mut s = x;
+        for _ in 0..8 {
+            let error = Self::bezier(s, x1, x2) - x;
+            if error.abs() < 1e-6 {
+                return s;
+            }
+            let slope = 3.0 * (1.0 - s) * (1.0 - s) * x1
+                + 6.0 * (1.0 - s) * s * (x2 - x1)
+                + 3.0 * s * s * (1.0 - x2);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            s = (s - error / slope).clamp(0.0, 1.0);
+        }
+
+        // Fall back to bisection, x is monotonic for x1 and x2 within [0, 1]
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..32 {
+            s = (low + high) / 2.0;
+            if Self::bezier(s, x1, x2) < x {
+                low = s;
+            } else {
+                high = s;
+            }
+        }
+        s
+    }
+}
+
+/// Easing curve applied to animation progress
+#[derive(Debug, Clone, Copy)]
+pub enum Easing {
+    Function(fn(f32) -> f32),
+    CubicBezier(CubicBezierEasing),
+}
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Function(function) => function(t),
+            Easing::CubicBezier(curve) => curve.evaluate(t),
+        }
+    }
+}
+
+/// Distance in pixels of a movement that takes exactly the base duration
+pub const REFERENCE_DISTANCE: f64 = 500.0;
+
+/// Generator for the shape of a cursor path
+pub trait PathGenerator: Send + Sync {
+    /// Point at eased progress `t` (0.0 to 1.0) on the way from `from` to `to`
+    fn point_at(&self, from: (f64, f64), to: (f64, f64), t: f64) -> (f64, f64);
+
+    /// Easing the path is traversed with, instead of the animation type's
+    fn easing(&self) -> Option<Easing> {
+        None
+    }
+
+    /// Duration of a movement over `distance` pixels
+    ///
+    /// `base_ms` is the duration of a movement over [`REFERENCE_DISTANCE`].
+    /// By default the duration grows with the square root of the distance, so
+    /// short nudges are quick and long jumps do not drag on.
+    fn duration_ms(&self, base_ms: u64, distance: f64) -> u64 {
+        (base_ms as f64 * (distance / REFERENCE_DISTANCE).sqrt()).round() as u64
+    }
+}
+
+/// Map a point from the path's local frame to screen coordinates
+///
+/// In the local frame the start is (0, 0), the end is (1, 0) and the second
+/// axis points sideways, scaled by the distance.
+fn from_local(from: (f64, f64), to: (f64, f64), local: (f64, f64)) -> (f64, f64) {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    (
+        from.0 + dx * local.0 - dy * local.1,
+        from.1 + dy * local.0 + dx * local.1,
+    )
+}
+
+/// Straight line between start and end
+pub struct StraightPath;
+
+impl PathGenerator for StraightPath {
+    fn point_at(&self, from: (f64, f64), to: (f64, f64), t: f64) -> (f64, f64) {
+        from_local(from, to, (t, 0.0))
+    }
+}
+
+/// Cubic Bézier curve with control points relative to the straight line
+pub struct BezierPath {
+    pub c1: (f64, f64),
+    pub c2: (f64, f64),
+}
+
+impl PathGenerator for BezierPath {
+    fn point_at(&self, from: (f64, f64), to: (f64, f64), t: f64) -> (f64, f64) {
+        let inv = 1.0 - t;
+        let (a, b, c) = (3.0 * inv * inv * t, 3.0 * inv * t * t, t * t * t);
+        from_local(
+            from,
+            to,
+            (
+                a * self.c1.0 + b * self.c2.0 + c,
+                a * self.c1.1 + b * self.c2.1,
+            ),
+        )
+    }
+}
+
+/// Circular arc whose height is a fraction of the distance
+pub struct ArcPath {
+    pub bulge: f64,
+}
+
+impl PathGenerator for ArcPath {
+    fn point_at(&self, from: (f64, f64), to: (f64, f64), t: f64) -> (f64, f64) {
+        // Half the angle the arc spans; the height over half the chord is tan(angle / 2)
+        let half_angle = 2.0 * (2.0 * self.bulge).atan();
+        if half_angle.abs() < 1e-6 {
+            return StraightPath.point_at(from, to, t);
         }
+
+        let radius = 0.5 / half_angle.sin();
+        let center_y = -radius * half_angle.cos();
+        let angle = half_angle * (2.0 * t - 1.0);
+        from_local(
+            from,
+            to,
+            (0.5 + radius * angle.sin(), center_y + radius * angle.cos()),
+        )
+    }
+}
+
+/// Straight line with a minimum-jerk speed profile
+///
+/// The duration follows Fitts' law: it grows with the logarithm of the
+/// distance, relative to a movement of [`REFERENCE_DISTANCE`].
+pub struct MinimumJerkPath;
+
+impl MinimumJerkPath {
+    /// Target width in pixels used for the index of difficulty
+    pub const TARGET_WIDTH: f64 = 32.0;
+
+    fn index_of_difficulty(distance: f64) -> f64 {
+        (distance / Self::TARGET_WIDTH + 1.0).log2()
+    }
+}
+
+impl PathGenerator for MinimumJerkPath {
+    fn point_at(&self, from: (f64, f64), to: (f64, f64), t: f64) -> (f64, f64) {
+        StraightPath.point_at(from, to, t)
+    }
+
+    fn easing(&self) -> Option<Easing> {
+        Some(Easing::Function(EasingFunctions::minimum_jerk))
+    }
+
+    fn duration_ms(&self, base_ms: u64, distance: f64) -> u64 {
+        let scale =
+            Self::index_of_difficulty(distance) / Self::index_of_difficulty(REFERENCE_DISTANCE);
+        (base_ms as f64 * scale.clamp(0.25, 2.0)).round() as u64
+    }
+}
+
+impl PathStyle {
+    /// Create the generator for this path style
+    pub fn generator(&self) -> Arc<dyn PathGenerator> {
+        match *self {
+            PathStyle::Straight => Arc::new(StraightPath),
+            PathStyle::Bezier { c1, c2 } => Arc::new(BezierPath {
+                c1: (c1.0 as f64, c1.1 as f64),
+                c2: (c2.0 as f64, c2.1 as f64),
+            }),
+            PathStyle::Arc { bulge } => Arc::new(ArcPath {
+                bulge: bulge as f64,
+            }),
+            PathStyle::MinimumJerk => Arc::new(MinimumJerkPath),
+        }
+    }
+}
+
+impl PathConfig {
+    /// Resolve the easing for an animation type
+    ///
+    /// An explicit cubic-bezier curve wins over the path's own easing, which
+    /// wins over the animation type's.
+    pub fn easing_for(&self, animation_type: AnimationType) -> Easing {
+        if let Some(curve) = self.easing {
+            return Easing::CubicBezier(curve);
+        }
+        self.style.generator().easing().unwrap_or(Easing::Function(
+            EasingFunctions::get_easing_function(animation_type),
+        ))
+    }
+}
+
+/// Bounds and rates used to time animations by distance
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationTiming {
+    /// Duration of a movement over [`REFERENCE_DISTANCE`] at normal speed
+    pub base_duration_ms: u64,
+    /// Shortest animation
+    pub min_duration_ms: u64,
+    /// Longest animation
+    pub max_duration_ms: u64,
+    /// Display refresh rate, one animation step per frame
+    pub refresh_rate_hz: u32,
+    /// Movements shorter than this many pixels are instant
+    pub instant_distance_px: f64,
+    /// Fast speed multiplier, as in the movement configuration
+    pub fast_speed_multiplier: f32,
+    /// Slow speed multiplier, as in the movement configuration
+    pub slow_speed_multiplier: f32,
+    /// Precision speed multiplier, as in the movement configuration
+    pub precision_speed_multiplier: f32,
+}
+
+impl Default for AnimationTiming {
+    fn default() -> Self {
+        Self {
+            base_duration_ms: 200,
+            min_duration_ms: 40,
+            max_duration_ms: 400,
+            refresh_rate_hz: 60,
+            instant_distance_px: 3.0,
+            fast_speed_multiplier: 3.0,
+            slow_speed_multiplier: 0.5,
+            precision_speed_multiplier: 0.1,
+        }
+    }
+}
+
+impl AnimationTiming {
+    /// Duration multiplier for a movement speed, the inverse of its speed multiplier
+    pub fn speed_factor(&self, speed: MovementSpeed) -> f32 {
+        let multiplier = match speed {
+            MovementSpeed::Precision => self.precision_speed_multiplier,
+            MovementSpeed::Slow => self.slow_speed_multiplier,
+            MovementSpeed::Normal => 1.0,
+            MovementSpeed::Fast => self.fast_speed_multiplier,
+        };
+        1.0 / multiplier.max(f32::EPSILON)
+    }
+
+    /// Time between two animation frames
+    pub fn frame_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_micros(1_000_000 / self.refresh_rate_hz.max(1) as u64)
     }
 }
 
@@ -79,9 +369,10 @@ impl AnimationConfig {
     /// Create animation config based on speed and type
     pub fn new(speed: MovementSpeed, animation_type: AnimationType) -> Self {
         let (duration_ms, steps) = match speed {
-            MovementSpeed::Slow => (300, 30), // Slower, more steps for smoothness
-            MovementSpeed::Normal => (150, 20), // Balanced
-            MovementSpeed::Fast => (80, 15),  // Faster, fewer steps for responsiveness
+            MovementSpeed::Precision => (400, 40), // Slowest, for fine adjustments
+            MovementSpeed::Slow => (300, 30),      // Slower, more steps for smoothness
+            MovementSpeed::Normal => (150, 20),    // Balanced
+            MovementSpeed::Fast => (80, 15),       // Faster, fewer steps for responsiveness
         };
 
         // For instant animation, use minimal duration and steps
@@ -98,6 +389,37 @@ impl AnimationConfig {
         }
     }
 
+    /// Create animation config for a movement over `distance` pixels
+    ///
+    /// The duration scales with the distance along `path`, is clamped to the
+    /// timing bounds and is split into one step per display frame. Movements
+    /// shorter than the instant threshold skip the animation.
+    pub fn for_movement(
+        speed: MovementSpeed,
+        animation_type: AnimationType,
+        distance: f64,
+        timing: &AnimationTiming,
+        path: &dyn PathGenerator,
+    ) -> Self {
+        if animation_type == AnimationType::Instant || distance < timing.instant_distance_px {
+            return Self::new(speed, AnimationType::Instant);
+        }
+
+        let base_ms = (timing.base_duration_ms as f32 * timing.speed_factor(speed)).round() as u64;
+        let duration_ms = path.duration_ms(base_ms, distance).clamp(
+            timing.min_duration_ms,
+            timing.max_duration_ms.max(timing.min_duration_ms),
+        );
+        let steps =
+            ((duration_ms as f64 * timing.refresh_rate_hz as f64 / 1000.0).ceil() as u32).max(1);
+
+        Self {
+            duration_ms,
+            steps,
+            easing_function: EasingFunctions::get_easing_function(animation_type),
+        }
+    }
+
     /// Calculate step delay in milliseconds
     pub fn step_delay_ms(&self) -> u64 {
         if self.steps <= 1 {
@@ -112,7 +434,11 @@ impl AnimationConfig {
 pub struct AnimationInterpolator {
     start_position: Position,
     end_position: Position,
+    speed: MovementSpeed,
+    animation_type: AnimationType,
     config: AnimationConfig,
+    path: Arc<dyn PathGenerator>,
+    easing: Easing,
     start_time: Instant,
 }
 
@@ -124,14 +450,36 @@ impl AnimationInterpolator {
         speed: MovementSpeed,
         animation_type: AnimationType,
     ) -> Self {
+        let config = AnimationConfig::new(speed, animation_type);
+
         Self {
             start_position: start,
             end_position: end,
-            config: AnimationConfig::new(speed, animation_type),
+            speed,
+            animation_type,
+            path: Arc::new(StraightPath),
+            easing: Easing::Function(config.easing_function),
+            config,
             start_time: Instant::now(),
         }
     }
 
+    /// Follow a path shape and easing, timed by distance
+    pub fn with_path(mut self, path: &PathConfig, timing: &AnimationTiming) -> Self {
+        let distance = ((self.end_position.x - self.start_position.x) as f64)
+            .hypot((self.end_position.y - self.start_position.y) as f64);
+        self.path = path.style.generator();
+        self.config = AnimationConfig::for_movement(
+            self.speed,
+            self.animation_type,
+            distance,
+            timing,
+            self.path.as_ref(),
+        );
+        self.easing = path.easing_for(self.animation_type);
+        self
+    }
+
     /// Get the next position in the animation sequence
     pub fn next_position(&self, step: u32) -> Option<Position> {
         if step >= self.config.steps {
@@ -147,18 +495,18 @@ impl AnimationInterpolator {
         let progress = (step + 1) as f32 / self.config.steps as f32;
 
         // Apply easing function
-        let eased_progress = (self.config.easing_function)(progress);
-
-        // Interpolate position
-        let dx = self.end_position.x - self.start_position.x;
-        let dy = self.end_position.y - self.start_position.y;
+        let eased_progress = self.easing.apply(progress);
 
-        let interpolated_x = self.start_position.x + (dx as f32 * eased_progress) as i32;
-        let interpolated_y = self.start_position.y + (dy as f32 * eased_progress) as i32;
+        // Interpolate position along the path
+        let (x, y) = self.path.point_at(
+            (self.start_position.x as f64, self.start_position.y as f64),
+            (self.end_position.x as f64, self.end_position.y as f64),
+            eased_progress as f64,
+        );
 
         Some(Position {
-            x: interpolated_x,
-            y: interpolated_y,
+            x: x.round() as i32,
+            y: y.round() as i32,
             screen_id: self.end_position.screen_id,
         })
     }
@@ -311,4 +659,149 @@ mod tests {
 
         assert!(!metrics.meets_performance_requirement());
     }
+
+    #[test]
+    fn test_elastic_animation_type() {
+        let easing = EasingFunctions::get_easing_function(AnimationType::Elastic);
+        assert_eq!(easing(0.3), EasingFunctions::ease_out_elastic(0.3));
+        assert_eq!(easing(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_cubic_bezier_easing() {
+        let linear = CubicBezierEasing::new(0.0, 0.0, 1.0, 1.0);
+        for t in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+            assert!((linear.evaluate(t) - t).abs() < 1e-3);
+        }
+
+        let ease = CubicBezierEasing::EASE_IN_OUT;
+        assert!((ease.evaluate(0.5) - 0.5).abs() < 1e-3);
+        assert!(ease.evaluate(0.2) < 0.2);
+        assert!(ease.evaluate(0.8) > 0.8);
+
+        let path = PathConfig {
+            style: PathStyle::MinimumJerk,
+            easing: Some(ease),
+        };
+        assert!(matches!(
+            path.easing_for(AnimationType::Smooth),
+            Easing::CubicBezier(_)
+        ));
+    }
+
+    #[test]
+    fn test_curved_paths() {
+        let (from, to) = ((0.0, 0.0), (100.0, 0.0));
+
+        let bezier = PathStyle::Bezier {
+            c1: (0.25, 0.5),
+            c2: (0.75, 0.5),
+        }
+        .generator();
+        assert_eq!(bezier.point_at(from, to, 0.0), from);
+        assert_eq!(bezier.point_at(from, to, 1.0), to);
+        let middle = bezier.point_at(from, to, 0.5);
+        assert!((middle.0 - 50.0).abs() < 1e-9);
+        assert!((middle.1 - 37.5).abs() < 1e-9);
+
+        let arc = PathStyle::Arc { bulge: 0.25 }.generator();
+        let end = arc.point_at(from, to, 1.0);
+        assert!((end.0 - 100.0).abs() < 1e-9 && end.1.abs() < 1e-9);
+        let top = arc.point_at(from, to, 0.5);
+        assert!((top.0 - 50.0).abs() < 1e-9);
+        assert!((top.1 - 25.0).abs() < 1e-9);
+
+        // Sideways offsets rotate with the direction of travel
+        let down = arc.point_at((0.0, 0.0), (0.0, 100.0), 0.5);
+        assert!((down.0 + 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_minimum_jerk_timing() {
+        let path = MinimumJerkPath;
+        assert_eq!(path.duration_ms(150, REFERENCE_DISTANCE), 150);
+        assert!(path.duration_ms(150, 100.0) < path.duration_ms(150, 1000.0));
+        assert!(path.duration_ms(150, 5000.0) <= 300);
+
+        let config = PathConfig {
+            style: PathStyle::MinimumJerk,
+            easing: None,
+        };
+        let interpolator = AnimationInterpolator::new(
+            Position::new(0, 0),
+            Position::new(2000, 0),
+            MovementSpeed::Normal,
+            AnimationType::Smooth,
+        )
+        .with_path(&config, &AnimationTiming::default());
+        assert!(interpolator.config().duration_ms > 200);
+
+        // Symmetric speed profile: halfway in time is halfway in space
+        let steps = interpolator.config().steps;
+        let middle = interpolator.next_position(steps / 2 - 1).unwrap();
+        assert_eq!(middle.x, 1000);
+        assert_eq!(
+            interpolator.next_position(steps - 1).unwrap(),
+            Position::new(2000, 0)
+        );
+    }
+
+    #[test]
+    fn test_distance_aware_timing() {
+        let timing = AnimationTiming::default();
+        let config = |speed, distance| {
+            AnimationConfig::for_movement(
+                speed,
+                AnimationType::Smooth,
+                distance,
+                &timing,
+                &StraightPath,
+            )
+        };
+
+        // A reference movement takes the base duration, one step per frame
+        let reference = config(MovementSpeed::Normal, REFERENCE_DISTANCE);
+        assert_eq!(reference.duration_ms, 200);
+        assert_eq!(reference.steps, 12);
+
+        // Short nudges are quicker than long jumps, within the bounds
+        let nudge = config(MovementSpeed::Normal, 20.0);
+        let jump = config(MovementSpeed::Normal, 3000.0);
+        assert_eq!(nudge.duration_ms, timing.min_duration_ms);
+        assert_eq!(jump.duration_ms, timing.max_duration_ms);
+        assert!(
+            config(MovementSpeed::Fast, 300.0).duration_ms
+                < config(MovementSpeed::Slow, 300.0).duration_ms
+        );
+
+        // Speed tiers scale the duration by the inverse of their configured multiplier
+        let configured = AnimationTiming {
+            fast_speed_multiplier: 4.0,
+            ..AnimationTiming::default()
+        };
+        assert_eq!(configured.speed_factor(MovementSpeed::Fast), 0.25);
+        assert_eq!(configured.speed_factor(MovementSpeed::Slow), 2.0);
+
+        // Tiny movements are instant
+        let tiny = config(MovementSpeed::Slow, 2.0);
+        assert_eq!(tiny.duration_ms, 0);
+        assert_eq!(tiny.steps, 1);
+
+        let fast_display = AnimationTiming {
+            refresh_rate_hz: 144,
+            ..AnimationTiming::default()
+        };
+        assert_eq!(
+            AnimationConfig::for_movement(
+                MovementSpeed::Normal,
+                AnimationType::Linear,
+                REFERENCE_DISTANCE,
+                &fast_display,
+                &StraightPath,
+            )
+            .steps,
+            29
+        );
+        assert_eq!(fast_display.frame_interval().as_micros(), 6944);
+    }
 }
@@ -0,0 +1,722 @@
+//! Animation scheduler module
+//!
+//! This module provides functionality for:
+//! - Running cursor animations frame by frame on a timer
+//! - Retargeting or cancelling the animation in flight
+//! - Blending a retargeted animation from the current cursor velocity
+//! - Reporting progress events and per-animation metrics
+//! - Driving animations from a real or a virtual clock
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+
+use crate::{
+    animation::{
+        AnimationConfig, AnimationMetrics, AnimationTiming, Easing, EasingFunctions, PathGenerator,
+        StraightPath,
+    },
+    error::MouseResult,
+    models::{AnimationType, MovementSpeed, PathConfig, Position},
+};
+
+/// Default time between two animation frames (about 120 frames per second)
+pub const DEFAULT_FRAME_INTERVAL: Duration = Duration::from_millis(8);
+
+/// Source of time for the animation scheduler and key sequence timeouts
+pub trait AnimationClock: Send + Sync {
+    /// Time elapsed since the clock was created
+    fn now(&self) -> Duration;
+}
+
+/// A clock shared between the modes of a mode manager
+pub type SharedClock = Arc<dyn AnimationClock>;
+
+/// Clock following the system's monotonic time
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimationClock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// Clock that only moves when advanced, for deterministic tests
+#[derive(Default)]
+pub struct VirtualClock {
+    now: Mutex<Duration>,
+}
+
+impl VirtualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, duration: Duration) {
+        if let Ok(mut now) = self.now.lock() {
+            *now += duration;
+        }
+    }
+}
+
+impl AnimationClock for VirtualClock {
+    fn now(&self) -> Duration {
+        self.now.lock().map(|now| *now).unwrap_or_default()
+    }
+}
+
+/// Identifier of a scheduled animation
+pub type AnimationId = u64;
+
+/// Events published by the animation scheduler
+#[derive(Debug, Clone)]
+pub enum AnimationEvent {
+    Started {
+        id: AnimationId,
+        from: Position,
+        to: Position,
+    },
+    Retargeted {
+        id: AnimationId,
+        from: Position,
+        to: Position,
+    },
+    Progress {
+        id: AnimationId,
+        position: Position,
+        progress: f32,
+    },
+    Completed {
+        id: AnimationId,
+        metrics: AnimationMetrics,
+    },
+    Cancelled {
+        id: AnimationId,
+        metrics: AnimationMetrics,
+    },
+}
+
+/// One leg of a cursor path
+///
+/// A fresh animation follows its path shape with its easing curve. After a
+/// retarget the leg is a cubic Hermite curve that starts with the velocity the
+/// cursor had and comes to rest at the new target.
+struct AnimationSegment {
+    from: (f64, f64),
+    to: (f64, f64),
+    /// Initial velocity in pixels per millisecond
+    velocity: (f64, f64),
+    duration: Duration,
+    easing: Easing,
+    path: Arc<dyn PathGenerator>,
+}
+
+impl AnimationSegment {
+    fn point_at(&self, t: f64) -> (f64, f64) {
+        let t = t.clamp(0.0, 1.0);
+
+        if self.velocity == (0.0, 0.0) {
+            let eased = self.easing.apply(t as f32) as f64;
+            return self.path.point_at(self.from, self.to, eased);
+        }
+
+        let duration_ms = self.duration.as_secs_f64() * 1000.0;
+        let h01 = 3.0 * t * t - 2.0 * t * t * t;
+        let h10 = t * t * t - 2.0 * t * t + t;
+        (
+            self.from.0 + (self.to.0 - self.from.0) * h01 + self.velocity.0 * duration_ms * h10,
+            self.from.1 + (self.to.1 - self.from.1) * h01 + self.velocity.1 * duration_ms * h10,
+        )
+    }
+
+    /// Velocity at progress `t` in pixels per millisecond
+    fn velocity_at(&self, t: f64) -> (f64, f64) {
+        let duration_ms = self.duration.as_secs_f64() * 1000.0;
+        if duration_ms <= 0.0 || t >= 1.0 {
+            return (0.0, 0.0);
+        }
+
+        const EPSILON: f64 = 1e-3;
+        let (before, after) = ((t - EPSILON).max(0.0), (t + EPSILON).min(1.0));
+        let (a, b) = (self.point_at(before), self.point_at(after));
+        let dt = (after - before) * duration_ms;
+        ((b.0 - a.0) / dt, (b.1 - a.1) / dt)
+    }
+}
+
+struct ActiveAnimation {
+    id: AnimationId,
+    segment: AnimationSegment,
+    target: Position,
+    started_at: Duration,
+    last_position: Option<Position>,
+    metrics: AnimationMetrics,
+}
+
+impl ActiveAnimation {
+    fn progress_at(&self, now: Duration) -> f64 {
+        if self.segment.duration.is_zero() {
+            return 1.0;
+        }
+        now.saturating_sub(self.started_at).as_secs_f64() / self.segment.duration.as_secs_f64()
+    }
+}
+
+/// Schedules cursor animations frame by frame
+///
+/// The scheduler does not own a timer: the owner calls [`tick`] once per
+/// frame (see [`frame_interval`]) and the scheduler moves the cursor to
+/// where the animation should be at the clock's current time. Starting a new
+/// animation while one is in flight retargets it.
+///
+/// [`tick`]: AnimationScheduler::tick
+/// [`frame_interval`]: AnimationScheduler::frame_interval
+pub struct AnimationScheduler {
+    clock: Arc<dyn AnimationClock>,
+    active: Option<ActiveAnimation>,
+    next_id: AnimationId,
+    frame_interval: Duration,
+    timing: AnimationTiming,
+    event_sender: broadcast::Sender<AnimationEvent>,
+}
+
+impl AnimationScheduler {
+    /// Create a scheduler driven by a clock
+    pub fn new(clock: Arc<dyn AnimationClock>) -> Self {
+        let (event_sender, _) = broadcast::channel(100);
+
+        Self {
+            clock,
+            active: None,
+            next_id: 1,
+            frame_interval: DEFAULT_FRAME_INTERVAL,
+            timing: AnimationTiming::default(),
+            event_sender,
+        }
+    }
+
+    /// Set the time between two frames
+    pub fn with_frame_interval(mut self, frame_interval: Duration) -> Self {
+        self.frame_interval = frame_interval.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Set how animations are timed, stepping once per display frame
+    pub fn with_timing(mut self, timing: AnimationTiming) -> Self {
+        self.set_timing(timing);
+        self
+    }
+
+    /// Update how animations are timed
+    pub fn set_timing(&mut self, timing: AnimationTiming) {
+        self.frame_interval = timing.frame_interval().max(Duration::from_millis(1));
+        self.timing = timing;
+    }
+
+    /// Get the time between two frames
+    pub fn frame_interval(&self) -> Duration {
+        self.frame_interval
+    }
+
+    /// Subscribe to animation events
+    pub fn subscribe(&self) -> broadcast::Receiver<AnimationEvent> {
+        self.event_sender.subscribe()
+    }
+
+    /// Get a sender for animation events, e.g. to subscribe from another thread
+    pub fn event_sender(&self) -> broadcast::Sender<AnimationEvent> {
+        self.event_sender.clone()
+    }
+
+    /// Check whether an animation is in flight
+    pub fn is_animating(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Get the target of the animation in flight
+    pub fn current_target(&self) -> Option<Position> {
+        self.active.as_ref().map(|active| active.target)
+    }
+
+    /// Animate the cursor along a straight line with the timing for a movement speed
+    pub fn animate(
+        &mut self,
+        from: Position,
+        to: Position,
+        speed: MovementSpeed,
+        animation_type: AnimationType,
+    ) -> AnimationId {
+        self.animate_along(from, to, speed, animation_type, &PathConfig::default())
+    }
+
+    /// Animate the cursor along a path shape, timed by speed and distance
+    ///
+    /// Movements shorter than the timing's instant threshold jump straight to
+    /// the target.
+    pub fn animate_along(
+        &mut self,
+        from: Position,
+        to: Position,
+        speed: MovementSpeed,
+        animation_type: AnimationType,
+        path: &PathConfig,
+    ) -> AnimationId {
+        let generator = path.style.generator();
+        let distance = match &self.active {
+            // A retarget starts where the cursor is now
+            Some(active) => {
+                let current = active
+                    .segment
+                    .point_at(active.progress_at(self.clock.now()));
+                (to.x as f64 - current.0).hypot(to.y as f64 - current.1)
+            }
+            None => ((to.x - from.x) as f64).hypot((to.y - from.y) as f64),
+        };
+        let config = AnimationConfig::for_movement(
+            speed,
+            animation_type,
+            distance,
+            &self.timing,
+            generator.as_ref(),
+        );
+
+        self.start(
+            from,
+            to,
+            Duration::from_millis(config.duration_ms),
+            path.easing_for(animation_type),
+            generator,
+        )
+    }
+
+    /// Animate the cursor along a straight line over a fixed duration
+    pub fn animate_for(
+        &mut self,
+        from: Position,
+        to: Position,
+        animation_type: AnimationType,
+        duration: Duration,
+    ) -> AnimationId {
+        let duration = if animation_type == AnimationType::Instant {
+            Duration::ZERO
+        } else {
+            duration
+        };
+        let easing = Easing::Function(EasingFunctions::get_easing_function(animation_type));
+        self.start(from, to, duration, easing, Arc::new(StraightPath))
+    }
+
+    /// Start an animation
+    ///
+    /// If an animation is in flight it is retargeted: the new path starts
+    /// where the cursor is now, with its current velocity, and `from` is
+    /// ignored.
+    fn start(
+        &mut self,
+        from: Position,
+        to: Position,
+        duration: Duration,
+        easing: Easing,
+        path: Arc<dyn PathGenerator>,
+    ) -> AnimationId {
+        let now = self.clock.now();
+
+        if let Some(active) = &mut self.active {
+            let t = active.progress_at(now);
+            let current = active.segment.point_at(t);
+            let velocity = if duration.is_zero() {
+                (0.0, 0.0)
+            } else {
+                active.segment.velocity_at(t)
+            };
+
+            active.segment = AnimationSegment {
+                from: current,
+                to: (to.x as f64, to.y as f64),
+                velocity,
+                duration,
+                easing,
+                path,
+            };
+            active.target = to;
+            active.started_at = now;
+
+            let id = active.id;
+            let _ = self.event_sender.send(AnimationEvent::Retargeted {
+                id,
+                from: Self::round(current, to),
+                to,
+            });
+            return id;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.active = Some(ActiveAnimation {
+            id,
+            segment: AnimationSegment {
+                from: (from.x as f64, from.y as f64),
+                to: (to.x as f64, to.y as f64),
+                velocity: (0.0, 0.0),
+                duration,
+                easing,
+                path,
+            },
+            target: to,
+            started_at: now,
+            last_position: None,
+            metrics: AnimationMetrics::new(),
+        });
+
+        let _ = self
+            .event_sender
+            .send(AnimationEvent::Started { id, from, to });
+        id
+    }
+
+    /// Cancel the animation in flight, leaving the cursor where it is
+    pub fn cancel(&mut self) -> Option<AnimationId> {
+        let active = self.active.take()?;
+        let _ = self.event_sender.send(AnimationEvent::Cancelled {
+            id: active.id,
+            metrics: active.metrics,
+        });
+        Some(active.id)
+    }
+
+    /// Advance the animation in flight to the clock's current time
+    ///
+    /// `move_cursor` is called with the new position whenever the cursor has
+    /// to move. Returns whether an animation is still in flight. A failing
+    /// move cancels the animation.
+    pub fn tick<F>(&mut self, mut move_cursor: F) -> MouseResult<bool>
+    where
+        F: FnMut(Position) -> MouseResult<()>,
+    {
+        let now = self.clock.now();
+        let Some(active) = &mut self.active else {
+            return Ok(false);
+        };
+
+        let t = active.progress_at(now);
+        let position = if t >= 1.0 {
+            active.target
+        } else {
+            Self::round(active.segment.point_at(t), active.target)
+        };
+
+        if active.last_position != Some(position) {
+            if let Err(e) = move_cursor(position) {
+                self.cancel();
+                return Err(e);
+            }
+            let step_time = self.clock.now().saturating_sub(now);
+            active.metrics.record_step(step_time.as_millis() as u64);
+            active.last_position = Some(position);
+        }
+
+        let _ = self.event_sender.send(AnimationEvent::Progress {
+            id: active.id,
+            position,
+            progress: t.min(1.0) as f32,
+        });
+
+        if t < 1.0 {
+            return Ok(true);
+        }
+
+        if let Some(active) = self.active.take() {
+            if !active.metrics.meets_performance_requirement() {
+                tracing::warn!(
+                    "Animation performance below requirement: avg={:.2}ms, max={}ms",
+                    active.metrics.average_step_time_ms,
+                    active.metrics.max_step_time_ms
+                );
+            }
+            let _ = self.event_sender.send(AnimationEvent::Completed {
+                id: active.id,
+                metrics: active.metrics,
+            });
+        }
+        Ok(false)
+    }
+
+    /// Run the animation in flight to completion, sleeping between frames
+    ///
+    /// Only meaningful with a clock that follows real time.
+    pub fn run_to_completion<F>(&mut self, mut move_cursor: F) -> MouseResult<()>
+    where
+        F: FnMut(Position) -> MouseResult<()>,
+    {
+        while self.tick(&mut move_cursor)? {
+            thread::sleep(self.frame_interval);
+        }
+        Ok(())
+    }
+
+    fn round(point: (f64, f64), target: Position) -> Position {
+        Position {
+            x: point.0.round() as i32,
+            y: point.1.round() as i32,
+            screen_id: target.screen_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_scheduler() -> (Arc<VirtualClock>, AnimationScheduler) {
+        let clock = Arc::new(VirtualClock::new());
+        let scheduler = AnimationScheduler::new(clock.clone());
+        (clock, scheduler)
+    }
+
+    fn run_frames(
+        clock: &VirtualClock,
+        scheduler: &mut AnimationScheduler,
+        frames: u32,
+        moves: &mut Vec<Position>,
+    ) -> bool {
+        let mut animating = false;
+        for _ in 0..frames {
+            clock.advance(Duration::from_millis(10));
+            animating = scheduler
+                .tick(|position| {
+                    moves.push(position);
+                    Ok(())
+                })
+                .unwrap();
+        }
+        animating
+    }
+
+    #[test]
+    fn test_animation_follows_virtual_clock() {
+        let (clock, mut scheduler) = create_test_scheduler();
+        let mut events = scheduler.subscribe();
+        let mut moves = Vec::new();
+
+        let id = scheduler.animate_for(
+            Position::new(0, 0),
+            Position::new(100, 0),
+            AnimationType::Linear,
+            Duration::from_millis(100),
+        );
+        assert!(scheduler.is_animating());
+
+        // Nothing moves until the clock does
+        scheduler
+            .tick(|p| {
+                moves.push(p);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(moves, vec![Position::new(0, 0)]);
+
+        assert!(run_frames(&clock, &mut scheduler, 5, &mut moves));
+        assert_eq!(moves.last(), Some(&Position::new(50, 0)));
+
+        assert!(!run_frames(&clock, &mut scheduler, 5, &mut moves));
+        assert_eq!(moves.last(), Some(&Position::new(100, 0)));
+        assert!(!scheduler.is_animating());
+
+        assert!(matches!(
+            events.try_recv(),
+            Ok(AnimationEvent::Started { .. })
+        ));
+        let mut last = None;
+        while let Ok(event) = events.try_recv() {
+            last = Some(event);
+        }
+        match last {
+            Some(AnimationEvent::Completed {
+                id: completed,
+                metrics,
+            }) => {
+                assert_eq!(completed, id);
+                assert_eq!(metrics.steps_completed, 11);
+            }
+            other => panic!("Expected completion event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_retarget_blends_velocity() {
+        let (clock, mut scheduler) = create_test_scheduler();
+        let mut moves = Vec::new();
+
+        let id = scheduler.animate_for(
+            Position::new(0, 0),
+            Position::new(1000, 0),
+            AnimationType::Linear,
+            Duration::from_millis(100),
+        );
+        run_frames(&clock, &mut scheduler, 5, &mut moves);
+        assert_eq!(moves.last(), Some(&Position::new(500, 0)));
+
+        // Retarget straight down while moving right at 10px/ms
+        let retargeted = scheduler.animate_for(
+            Position::new(0, 0),
+            Position::new(500, 400),
+            AnimationType::Smooth,
+            Duration::from_millis(100),
+        );
+        assert_eq!(retargeted, id);
+        assert_eq!(scheduler.current_target(), Some(Position::new(500, 400)));
+
+        // The cursor keeps drifting right before curving to the target
+        run_frames(&clock, &mut scheduler, 1, &mut moves);
+        let first = *moves.last().unwrap();
+        assert!(first.x > 500, "no velocity carried over: {:?}", first);
+        assert!(first.y > 0);
+
+        run_frames(&clock, &mut scheduler, 9, &mut moves);
+        assert_eq!(moves.last(), Some(&Position::new(500, 400)));
+        assert!(!scheduler.is_animating());
+    }
+
+    #[test]
+    fn test_cancel_and_instant() {
+        let (clock, mut scheduler) = create_test_scheduler();
+        let mut events = scheduler.subscribe();
+        let mut moves = Vec::new();
+
+        let id = scheduler.animate(
+            Position::new(0, 0),
+            Position::new(300, 300),
+            MovementSpeed::Normal,
+            AnimationType::Smooth,
+        );
+        run_frames(&clock, &mut scheduler, 2, &mut moves);
+        assert_eq!(scheduler.cancel(), Some(id));
+        assert_eq!(scheduler.cancel(), None);
+        assert!(!run_frames(&clock, &mut scheduler, 2, &mut moves));
+        assert_eq!(moves.len(), 2);
+
+        let cancelled = std::iter::from_fn(|| events.try_recv().ok())
+            .any(|event| matches!(event, AnimationEvent::Cancelled { id: c, .. } if c == id));
+        assert!(cancelled);
+
+        // Instant moves finish on the first tick
+        scheduler.animate(
+            Position::new(0, 0),
+            Position::new(10, 10),
+            MovementSpeed::Slow,
+            AnimationType::Instant,
+        );
+        assert!(!scheduler
+            .tick(|p| {
+                moves.push(p);
+                Ok(())
+            })
+            .unwrap());
+        assert_eq!(moves.last(), Some(&Position::new(10, 10)));
+    }
+
+    #[test]
+    fn test_animate_along_curved_path() {
+        let (clock, mut scheduler) = create_test_scheduler();
+        let mut moves = Vec::new();
+
+        let path = PathConfig {
+            style: crate::PathStyle::Arc { bulge: 0.25 },
+            easing: Some(crate::CubicBezierEasing::new(0.0, 0.0, 1.0, 1.0)),
+        };
+        scheduler.animate_along(
+            Position::new(0, 0),
+            Position::new(500, 0),
+            MovementSpeed::Normal,
+            AnimationType::Smooth,
+            &path,
+        );
+
+        // A reference distance at normal speed takes 200ms; halfway the arc is at its highest
+        run_frames(&clock, &mut scheduler, 10, &mut moves);
+        assert_eq!(moves.last(), Some(&Position::new(250, 125)));
+
+        run_frames(&clock, &mut scheduler, 10, &mut moves);
+        assert_eq!(moves.last(), Some(&Position::new(500, 0)));
+        assert!(!scheduler.is_animating());
+    }
+
+    #[test]
+    fn test_distance_aware_scheduling() {
+        let (clock, scheduler) = create_test_scheduler();
+        let mut scheduler = scheduler.with_timing(AnimationTiming {
+            refresh_rate_hz: 100,
+            ..AnimationTiming::default()
+        });
+        assert_eq!(scheduler.frame_interval(), Duration::from_millis(10));
+        let mut moves = Vec::new();
+
+        // Tiny moves finish on the first tick
+        scheduler.animate(
+            Position::new(100, 100),
+            Position::new(102, 101),
+            MovementSpeed::Slow,
+            AnimationType::Smooth,
+        );
+        assert!(!scheduler
+            .tick(|p| {
+                moves.push(p);
+                Ok(())
+            })
+            .unwrap());
+        assert_eq!(moves, vec![Position::new(102, 101)]);
+
+        // Short moves take the minimum duration
+        scheduler.animate(
+            Position::new(102, 101),
+            Position::new(122, 101),
+            MovementSpeed::Normal,
+            AnimationType::Smooth,
+        );
+        assert!(run_frames(&clock, &mut scheduler, 3, &mut moves));
+        assert!(!run_frames(&clock, &mut scheduler, 1, &mut moves));
+        assert_eq!(moves.last(), Some(&Position::new(122, 101)));
+    }
+
+    #[test]
+    fn test_failed_move_cancels_animation() {
+        let (clock, mut scheduler) = create_test_scheduler();
+
+        scheduler.animate(
+            Position::new(0, 0),
+            Position::new(100, 100),
+            MovementSpeed::Fast,
+            AnimationType::Linear,
+        );
+        clock.advance(Duration::from_millis(10));
+
+        let result = scheduler.tick(|_| {
+            Err(crate::error::MouseError::MovementFailed {
+                x: 0,
+                y: 0,
+                reason: "test".to_string(),
+            })
+        });
+        assert!(result.is_err());
+        assert!(!scheduler.is_animating());
+    }
+}
@@ -1,13 +1,197 @@
-use std::collections::HashMap;
-use tracing::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
 
 use crate::{
-    error::Result,
-    models::{Action, AnimationType, Area, InteractionMode, KeyInput, Position, ScreenBounds},
-    traits::KeyBindings,
+    animation_scheduler::{SharedClock, SystemClock},
+    error::{ConfigError, ConfigResult, MouselessError, Result},
+    keyboard_layout::{KeyRow, KeyboardLayout, KEYS_PER_ROW},
+    models::{
+        Action, AnimationType, Area, InteractionMode, KeyInput, MultiScreenLayout, Position,
+        ScreenBounds,
+    },
+    screen::ScreenManager,
+    traits::{KeyBindings, Mode, ModeOverlay},
 };
 
-/// Area mode handler for 9-region screen navigation
+/// Layout of the areas used by area mode
+///
+/// ```toml
+/// [area]
+/// type = "grid"
+/// rows = 2
+/// columns = 4
+/// keys = "qwerasdf"
+/// ```
+///
+/// or a list of rectangles given in fractions of the screen:
+///
+/// ```toml
+/// [area]
+/// type = "regions"
+///
+/// [[area.regions]]
+/// name = "sidebar"
+/// key = "s"
+/// x = 0.0
+/// y = 0.0
+/// width = 0.2
+/// height = 1.0
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AreaLayout {
+    /// Uniform grid of areas
+    Grid {
+        rows: u32,
+        columns: u32,
+        /// Area keys in row-major order; taken from the left of the keyboard layout when unset
+        #[serde(default)]
+        keys: Option<String>,
+    },
+    /// Named rectangles, each with its own key
+    Regions { regions: Vec<AreaRegion> },
+}
+
+/// Named screen region for area mode, in fractions of the screen size
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AreaRegion {
+    pub name: String,
+    pub key: char,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Default for AreaLayout {
+    fn default() -> Self {
+        Self::Grid {
+            rows: 3,
+            columns: 3,
+            keys: None,
+        }
+    }
+}
+
+impl AreaLayout {
+    /// Validate the layout independently of the keyboard layout
+    pub fn validate(&self) -> ConfigResult<()> {
+        match self {
+            Self::Grid {
+                rows,
+                columns,
+                keys,
+            } => {
+                if *rows == 0 || *columns == 0 {
+                    return Err(ConfigError::InvalidValue {
+                        field: "area.rows/columns".to_string(),
+                        value: format!("{}x{}", rows, columns),
+                    });
+                }
+
+                match keys {
+                    Some(keys) => {
+                        if keys.chars().count() != (rows * columns) as usize {
+                            return Err(ConfigError::ValidationFailed {
+                                reason: format!(
+                                    "Area keys '{}' must have one key per area ({})",
+                                    keys,
+                                    rows * columns
+                                ),
+                            });
+                        }
+                        Self::validate_keys(keys.chars())
+                    }
+                    None if *rows as usize > KeyRow::ALL.len()
+                        || *columns as usize > KEYS_PER_ROW =>
+                    {
+                        Err(ConfigError::MissingField {
+                            field: "area.keys".to_string(),
+                        })
+                    }
+                    None => Ok(()),
+                }
+            }
+            Self::Regions { regions } => {
+                if regions.is_empty() {
+                    return Err(ConfigError::MissingField {
+                        field: "area.regions".to_string(),
+                    });
+                }
+
+                for region in regions {
+                    let in_range = |v: f64| (0.0..=1.0).contains(&v);
+                    if !in_range(region.x)
+                        || !in_range(region.y)
+                        || region.width <= 0.0
+                        || region.height <= 0.0
+                        || region.x + region.width > 1.0 + f64::EPSILON
+                        || region.y + region.height > 1.0 + f64::EPSILON
+                    {
+                        return Err(ConfigError::InvalidValue {
+                            field: format!("area.regions.{}", region.name),
+                            value: format!(
+                                "{}, {}, {}x{}",
+                                region.x, region.y, region.width, region.height
+                            ),
+                        });
+                    }
+                }
+
+                Self::validate_keys(regions.iter().map(|region| region.key))
+            }
+        }
+    }
+
+    fn validate_keys(keys: impl Iterator<Item = char>) -> ConfigResult<()> {
+        let mut seen = HashSet::new();
+        for key in keys {
+            let key = key.to_lowercase().next().unwrap_or(key);
+            if key.is_whitespace() || key.is_control() || !seen.insert(key) {
+                return Err(ConfigError::InvalidValue {
+                    field: "area.keys".to_string(),
+                    value: key.escape_default().to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the keys of a grid layout in row-major order
+    ///
+    /// Without explicit keys the left block of the keyboard layout is used,
+    /// which is Q/W/E/A/S/D/Z/X/C for a 3x3 grid on QWERTY. Four rows add the
+    /// number row on top.
+    fn grid_keys(
+        rows: u32,
+        columns: u32,
+        keys: &Option<String>,
+        layout: &KeyboardLayout,
+    ) -> Vec<char> {
+        if let Some(keys) = keys {
+            return keys.chars().flat_map(char::to_lowercase).collect();
+        }
+
+        let key_rows: &[KeyRow] = if rows as usize > 3 {
+            &KeyRow::ALL
+        } else {
+            &[KeyRow::Top, KeyRow::Home, KeyRow::Bottom]
+        };
+
+        key_rows
+            .iter()
+            .take(rows as usize)
+            .flat_map(|&key_row| layout.row(key_row).into_iter().take(columns as usize))
+            .collect()
+    }
+}
+
+/// Area mode handler for configurable region screen navigation
+///
+/// Areas and the positions it emits are in logical pixels.
 pub struct AreaMode {
     /// Whether area mode is currently active
     is_active: bool,
@@ -15,15 +199,35 @@ pub struct AreaMode {
     /// Screen areas mapped to their keys
     areas: HashMap<char, Area>,
 
-    /// Screen dimensions for area calculation
-    screen_width: f64,
-    screen_height: f64,
+    /// Screens the areas are laid out on
+    screens: Vec<ScreenBounds>,
+
+    /// How areas are laid out over several screens
+    multi_screen: MultiScreenLayout,
+
+    /// Index of the screen whose areas are selected without a screen prefix
+    active_screen: usize,
+
+    /// Index of the screen chosen with a screen number prefix key
+    selected_screen: Option<usize>,
 
     /// Current combination state for Q+E style navigation
     first_key: Option<char>,
 
     /// Timestamp of first key press for timeout handling
-    first_key_time: Option<std::time::Instant>,
+    first_key_time: Option<Duration>,
+
+    /// Keyboard layout used to pick the area keys
+    keyboard_layout: KeyboardLayout,
+
+    /// Configured area layout
+    layout: AreaLayout,
+
+    /// Animation used for cursor movement
+    animation_type: AnimationType,
+
+    /// Clock for key combination timeouts
+    clock: SharedClock,
 }
 
 impl AreaMode {
@@ -32,24 +236,105 @@ impl AreaMode {
         Self {
             is_active: false,
             areas: HashMap::new(),
-            screen_width: 1920.0,  // Default, will be updated
-            screen_height: 1080.0, // Default, will be updated
+            screens: vec![ScreenBounds {
+                id: 0,
+                x: 0,
+                y: 0,
+                width: 1920,  // Default, will be updated
+                height: 1080, // Default, will be updated
+                is_primary: true,
+                scale_factor: 1.0,
+            }],
+            multi_screen: MultiScreenLayout::default(),
+            active_screen: 0,
+            selected_screen: None,
             first_key: None,
             first_key_time: None,
+            keyboard_layout: KeyboardLayout::default(),
+            layout: AreaLayout::default(),
+            animation_type: AnimationType::Smooth,
+            clock: Arc::new(SystemClock::new()),
         }
     }
 
-    /// Set screen dimensions and recalculate areas
-    pub fn set_screen_dimensions(&mut self, width: f64, height: f64) {
-        self.screen_width = width;
-        self.screen_height = height;
+    /// Set the animation used for cursor movement
+    pub fn set_animation_type(&mut self, animation_type: AnimationType) {
+        self.animation_type = animation_type;
+    }
+
+    /// Set the area layout and recalculate areas
+    pub fn set_layout(&mut self, layout: AreaLayout) -> Result<()> {
+        layout.validate()?;
+        self.layout = layout;
+        self.reset_combination_state();
         self.calculate_areas();
+        debug!("Area layout updated: {} areas", self.areas.len());
+        Ok(())
+    }
+
+    /// Get the configured area layout
+    pub fn layout(&self) -> &AreaLayout {
+        &self.layout
+    }
+
+    /// Set the keyboard layout and recalculate areas
+    pub fn set_keyboard_layout(&mut self, keyboard_layout: KeyboardLayout) {
+        self.keyboard_layout = keyboard_layout;
+        self.reset_combination_state();
+        self.calculate_areas();
+        debug!("Keyboard layout updated: {}", self.keyboard_layout.name);
+    }
+
+    /// Set screen dimensions of a single screen at the origin and recalculate areas
+    pub fn set_screen_dimensions(&mut self, width: f64, height: f64) {
+        self.set_screens(vec![ScreenBounds {
+            id: 0,
+            x: 0,
+            y: 0,
+            width: width as u32,
+            height: height as u32,
+            is_primary: true,
+            scale_factor: 1.0,
+        }]);
         debug!("Screen dimensions updated: {}x{}", width, height);
     }
 
+    /// Set the screens to lay out areas on and recalculate areas
+    pub fn set_screens(&mut self, screens: Vec<ScreenBounds>) {
+        self.active_screen = screens.iter().position(|s| s.is_primary).unwrap_or(0);
+        self.screens = screens;
+        self.selected_screen = None;
+        self.reset_combination_state();
+        self.calculate_areas();
+        debug!("Screens updated: {} screens", self.screens.len());
+    }
+
+    /// Set how areas are laid out over several screens
+    pub fn set_multi_screen_layout(&mut self, multi_screen: MultiScreenLayout) {
+        self.multi_screen = multi_screen;
+        self.selected_screen = None;
+        self.reset_combination_state();
+        self.calculate_areas();
+    }
+
+    /// Set the screen whose areas are selected without a screen prefix
+    pub fn set_active_screen(&mut self, screen_id: u32) -> Result<()> {
+        self.active_screen = self
+            .screens
+            .iter()
+            .position(|s| s.id == screen_id)
+            .ok_or_else(|| {
+                MouselessError::ModeError(format!("Screen with ID {} not found", screen_id))
+            })?;
+        self.selected_screen = None;
+        self.calculate_areas();
+        Ok(())
+    }
+
     /// Activate area mode
     pub fn activate(&mut self) {
         self.is_active = true;
+        self.selected_screen = None;
         self.reset_combination_state();
         self.calculate_areas();
         info!("Area mode activated");
@@ -67,70 +352,188 @@ impl AreaMode {
         self.is_active
     }
 
-    /// Get current areas for UI rendering
+    /// Get the areas currently selectable by key for UI rendering
     pub fn get_areas(&self) -> Vec<Area> {
         self.areas.values().cloned().collect()
     }
 
+    /// Get the areas of every screen for UI rendering
+    ///
+    /// With one layout per screen, labels carry the screen number prefix.
+    pub fn get_all_areas(&self) -> Vec<Area> {
+        if !self.uses_screen_prefix() {
+            return self.get_areas();
+        }
+
+        (0..self.screens.len())
+            .flat_map(|index| {
+                self.compute_areas(Some(index))
+                    .into_values()
+                    .map(move |mut area| {
+                        area.label = format!("{}{}", index + 1, area.label);
+                        area
+                    })
+            })
+            .collect()
+    }
+
+    /// Check whether areas of other screens are selected with a screen number prefix
+    fn uses_screen_prefix(&self) -> bool {
+        self.multi_screen == MultiScreenLayout::PerScreen && self.screens.len() > 1
+    }
+
+    /// Get the screen number (0-based) selected by a prefix key, if it is one
+    fn screen_prefix_index(&self, key: char) -> Option<usize> {
+        if !self.uses_screen_prefix() || self.areas.contains_key(&key) {
+            return None;
+        }
+
+        let index = key.to_digit(10)?.checked_sub(1)? as usize;
+        (index < self.screens.len()).then_some(index)
+    }
+
     /// Reset combination state
     fn reset_combination_state(&mut self) {
         self.first_key = None;
         self.first_key_time = None;
     }
 
-    /// Calculate 9 screen areas based on current dimensions
+    /// Calculate screen areas based on current screens and layout
     fn calculate_areas(&mut self) {
-        self.areas.clear();
-
-        let area_width = self.screen_width / 3.0;
-        let area_height = self.screen_height / 3.0;
-
-        // Define the 9 areas with their keys (Q/W/E/A/S/D/Z/X/C)
-        let area_keys = [
-            ('q', 0, 0),
-            ('w', 1, 0),
-            ('e', 2, 0), // Top row
-            ('a', 0, 1),
-            ('s', 1, 1),
-            ('d', 2, 1), // Middle row
-            ('z', 0, 2),
-            ('x', 1, 2),
-            ('c', 2, 2), // Bottom row
-        ];
-
-        for (key, col, row) in area_keys.iter() {
-            let x = *col as f64 * area_width;
-            let y = *row as f64 * area_height;
-
-            let area = Area {
-                key: *key,
-                bounds: ScreenBounds {
-                    id: 0,
-                    x: x as i32,
-                    y: y as i32,
-                    width: area_width as u32,
-                    height: area_height as u32,
-                    is_primary: true,
-                },
-                center: Position {
-                    x: (x + area_width / 2.0) as i32,
-                    y: (y + area_height / 2.0) as i32,
-                    screen_id: None,
-                },
-                label: format!("{}", key.to_uppercase()),
-            };
-
-            self.areas.insert(*key, area);
-        }
+        let screen = match self.multi_screen {
+            MultiScreenLayout::PerScreen => {
+                Some(self.selected_screen.unwrap_or(self.active_screen))
+            }
+            MultiScreenLayout::SpanDesktop => None,
+        };
+        self.areas = self.compute_areas(screen);
 
         debug!(
-            "Calculated {} areas for screen {}x{}",
+            "Calculated {} areas for {} screens",
             self.areas.len(),
-            self.screen_width,
-            self.screen_height
+            self.screens.len()
         );
     }
 
+    /// Compute the areas on one screen, or across the desktop when no screen is given
+    fn compute_areas(&self, screen: Option<usize>) -> HashMap<char, Area> {
+        let mut areas = HashMap::new();
+
+        let region =
+            match screen {
+                Some(index) => self.screens.get(index).cloned(),
+                None => ScreenManager::desktop_bounds_of(&self.screens).ok().map(
+                    |(x, y, width, height)| ScreenBounds {
+                        id: 0,
+                        x,
+                        y,
+                        width,
+                        height,
+                        is_primary: true,
+                        scale_factor: 1.0,
+                    },
+                ),
+            };
+        let Some(region) = region else {
+            return areas;
+        };
+
+        let origin_x = region.x as f64;
+        let origin_y = region.y as f64;
+        let screen_width = region.width as f64;
+        let screen_height = region.height as f64;
+
+        match &self.layout {
+            AreaLayout::Grid {
+                rows,
+                columns,
+                keys,
+            } => {
+                let area_width = screen_width / *columns as f64;
+                let area_height = screen_height / *rows as f64;
+                let keys = AreaLayout::grid_keys(*rows, *columns, keys, &self.keyboard_layout);
+
+                if keys.len() < (rows * columns) as usize {
+                    warn!(
+                        "Keyboard layout '{}' has too few keys for {}x{} areas",
+                        self.keyboard_layout.name, rows, columns
+                    );
+                }
+
+                for (index, key) in keys.into_iter().enumerate() {
+                    let col = index % *columns as usize;
+                    let row = index / *columns as usize;
+                    let x = origin_x + col as f64 * area_width;
+                    let y = origin_y + row as f64 * area_height;
+
+                    areas.insert(
+                        key,
+                        self.create_area(
+                            key,
+                            x,
+                            y,
+                            area_width,
+                            area_height,
+                            key.to_uppercase().to_string(),
+                        ),
+                    );
+                }
+            }
+            AreaLayout::Regions { regions } => {
+                for region in regions {
+                    let key = region.key.to_lowercase().next().unwrap_or(region.key);
+                    let area = self.create_area(
+                        key,
+                        origin_x + (region.x * screen_width).round(),
+                        origin_y + (region.y * screen_height).round(),
+                        (region.width * screen_width).round(),
+                        (region.height * screen_height).round(),
+                        region.name.clone(),
+                    );
+                    areas.insert(key, area);
+                }
+            }
+        }
+
+        areas
+    }
+
+    fn create_area(
+        &self,
+        key: char,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        label: String,
+    ) -> Area {
+        let center = self.place_on_screen(Position::new(
+            (x + width / 2.0) as i32,
+            (y + height / 2.0) as i32,
+        ));
+        let screen = self.screens.iter().find(|s| Some(s.id) == center.screen_id);
+
+        Area {
+            key,
+            bounds: ScreenBounds {
+                id: screen.map_or(0, |s| s.id),
+                x: x as i32,
+                y: y as i32,
+                width: width as u32,
+                height: height as u32,
+                is_primary: screen.is_none_or(|s| s.is_primary),
+                scale_factor: screen.map_or(1.0, |s| s.scale_factor),
+            },
+            center,
+            label,
+        }
+    }
+
+    /// Tag a global position with its screen, clamping positions in gaps between screens
+    fn place_on_screen(&self, position: Position) -> Position {
+        ScreenManager::place_on_screens(&self.screens, position)
+    }
+
     /// Process input for area mode
     pub fn process_input(
         &mut self,
@@ -142,7 +545,7 @@ impl AreaMode {
         }
 
         let key_char = input.key.to_lowercase().next().unwrap_or(input.key);
-        let now = std::time::Instant::now();
+        let now = self.clock.now();
 
         // Handle exit keys
         if key_char == ' ' || input.key == '\x1b' {
@@ -151,17 +554,25 @@ impl AreaMode {
             return Ok(Action::DeactivateMode);
         }
 
-        // Valid area keys
-        let area_keys = ['q', 'w', 'e', 'a', 's', 'd', 'z', 'x', 'c'];
+        // Screen number prefix selects the screen for the following area keys
+        if self.first_key.is_none() {
+            if let Some(index) = self.screen_prefix_index(key_char) {
+                debug!("Screen {} selected for area mode", index + 1);
+                self.selected_screen = Some(index);
+                self.calculate_areas();
+                return Ok(Action::NoAction);
+            }
+        }
 
-        if !area_keys.contains(&key_char) {
+        // Valid area keys
+        if !self.areas.contains_key(&key_char) {
             debug!("Invalid area key: {}", key_char);
             return Ok(Action::NoAction);
         }
 
         // Check for timeout (800ms for better UX)
         if let Some(first_time) = self.first_key_time {
-            if now.duration_since(first_time).as_millis() > 800 {
+            if now.saturating_sub(first_time).as_millis() > 800 {
                 debug!("Area key combination timeout, resetting");
                 self.reset_combination_state();
             }
@@ -193,7 +604,7 @@ impl AreaMode {
 
                 if let Some(position) = combination_position {
                     debug!("Moving to combination position: {:?}", position);
-                    return Ok(Action::MoveCursor(position, AnimationType::Smooth));
+                    return Ok(Action::MoveCursor(position, self.animation_type));
                 } else {
                     // Invalid combination, move to second key's area
                     if let Some(area) = self.areas.get(&key_char) {
@@ -202,7 +613,7 @@ impl AreaMode {
                             "Invalid combination, moving to second key area: {}",
                             key_char.to_uppercase()
                         );
-                        return Ok(Action::MoveCursor(center, AnimationType::Smooth));
+                        return Ok(Action::MoveCursor(center, self.animation_type));
                     }
                 }
             }
@@ -215,8 +626,8 @@ impl AreaMode {
     pub fn handle_timeout(&mut self) -> Result<Action> {
         if let Some(first_key) = self.first_key {
             if let Some(first_time) = self.first_key_time {
-                let now = std::time::Instant::now();
-                if now.duration_since(first_time).as_millis() > 400 {
+                let now = self.clock.now();
+                if now.saturating_sub(first_time).as_millis() > 400 {
                     // Move to the first key's area after timeout
                     if let Some(area) = self.areas.get(&first_key) {
                         let center = area.center.clone();
@@ -226,7 +637,7 @@ impl AreaMode {
                             center
                         );
                         self.reset_combination_state();
-                        return Ok(Action::MoveCursor(center, AnimationType::Smooth));
+                        return Ok(Action::MoveCursor(center, self.animation_type));
                     }
                 }
             }
@@ -235,6 +646,11 @@ impl AreaMode {
     }
 
     /// Calculate position for area key combinations (e.g., Q+E)
+    ///
+    /// Areas that share columns meet at the horizontal edge between them,
+    /// areas that share rows at the vertical edge, and any other pair at the
+    /// corner of the first area facing the second. Pressing a key twice
+    /// selects the center of its area.
     fn calculate_combination_position(
         &self,
         first_key: char,
@@ -242,34 +658,40 @@ impl AreaMode {
     ) -> Option<Position> {
         let area1 = self.areas.get(&first_key)?;
         let area2 = self.areas.get(&second_key)?;
+        let (b1, b2) = (&area1.bounds, &area2.bounds);
 
-        // Get area positions in grid (0-2 for each axis)
-        let (col1, row1) = self.get_area_grid_position(first_key)?;
-        let (col2, row2) = self.get_area_grid_position(second_key)?;
+        let right = |b: &ScreenBounds| b.x + b.width as i32;
+        let bottom = |b: &ScreenBounds| b.y + b.height as i32;
+        let overlaps_x = b1.x < right(b2) && b2.x < right(b1);
+        let overlaps_y = b1.y < bottom(b2) && b2.y < bottom(b1);
+        let first_left = area1.center.x < area2.center.x;
+        let first_above = area1.center.y < area2.center.y;
 
         // Calculate intersection based on area relationships
-        let intersection_pos = if col1 == col2 {
+        let intersection_pos = if first_key == second_key {
+            area1.center
+        } else if overlaps_x {
             // Same column - vertical intersection
-            let y = if row1 < row2 {
+            let y = if first_above {
                 // First area is above second - intersection at bottom of first/top of second
-                area1.bounds.y + area1.bounds.height as i32
+                bottom(b1)
             } else {
                 // First area is below second - intersection at top of first/bottom of second
-                area2.bounds.y + area2.bounds.height as i32
+                bottom(b2)
             };
             Position {
                 x: area1.center.x, // Same column, use x center
                 y,
                 screen_id: None,
             }
-        } else if row1 == row2 {
+        } else if overlaps_y {
             // Same row - horizontal intersection
-            let x = if col1 < col2 {
+            let x = if first_left {
                 // First area is left of second - intersection at right of first/left of second
-                area1.bounds.x + area1.bounds.width as i32
+                right(b1)
             } else {
                 // First area is right of second - intersection at left of first/right of second
-                area2.bounds.x + area2.bounds.width as i32
+                right(b2)
             };
             Position {
                 x,
@@ -278,16 +700,8 @@ impl AreaMode {
             }
         } else {
             // Diagonal intersection - use corner point
-            let x = if col1 < col2 {
-                area1.bounds.x + area1.bounds.width as i32
-            } else {
-                area1.bounds.x
-            };
-            let y = if row1 < row2 {
-                area1.bounds.y + area1.bounds.height as i32
-            } else {
-                area1.bounds.y
-            };
+            let x = if first_left { right(b1) } else { b1.x };
+            let y = if first_above { bottom(b1) } else { b1.y };
             Position {
                 x,
                 y,
@@ -295,34 +709,35 @@ impl AreaMode {
             }
         };
 
+        let intersection_pos = self.place_on_screen(intersection_pos);
+
         debug!(
-            "Combination {} + {} -> grid positions ({},{}) + ({},{}) -> intersection {:?}",
+            "Combination {} + {} -> intersection {:?}",
             first_key.to_uppercase(),
             second_key.to_uppercase(),
-            col1,
-            row1,
-            col2,
-            row2,
             intersection_pos
         );
 
         Some(intersection_pos)
     }
 
-    /// Get grid position (column, row) for an area key
-    fn get_area_grid_position(&self, key: char) -> Option<(usize, usize)> {
-        match key {
-            'q' => Some((0, 0)),
-            'w' => Some((1, 0)),
-            'e' => Some((2, 0)), // Top row
-            'a' => Some((0, 1)),
-            's' => Some((1, 1)),
-            'd' => Some((2, 1)), // Middle row
-            'z' => Some((0, 2)),
-            'x' => Some((1, 2)),
-            'c' => Some((2, 2)), // Bottom row
-            _ => None,
-        }
+    /// Get grid position (column, row) for an area key of a grid layout
+    pub fn get_area_grid_position(&self, key: char) -> Option<(usize, usize)> {
+        let AreaLayout::Grid {
+            rows,
+            columns,
+            keys,
+        } = &self.layout
+        else {
+            return None;
+        };
+
+        let key = key.to_lowercase().next().unwrap_or(key);
+        let index = AreaLayout::grid_keys(*rows, *columns, keys, &self.keyboard_layout)
+            .iter()
+            .position(|&k| k == key)?;
+
+        Some((index % *columns as usize, index / *columns as usize))
     }
 }
 
@@ -332,9 +747,41 @@ impl Default for AreaMode {
     }
 }
 
+impl Mode for AreaMode {
+    fn name(&self) -> &str {
+        InteractionMode::Area.name()
+    }
+
+    fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    fn activate(&mut self) {
+        AreaMode::activate(self)
+    }
+
+    fn deactivate(&mut self) {
+        AreaMode::deactivate(self)
+    }
+
+    fn process_input(&mut self, input: KeyInput, bindings: &KeyBindings) -> Result<Action> {
+        AreaMode::process_input(self, input, bindings)
+    }
+
+    fn overlay(&self) -> ModeOverlay {
+        ModeOverlay::Areas(self.get_all_areas())
+    }
+
+    /// Move to the first area when no second key follows in time
+    fn tick(&mut self) -> Result<Action> {
+        self.handle_timeout()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::animation_scheduler::{AnimationClock, VirtualClock};
 
     fn create_test_input(key: char) -> KeyInput {
         KeyInput {
@@ -386,6 +833,8 @@ mod tests {
     fn test_area_input_processing() {
         let mut mode = AreaMode::new();
         let bindings = KeyBindings::default();
+        let clock = Arc::new(VirtualClock::new());
+        mode.set_clock(clock.clone());
 
         mode.set_screen_dimensions(1920.0, 1080.0);
         mode.activate();
@@ -400,9 +849,8 @@ mod tests {
         assert_eq!(mode.first_key, Some('q'));
         assert!(mode.first_key_time.is_some());
 
-        // Manually set the time to simulate timeout
-        mode.first_key_time =
-            Some(std::time::Instant::now() - std::time::Duration::from_millis(500));
+        // Let the combination time out
+        clock.advance(Duration::from_millis(500));
 
         // Test timeout handling - should move to area after timeout
         let action = mode.handle_timeout().unwrap();
@@ -463,6 +911,262 @@ mod tests {
         assert_eq!(mode.get_area_grid_position('f'), None);
     }
 
+    #[test]
+    fn test_area_keys_follow_layout() {
+        let mut mode = AreaMode::new();
+        mode.set_screen_dimensions(1920.0, 1080.0);
+        mode.set_keyboard_layout(KeyboardLayout::azerty());
+
+        // AZERTY top-left keys are A/Z/E, Q/S/D, W/X/C
+        assert_eq!(mode.get_area_grid_position('a'), Some((0, 0)));
+        assert_eq!(mode.get_area_grid_position('q'), Some((0, 1)));
+        assert_eq!(mode.get_area_grid_position('w'), Some((0, 2)));
+        assert_eq!(mode.areas.len(), 9);
+
+        let a_area = mode.areas.get(&'a').unwrap();
+        assert_eq!(a_area.center.x, 320);
+        assert_eq!(a_area.center.y, 180);
+
+        // Keys outside the area block are rejected
+        mode.activate();
+        let action = mode
+            .process_input(create_test_input('r'), &KeyBindings::default())
+            .unwrap();
+        assert_eq!(action, Action::NoAction);
+    }
+
+    #[test]
+    fn test_custom_grid_layout() {
+        let mut mode = AreaMode::new();
+        mode.set_screen_dimensions(1920.0, 1080.0);
+        mode.set_layout(AreaLayout::Grid {
+            rows: 2,
+            columns: 4,
+            keys: Some("QWERASDF".to_string()),
+        })
+        .unwrap();
+
+        assert_eq!(mode.areas.len(), 8);
+        assert_eq!(mode.get_area_grid_position('r'), Some((3, 0)));
+        assert_eq!(mode.get_area_grid_position('s'), Some((1, 1)));
+
+        let f_area = mode.areas.get(&'f').unwrap();
+        assert_eq!(f_area.bounds.x, 1440);
+        assert_eq!(f_area.bounds.y, 540);
+        assert_eq!(f_area.center.x, 1680);
+        assert_eq!(f_area.center.y, 810);
+
+        // Vertical neighbours meet at the row boundary
+        let pos = mode.calculate_combination_position('w', 's').unwrap();
+        assert_eq!(pos.x, 720);
+        assert_eq!(pos.y, 540);
+
+        // Four rows without keys use the number row on top
+        mode.set_layout(AreaLayout::Grid {
+            rows: 4,
+            columns: 2,
+            keys: None,
+        })
+        .unwrap();
+        assert_eq!(mode.get_area_grid_position('1'), Some((0, 0)));
+        assert_eq!(mode.get_area_grid_position('x'), Some((1, 3)));
+    }
+
+    #[test]
+    fn test_region_layout() {
+        let mut mode = AreaMode::new();
+        mode.set_screen_dimensions(1920.0, 1080.0);
+
+        let region = |name: &str, key, x, y, width, height| AreaRegion {
+            name: name.to_string(),
+            key,
+            x,
+            y,
+            width,
+            height,
+        };
+        mode.set_layout(AreaLayout::Regions {
+            regions: vec![
+                region("sidebar", 's', 0.0, 0.0, 0.25, 1.0),
+                region("editor", 'e', 0.25, 0.0, 0.75, 0.75),
+                region("terminal", 't', 0.25, 0.75, 0.75, 0.25),
+            ],
+        })
+        .unwrap();
+
+        assert_eq!(mode.areas.len(), 3);
+        assert_eq!(mode.get_area_grid_position('s'), None);
+
+        let editor = mode.areas.get(&'e').unwrap();
+        assert_eq!(editor.label, "editor");
+        assert_eq!(editor.bounds.x, 480);
+        assert_eq!(editor.bounds.width, 1440);
+        assert_eq!(editor.center.y, 405);
+
+        // Sidebar and editor share rows: meet at the sidebar's right edge
+        let pos = mode.calculate_combination_position('s', 'e').unwrap();
+        assert_eq!(pos.x, 480);
+        assert_eq!(pos.y, 540);
+
+        // Editor above terminal: meet at the editor's bottom edge
+        let pos = mode.calculate_combination_position('e', 't').unwrap();
+        assert_eq!(pos.x, 1200);
+        assert_eq!(pos.y, 810);
+
+        // Keys outside the layout are ignored
+        mode.activate();
+        let action = mode
+            .process_input(create_test_input('q'), &KeyBindings::default())
+            .unwrap();
+        assert_eq!(action, Action::NoAction);
+    }
+
+    #[test]
+    fn test_invalid_layouts() {
+        let mut mode = AreaMode::new();
+
+        // Wrong number of keys
+        let layout = AreaLayout::Grid {
+            rows: 2,
+            columns: 2,
+            keys: Some("abc".to_string()),
+        };
+        assert!(mode.set_layout(layout).is_err());
+
+        // Too many columns for the keyboard layout without explicit keys
+        let layout = AreaLayout::Grid {
+            rows: 2,
+            columns: 12,
+            keys: None,
+        };
+        assert!(mode.set_layout(layout).is_err());
+
+        // Region outside the screen and duplicate keys
+        let region = AreaRegion {
+            name: "wide".to_string(),
+            key: 'w',
+            x: 0.5,
+            y: 0.0,
+            width: 0.6,
+            height: 1.0,
+        };
+        let layout = AreaLayout::Regions {
+            regions: vec![region.clone()],
+        };
+        assert!(mode.set_layout(layout).is_err());
+
+        let narrow = AreaRegion {
+            width: 0.2,
+            ..region
+        };
+        let layout = AreaLayout::Regions {
+            regions: vec![narrow.clone(), narrow],
+        };
+        assert!(mode.set_layout(layout).is_err());
+
+        // The previous layout is kept
+        assert_eq!(mode.layout(), &AreaLayout::default());
+    }
+
+    fn create_test_screens() -> Vec<ScreenBounds> {
+        vec![
+            ScreenBounds {
+                id: 1,
+                x: 0,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: true,
+                scale_factor: 1.0,
+            },
+            ScreenBounds {
+                id: 2,
+                x: -1200,
+                y: -300,
+                width: 1200,
+                height: 900,
+                is_primary: false,
+                scale_factor: 1.0,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_per_screen_areas() {
+        let mut mode = AreaMode::new();
+        let bindings = KeyBindings::default();
+        mode.set_screens(create_test_screens());
+        mode.activate();
+
+        // Without prefix the primary screen is used
+        let s_area = mode.areas.get(&'s').unwrap();
+        assert_eq!(s_area.center, Position::with_screen(960, 540, 1));
+        assert_eq!(mode.get_all_areas().len(), 18);
+
+        // Screen prefix selects the second screen
+        let action = mode
+            .process_input(create_test_input('2'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::NoAction);
+
+        let q_area = mode.areas.get(&'q').unwrap();
+        assert_eq!(q_area.bounds.id, 2);
+        assert_eq!((q_area.bounds.x, q_area.bounds.y), (-1200, -300));
+        assert_eq!(q_area.center, Position::with_screen(-1000, -150, 2));
+
+        // Combinations produce global coordinates on the selected screen
+        mode.process_input(create_test_input('q'), &bindings)
+            .unwrap();
+        let action = mode
+            .process_input(create_test_input('e'), &bindings)
+            .unwrap();
+        assert_eq!(
+            action,
+            Action::MoveCursor(Position::with_screen(-800, -150, 2), AnimationType::Smooth)
+        );
+
+        // Screen numbers beyond the connected screens are ignored
+        let action = mode
+            .process_input(create_test_input('3'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::NoAction);
+        assert_eq!(mode.areas.get(&'q').unwrap().bounds.id, 2);
+
+        // Reactivating returns to the active screen
+        mode.activate();
+        assert_eq!(mode.areas.get(&'q').unwrap().bounds.id, 1);
+        mode.set_active_screen(2).unwrap();
+        assert_eq!(mode.areas.get(&'q').unwrap().bounds.id, 2);
+        assert!(mode.set_active_screen(9).is_err());
+    }
+
+    #[test]
+    fn test_desktop_spanning_areas() {
+        let mut mode = AreaMode::new();
+        mode.set_screens(create_test_screens());
+        mode.set_multi_screen_layout(MultiScreenLayout::SpanDesktop);
+
+        // Desktop spans x -1200..1920 and y -300..1080
+        assert_eq!(mode.areas.len(), 9);
+        assert_eq!(mode.get_all_areas().len(), 9);
+
+        let q_area = mode.areas.get(&'q').unwrap();
+        assert_eq!((q_area.bounds.x, q_area.bounds.y), (-1200, -300));
+        assert_eq!(q_area.center, Position::with_screen(-680, -70, 2));
+
+        // The bottom-left area's center lies below the second screen and is clamped onto it
+        let z_area = mode.areas.get(&'z').unwrap();
+        assert_eq!(z_area.center, Position::with_screen(-680, 599, 2));
+
+        // Digits are not screen prefixes when spanning the desktop
+        mode.activate();
+        let action = mode
+            .process_input(create_test_input('2'), &KeyBindings::default())
+            .unwrap();
+        assert_eq!(action, Action::NoAction);
+        assert_eq!(mode.selected_screen, None);
+    }
+
     #[test]
     fn test_combination_input_processing() {
         let mut mode = AreaMode::new();
@@ -493,13 +1197,15 @@ mod tests {
     #[test]
     fn test_timeout_handling() {
         let mut mode = AreaMode::new();
+        let clock = Arc::new(VirtualClock::new());
+        mode.set_clock(clock.clone());
         mode.set_screen_dimensions(1920.0, 1080.0);
         mode.activate();
 
         // Simulate first key press
         mode.first_key = Some('q');
-        mode.first_key_time =
-            Some(std::time::Instant::now() - std::time::Duration::from_millis(500));
+        mode.first_key_time = Some(clock.now());
+        clock.advance(Duration::from_millis(500));
 
         // Test timeout handling
         let action = mode.handle_timeout().unwrap();
@@ -1,11 +1,13 @@
 use tracing::{debug, info};
 
 use crate::{
+    config::MovementConfig,
     error::Result,
     models::{
-        Action, AnimationType, InteractionMode, KeyInput, MouseButton, Position, ScrollDirection,
+        Action, AnimationType, InteractionMode, KeyInput, MouseButton, MovementSpeed, ScreenTarget,
+        ScrollDirection,
     },
-    traits::KeyBindings,
+    traits::{KeyBindings, Mode},
 };
 
 /// Basic movement mode implementation
@@ -14,54 +16,89 @@ use crate::{
 /// U/O/Y/P keys for scrolling operations
 /// B key for click-and-hold toggle functionality
 pub struct BasicMode {
-    /// Current movement speed multiplier
-    movement_speed: f32,
-
-    /// Whether fast mode is enabled
-    fast_mode: bool,
+    /// Current movement speed tier
+    speed: MovementSpeed,
 
     /// Current hold state for click-and-hold
     hold_state: bool,
 
-    /// Base movement distance in pixels
-    base_movement_distance: i32,
+    /// Step sizes and speed tiers
+    movement: MovementConfig,
 
-    /// Base scroll amount
-    base_scroll_amount: i32,
+    /// Animation used for cursor movement
+    animation_type: AnimationType,
 }
 
 impl BasicMode {
     /// Create a new basic mode instance
     pub fn new() -> Self {
+        let movement = MovementConfig::default();
+
         Self {
-            movement_speed: 1.0,
-            fast_mode: false,
+            speed: movement.default_speed,
             hold_state: false,
-            base_movement_distance: 20,
-            base_scroll_amount: 3,
+            movement,
+            animation_type: AnimationType::Smooth,
         }
     }
 
-    /// Set movement speed multiplier
-    pub fn set_movement_speed(&mut self, speed: f32) {
-        self.movement_speed = speed.max(0.1).min(10.0); // Clamp between 0.1 and 10.0
-        debug!("Movement speed set to: {}", self.movement_speed);
+    /// Set the animation used for cursor movement
+    pub fn set_animation_type(&mut self, animation_type: AnimationType) {
+        self.animation_type = animation_type;
+    }
+
+    /// Set the movement configuration and return to its default speed
+    pub fn set_movement_config(&mut self, movement: MovementConfig) {
+        self.speed = movement.default_speed;
+        self.movement = movement;
+        debug!("Movement configuration updated, speed: {:?}", self.speed);
     }
 
-    /// Get current movement speed
-    pub fn get_movement_speed(&self) -> f32 {
-        self.movement_speed
+    /// Get the movement configuration
+    pub fn movement_config(&self) -> &MovementConfig {
+        &self.movement
+    }
+
+    /// Set the movement speed tier
+    pub fn set_speed(&mut self, speed: MovementSpeed) {
+        self.speed = speed;
+        debug!("Movement speed set to: {:?}", self.speed);
+    }
+
+    /// Get the current movement speed tier
+    pub fn get_speed(&self) -> MovementSpeed {
+        self.speed
+    }
+
+    /// Toggle between a speed tier and the default speed
+    fn toggle_speed_tier(&mut self, speed: MovementSpeed) -> MovementSpeed {
+        self.speed = if self.speed == speed {
+            self.movement.default_speed
+        } else {
+            speed
+        };
+        info!("Movement speed toggled to: {:?}", self.speed);
+        self.speed
     }
 
     /// Toggle fast mode
     pub fn toggle_fast_mode(&mut self) {
-        self.fast_mode = !self.fast_mode;
-        info!("Fast mode toggled to: {}", self.fast_mode);
+        self.toggle_speed_tier(MovementSpeed::Fast);
     }
 
     /// Check if fast mode is enabled
     pub fn is_fast_mode(&self) -> bool {
-        self.fast_mode
+        self.speed == MovementSpeed::Fast
+    }
+
+    /// Toggle precision mode
+    pub fn toggle_precision_mode(&mut self) {
+        self.toggle_speed_tier(MovementSpeed::Precision);
+    }
+
+    /// Check if precision mode is enabled
+    pub fn is_precision_mode(&self) -> bool {
+        self.speed == MovementSpeed::Precision
     }
 
     /// Toggle hold state
@@ -83,24 +120,12 @@ impl BasicMode {
 
     /// Calculate actual movement distance based on current settings
     fn get_movement_distance(&self) -> i32 {
-        let speed_multiplier = if self.fast_mode {
-            self.movement_speed * 3.0 // Fast mode is 3x faster
-        } else {
-            self.movement_speed
-        };
-
-        (self.base_movement_distance as f32 * speed_multiplier) as i32
+        self.movement.step_pixels(self.speed)
     }
 
     /// Calculate actual scroll amount based on current settings
     fn get_scroll_amount(&self) -> i32 {
-        let speed_multiplier = if self.fast_mode {
-            self.movement_speed * 2.0 // Fast mode is 2x faster for scrolling
-        } else {
-            self.movement_speed
-        };
-
-        (self.base_scroll_amount as f32 * speed_multiplier) as i32
+        self.movement.scroll_amount(self.speed)
     }
 
     /// Process input for basic movement mode
@@ -112,33 +137,37 @@ impl BasicMode {
 
         if key == bindings.move_up {
             debug!("Processing move up command");
-            return Ok(Action::MoveCursor(
-                Position::new(0, -movement_distance),
-                AnimationType::Smooth,
+            return Ok(Action::MoveRelative(
+                0,
+                -movement_distance,
+                self.animation_type,
             ));
         }
 
         if key == bindings.move_down {
             debug!("Processing move down command");
-            return Ok(Action::MoveCursor(
-                Position::new(0, movement_distance),
-                AnimationType::Smooth,
+            return Ok(Action::MoveRelative(
+                0,
+                movement_distance,
+                self.animation_type,
             ));
         }
 
         if key == bindings.move_left {
             debug!("Processing move left command");
-            return Ok(Action::MoveCursor(
-                Position::new(-movement_distance, 0),
-                AnimationType::Smooth,
+            return Ok(Action::MoveRelative(
+                -movement_distance,
+                0,
+                self.animation_type,
             ));
         }
 
         if key == bindings.move_right {
             debug!("Processing move right command");
-            return Ok(Action::MoveCursor(
-                Position::new(movement_distance, 0),
-                AnimationType::Smooth,
+            return Ok(Action::MoveRelative(
+                movement_distance,
+                0,
+                self.animation_type,
             ));
         }
 
@@ -184,6 +213,17 @@ impl BasicMode {
             return Ok(Action::Scroll(ScrollDirection::Right, scroll_amount));
         }
 
+        // Page scroll keys, if configured
+        if bindings.page_up == Some(key) {
+            debug!("Processing page up command");
+            return Ok(Action::ScrollPage(ScrollDirection::Up));
+        }
+
+        if bindings.page_down == Some(key) {
+            debug!("Processing page down command");
+            return Ok(Action::ScrollPage(ScrollDirection::Down));
+        }
+
         // Hold toggle key (B)
         if key == bindings.hold_toggle {
             debug!("Processing hold toggle command");
@@ -194,33 +234,31 @@ impl BasicMode {
         // Speed toggle key (F)
         if key == bindings.speed_toggle {
             debug!("Processing speed toggle command");
-            self.toggle_fast_mode();
-            return Ok(Action::ToggleSpeed);
-        }
-
-        // Screen switching keys (1/2/3)
-        if key == bindings.screen_1 {
-            debug!("Processing screen 1 switch command");
-            return Ok(Action::MoveCursor(
-                Position::with_screen(0, 0, 1),
-                AnimationType::Smooth,
-            ));
+            let speed = self.toggle_speed_tier(MovementSpeed::Fast);
+            return Ok(Action::SetSpeed(speed));
         }
 
-        if key == bindings.screen_2 {
-            debug!("Processing screen 2 switch command");
-            return Ok(Action::MoveCursor(
-                Position::with_screen(0, 0, 2),
-                AnimationType::Smooth,
-            ));
+        if bindings.precision_toggle == Some(key) {
+            debug!("Processing precision toggle command");
+            let speed = self.toggle_speed_tier(MovementSpeed::Precision);
+            return Ok(Action::SetSpeed(speed));
         }
 
-        if key == bindings.screen_3 {
-            debug!("Processing screen 3 switch command");
-            return Ok(Action::MoveCursor(
-                Position::with_screen(0, 0, 3),
-                AnimationType::Smooth,
-            ));
+        // Screen switching keys (1/2/3, 9/0 and optional directions)
+        let screen_keys = [
+            (Some(bindings.screen_1), ScreenTarget::Number(1)),
+            (Some(bindings.screen_2), ScreenTarget::Number(2)),
+            (Some(bindings.screen_3), ScreenTarget::Number(3)),
+            (bindings.screen_next, ScreenTarget::Next),
+            (bindings.screen_previous, ScreenTarget::Previous),
+            (bindings.screen_left, ScreenTarget::Left),
+            (bindings.screen_right, ScreenTarget::Right),
+            (bindings.screen_up, ScreenTarget::Up),
+            (bindings.screen_down, ScreenTarget::Down),
+        ];
+        if let Some((_, target)) = screen_keys.iter().find(|(k, _)| *k == Some(key)) {
+            debug!("Processing screen switch command: {:?}", target);
+            return Ok(Action::SwitchScreen(*target, self.animation_type));
         }
 
         // Mode switching keys
@@ -250,10 +288,27 @@ impl BasicMode {
         Ok(Action::NoAction)
     }
 
+    /// Process the release of a key; releasing a scroll key ends its hold
+    pub fn process_key_release(&self, input: KeyInput, bindings: &KeyBindings) -> Result<Action> {
+        let scroll_keys = [
+            (bindings.scroll_up, ScrollDirection::Up),
+            (bindings.scroll_down, ScrollDirection::Down),
+            (bindings.scroll_left, ScrollDirection::Left),
+            (bindings.scroll_right, ScrollDirection::Right),
+        ];
+
+        match scroll_keys.iter().find(|(k, _)| *k == input.key) {
+            Some((_, direction)) => {
+                debug!("Processing scroll release: {:?}", direction);
+                Ok(Action::ScrollRelease(*direction))
+            }
+            None => Ok(Action::NoAction),
+        }
+    }
+
     /// Reset the mode to default state
     pub fn reset(&mut self) {
-        self.movement_speed = 1.0;
-        self.fast_mode = false;
+        self.speed = self.movement.default_speed;
         self.hold_state = false;
         info!("Basic mode reset to default state");
     }
@@ -261,8 +316,8 @@ impl BasicMode {
     /// Get current mode state as a string for debugging
     pub fn get_state_info(&self) -> String {
         format!(
-            "BasicMode {{ speed: {:.1}, fast: {}, holding: {} }}",
-            self.movement_speed, self.fast_mode, self.hold_state
+            "BasicMode {{ speed: {:?}, holding: {} }}",
+            self.speed, self.hold_state
         )
     }
 }
@@ -273,6 +328,20 @@ impl Default for BasicMode {
     }
 }
 
+impl Mode for BasicMode {
+    fn name(&self) -> &str {
+        InteractionMode::Basic.name()
+    }
+
+    fn process_input(&mut self, input: KeyInput, bindings: &KeyBindings) -> Result<Action> {
+        BasicMode::process_input(self, input, bindings)
+    }
+
+    fn process_key_release(&mut self, input: KeyInput, bindings: &KeyBindings) -> Result<Action> {
+        BasicMode::process_key_release(self, input, bindings)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,7 +358,7 @@ mod tests {
     #[test]
     fn test_basic_mode_creation() {
         let mode = BasicMode::new();
-        assert_eq!(mode.get_movement_speed(), 1.0);
+        assert_eq!(mode.get_speed(), MovementSpeed::Normal);
         assert!(!mode.is_fast_mode());
         assert!(!mode.is_holding());
     }
@@ -304,11 +373,11 @@ mod tests {
             .process_input(create_test_input('i'), &bindings)
             .unwrap();
         match action {
-            Action::MoveCursor(pos, AnimationType::Smooth) => {
-                assert_eq!(pos.x, 0);
-                assert_eq!(pos.y, -20); // Moving up
+            Action::MoveRelative(dx, dy, AnimationType::Smooth) => {
+                assert_eq!(dx, 0);
+                assert_eq!(dy, -20); // Moving up
             }
-            _ => panic!("Expected MoveCursor action for up movement"),
+            _ => panic!("Expected MoveRelative action for up movement"),
         }
 
         // Test down movement
@@ -316,11 +385,11 @@ mod tests {
             .process_input(create_test_input('k'), &bindings)
             .unwrap();
         match action {
-            Action::MoveCursor(pos, AnimationType::Smooth) => {
-                assert_eq!(pos.x, 0);
-                assert_eq!(pos.y, 20); // Moving down
+            Action::MoveRelative(dx, dy, AnimationType::Smooth) => {
+                assert_eq!(dx, 0);
+                assert_eq!(dy, 20); // Moving down
             }
-            _ => panic!("Expected MoveCursor action for down movement"),
+            _ => panic!("Expected MoveRelative action for down movement"),
         }
 
         // Test left movement
@@ -328,11 +397,11 @@ mod tests {
             .process_input(create_test_input('j'), &bindings)
             .unwrap();
         match action {
-            Action::MoveCursor(pos, AnimationType::Smooth) => {
-                assert_eq!(pos.x, -20); // Moving left
-                assert_eq!(pos.y, 0);
+            Action::MoveRelative(dx, dy, AnimationType::Smooth) => {
+                assert_eq!(dx, -20); // Moving left
+                assert_eq!(dy, 0);
             }
-            _ => panic!("Expected MoveCursor action for left movement"),
+            _ => panic!("Expected MoveRelative action for left movement"),
         }
 
         // Test right movement
@@ -340,11 +409,11 @@ mod tests {
             .process_input(create_test_input('l'), &bindings)
             .unwrap();
         match action {
-            Action::MoveCursor(pos, AnimationType::Smooth) => {
-                assert_eq!(pos.x, 20); // Moving right
-                assert_eq!(pos.y, 0);
+            Action::MoveRelative(dx, dy, AnimationType::Smooth) => {
+                assert_eq!(dx, 20); // Moving right
+                assert_eq!(dy, 0);
             }
-            _ => panic!("Expected MoveCursor action for right movement"),
+            _ => panic!("Expected MoveRelative action for right movement"),
         }
     }
 
@@ -402,6 +471,32 @@ mod tests {
         assert_eq!(action, Action::Scroll(ScrollDirection::Right, 3));
     }
 
+    #[test]
+    fn test_page_keys_and_scroll_release() {
+        let mut mode = BasicMode::new();
+        let bindings = KeyBindings {
+            page_up: Some('['),
+            page_down: Some(']'),
+            ..KeyBindings::default()
+        };
+
+        let action = mode
+            .process_input(create_test_input(']'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::ScrollPage(ScrollDirection::Down));
+
+        // Releasing a scroll key ends its hold, other releases do nothing
+        let action = mode
+            .process_key_release(create_test_input('u'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::ScrollRelease(ScrollDirection::Up));
+
+        let action = mode
+            .process_key_release(create_test_input('i'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::NoAction);
+    }
+
     #[test]
     fn test_hold_toggle() {
         let mut mode = BasicMode::new();
@@ -435,7 +530,7 @@ mod tests {
         let action = mode
             .process_input(create_test_input('f'), &bindings)
             .unwrap();
-        assert_eq!(action, Action::ToggleSpeed);
+        assert_eq!(action, Action::SetSpeed(MovementSpeed::Fast));
         assert!(mode.is_fast_mode());
 
         // Test that movement distance changes in fast mode
@@ -443,10 +538,10 @@ mod tests {
             .process_input(create_test_input('i'), &bindings)
             .unwrap();
         match action {
-            Action::MoveCursor(pos, _) => {
-                assert_eq!(pos.y, -60); // 3x faster in fast mode
+            Action::MoveRelative(_, dy, _) => {
+                assert_eq!(dy, -60); // 3x faster in fast mode
             }
-            _ => panic!("Expected MoveCursor action"),
+            _ => panic!("Expected MoveRelative action"),
         }
     }
 
@@ -459,23 +554,63 @@ mod tests {
         let action = mode
             .process_input(create_test_input('1'), &bindings)
             .unwrap();
-        match action {
-            Action::MoveCursor(pos, _) => {
-                assert_eq!(pos.screen_id, Some(1));
-            }
-            _ => panic!("Expected MoveCursor action for screen switch"),
-        }
+        assert_eq!(
+            action,
+            Action::SwitchScreen(ScreenTarget::Number(1), AnimationType::Smooth)
+        );
 
         // Test screen 2
         let action = mode
             .process_input(create_test_input('2'), &bindings)
             .unwrap();
-        match action {
-            Action::MoveCursor(pos, _) => {
-                assert_eq!(pos.screen_id, Some(2));
-            }
-            _ => panic!("Expected MoveCursor action for screen switch"),
-        }
+        assert_eq!(
+            action,
+            Action::SwitchScreen(ScreenTarget::Number(2), AnimationType::Smooth)
+        );
+
+        // Test next and previous screen
+        let action = mode
+            .process_input(create_test_input('0'), &bindings)
+            .unwrap();
+        assert_eq!(
+            action,
+            Action::SwitchScreen(ScreenTarget::Next, AnimationType::Smooth)
+        );
+        let action = mode
+            .process_input(create_test_input('9'), &bindings)
+            .unwrap();
+        assert_eq!(
+            action,
+            Action::SwitchScreen(ScreenTarget::Previous, AnimationType::Smooth)
+        );
+
+        // Directional switching needs configured keys
+        let action = mode
+            .process_input(create_test_input(';'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::NoAction);
+
+        let bindings = KeyBindings {
+            screen_right: Some(';'),
+            ..KeyBindings::default()
+        };
+        let action = mode
+            .process_input(create_test_input(';'), &bindings)
+            .unwrap();
+        assert_eq!(
+            action,
+            Action::SwitchScreen(ScreenTarget::Right, AnimationType::Smooth)
+        );
+
+        // Switches use the configured animation like other moves
+        mode.set_animation_type(AnimationType::Linear);
+        let action = mode
+            .process_input(create_test_input('1'), &bindings)
+            .unwrap();
+        assert_eq!(
+            action,
+            Action::SwitchScreen(ScreenTarget::Number(1), AnimationType::Linear)
+        );
     }
 
     #[test]
@@ -514,53 +649,83 @@ mod tests {
     }
 
     #[test]
-    fn test_movement_speed_setting() {
+    fn test_precision_toggle() {
         let mut mode = BasicMode::new();
+        let bindings = KeyBindings {
+            precision_toggle: Some('v'),
+            ..KeyBindings::default()
+        };
 
-        mode.set_movement_speed(2.0);
-        assert_eq!(mode.get_movement_speed(), 2.0);
+        let action = mode
+            .process_input(create_test_input('v'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::SetSpeed(MovementSpeed::Precision));
+        assert!(mode.is_precision_mode());
 
-        // Test clamping
-        mode.set_movement_speed(15.0);
-        assert_eq!(mode.get_movement_speed(), 10.0); // Clamped to max
+        let action = mode
+            .process_input(create_test_input('i'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::MoveRelative(0, -2, AnimationType::Smooth));
 
-        mode.set_movement_speed(0.05);
-        assert_eq!(mode.get_movement_speed(), 0.1); // Clamped to min
+        // Toggling again returns to the default speed
+        let action = mode
+            .process_input(create_test_input('v'), &bindings)
+            .unwrap();
+        assert_eq!(action, Action::SetSpeed(MovementSpeed::Normal));
     }
 
     #[test]
-    fn test_custom_movement_speed() {
+    fn test_movement_config() {
         let mut mode = BasicMode::new();
         let bindings = KeyBindings::default();
 
-        mode.set_movement_speed(2.0);
+        mode.set_movement_config(MovementConfig {
+            default_speed: MovementSpeed::Slow,
+            step_size: 40,
+            fast_speed_multiplier: 2.0,
+            ..MovementConfig::default()
+        });
+        assert_eq!(mode.get_speed(), MovementSpeed::Slow);
+
+        let action = mode
+            .process_input(create_test_input('i'), &bindings)
+            .unwrap();
+        match action {
+            Action::MoveRelative(_, dy, _) => {
+                assert_eq!(dy, -20); // Half of the configured step
+            }
+            _ => panic!("Expected MoveRelative action"),
+        }
 
+        mode.toggle_fast_mode();
         let action = mode
             .process_input(create_test_input('i'), &bindings)
             .unwrap();
         match action {
-            Action::MoveCursor(pos, _) => {
-                assert_eq!(pos.y, -40); // 2x speed
+            Action::MoveRelative(_, dy, _) => {
+                assert_eq!(dy, -80); // Configured fast multiplier
             }
-            _ => panic!("Expected MoveCursor action"),
+            _ => panic!("Expected MoveRelative action"),
         }
+
+        // Leaving fast mode goes back to the configured default
+        mode.toggle_fast_mode();
+        assert_eq!(mode.get_speed(), MovementSpeed::Slow);
     }
 
     #[test]
     fn test_reset() {
         let mut mode = BasicMode::new();
 
-        mode.set_movement_speed(2.0);
         mode.toggle_fast_mode();
         mode.toggle_hold_state();
 
-        assert_eq!(mode.get_movement_speed(), 2.0);
         assert!(mode.is_fast_mode());
         assert!(mode.is_holding());
 
         mode.reset();
 
-        assert_eq!(mode.get_movement_speed(), 1.0);
+        assert_eq!(mode.get_speed(), MovementSpeed::Normal);
         assert!(!mode.is_fast_mode());
         assert!(!mode.is_holding());
     }
@@ -580,8 +745,7 @@ mod tests {
     fn test_state_info() {
         let mode = BasicMode::new();
         let info = mode.get_state_info();
-        assert!(info.contains("speed: 1.0"));
-        assert!(info.contains("fast: false"));
+        assert!(info.contains("speed: Normal"));
         assert!(info.contains("holding: false"));
     }
 }
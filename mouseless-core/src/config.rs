@@ -11,9 +11,21 @@ use std::path::{Path, PathBuf};
 use tracing::{info, warn};
 
 use crate::{
+    animation::AnimationTiming,
+    area_mode::AreaLayout,
     error::{ConfigError, ConfigResult},
+    hints::HintGenerator,
     input::ActivationConfig,
-    models::{AnimationType, MovementSpeed},
+    keyboard_layout::KeyboardConfig,
+    macros::MacroConfig,
+    marks::Marks,
+    models::{
+        AnimationType, GridConfig, ModePathConfig, MovementSpeed, MultiScreenLayout, PathConfig,
+        PathStyle,
+    },
+    scripting::ScriptConfig,
+    scroll::ScrollConfig,
+    session_log::SessionLogConfig,
     traits::{KeyBindings, Theme},
 };
 
@@ -26,10 +38,38 @@ pub struct AppConfig {
     pub movement: MovementConfig,
     /// UI settings
     pub ui: UIConfig,
-    /// Key bindings
-    pub keybindings: KeyBindings,
+    /// Key bindings, derived from the keyboard layout when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keybindings: Option<KeyBindings>,
     /// Theme settings
     pub theme: Theme,
+    /// Keyboard layout settings
+    #[serde(default)]
+    pub keyboard: KeyboardConfig,
+    /// Grid mode layout and labels
+    #[serde(default)]
+    pub grid: GridConfig,
+    /// Area mode layout
+    #[serde(default)]
+    pub area: AreaLayout,
+    /// How grid and area mode cover several screens
+    #[serde(default)]
+    pub multi_screen: MultiScreenLayout,
+    /// Scrolling settings
+    #[serde(default)]
+    pub scroll: ScrollConfig,
+    /// Named cursor positions
+    #[serde(default)]
+    pub marks: Marks,
+    /// Scripts and the keys that run them
+    #[serde(default)]
+    pub scripting: ScriptConfig,
+    /// Macro recording and playback
+    #[serde(default)]
+    pub macros: MacroConfig,
+    /// Session log for bug reports
+    #[serde(default)]
+    pub session_log: SessionLogConfig,
 }
 
 impl Default for AppConfig {
@@ -38,8 +78,41 @@ impl Default for AppConfig {
             activation: ActivationConfig::default(),
             movement: MovementConfig::default(),
             ui: UIConfig::default(),
-            keybindings: KeyBindings::default(),
+            keybindings: None,
             theme: Theme::default(),
+            keyboard: KeyboardConfig::default(),
+            grid: GridConfig::default(),
+            area: AreaLayout::default(),
+            multi_screen: MultiScreenLayout::default(),
+            scroll: ScrollConfig::default(),
+            marks: Marks::default(),
+            scripting: ScriptConfig::default(),
+            macros: MacroConfig::default(),
+            session_log: SessionLogConfig::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// The key bindings, derived from the keyboard layout unless set explicitly
+    pub fn key_bindings(&self) -> ConfigResult<KeyBindings> {
+        match &self.keybindings {
+            Some(bindings) => Ok(bindings.clone()),
+            None => KeyBindings::for_layout(&self.keyboard.resolve_layout()?),
+        }
+    }
+
+    /// Build the cursor animation timing from the movement and UI settings
+    pub fn animation_timing(&self) -> AnimationTiming {
+        AnimationTiming {
+            base_duration_ms: self.ui.animation_duration_ms,
+            min_duration_ms: self.movement.min_animation_ms,
+            max_duration_ms: self.movement.max_animation_ms,
+            refresh_rate_hz: self.movement.refresh_rate_hz,
+            instant_distance_px: self.movement.instant_distance_px,
+            fast_speed_multiplier: self.movement.fast_speed_multiplier,
+            slow_speed_multiplier: self.movement.slow_speed_multiplier,
+            precision_speed_multiplier: self.movement.precision_speed_multiplier,
         }
     }
 }
@@ -53,20 +126,94 @@ pub struct MovementConfig {
     pub fast_speed_multiplier: f32,
     /// Slow movement multiplier
     pub slow_speed_multiplier: f32,
+    /// Precision movement multiplier
+    #[serde(default = "default_precision_speed_multiplier")]
+    pub precision_speed_multiplier: f32,
     /// Default animation type
     pub default_animation: AnimationType,
-    /// Movement step size in pixels
+    /// Movement step size in pixels at normal speed
     pub step_size: i32,
+    /// Scroll amount at normal speed
+    #[serde(default = "default_scroll_step")]
+    pub scroll_step: i32,
+    /// Cursor path shape and easing per interaction mode
+    #[serde(default)]
+    pub paths: ModePathConfig,
+    /// Shortest cursor animation in milliseconds
+    #[serde(default = "default_min_animation_ms")]
+    pub min_animation_ms: u64,
+    /// Longest cursor animation in milliseconds
+    #[serde(default = "default_max_animation_ms")]
+    pub max_animation_ms: u64,
+    /// Display refresh rate, one animation step per frame
+    #[serde(default = "default_refresh_rate_hz")]
+    pub refresh_rate_hz: u32,
+    /// Movements shorter than this many pixels are instant
+    #[serde(default = "default_instant_distance_px")]
+    pub instant_distance_px: f64,
+}
+
+fn default_precision_speed_multiplier() -> f32 {
+    0.1
+}
+
+fn default_scroll_step() -> i32 {
+    3
+}
+
+impl MovementConfig {
+    /// Get the multiplier of a speed tier relative to normal speed
+    pub fn speed_multiplier(&self, speed: MovementSpeed) -> f32 {
+        match speed {
+            MovementSpeed::Precision => self.precision_speed_multiplier,
+            MovementSpeed::Slow => self.slow_speed_multiplier,
+            MovementSpeed::Normal => 1.0,
+            MovementSpeed::Fast => self.fast_speed_multiplier,
+        }
+    }
+
+    /// Get the cursor step in pixels at a speed tier
+    pub fn step_pixels(&self, speed: MovementSpeed) -> i32 {
+        ((self.step_size as f32 * self.speed_multiplier(speed)).round() as i32).max(1)
+    }
+
+    /// Get the scroll amount at a speed tier
+    pub fn scroll_amount(&self, speed: MovementSpeed) -> i32 {
+        ((self.scroll_step as f32 * self.speed_multiplier(speed)).round() as i32).max(1)
+    }
+}
+
+fn default_min_animation_ms() -> u64 {
+    AnimationTiming::default().min_duration_ms
+}
+
+fn default_max_animation_ms() -> u64 {
+    AnimationTiming::default().max_duration_ms
+}
+
+fn default_refresh_rate_hz() -> u32 {
+    AnimationTiming::default().refresh_rate_hz
+}
+
+fn default_instant_distance_px() -> f64 {
+    AnimationTiming::default().instant_distance_px
 }
 
 impl Default for MovementConfig {
     fn default() -> Self {
         Self {
             default_speed: MovementSpeed::Normal,
-            fast_speed_multiplier: 2.0,
+            fast_speed_multiplier: 3.0,
             slow_speed_multiplier: 0.5,
+            precision_speed_multiplier: default_precision_speed_multiplier(),
             default_animation: AnimationType::Smooth,
-            step_size: 10,
+            step_size: 20,
+            scroll_step: default_scroll_step(),
+            paths: ModePathConfig::default(),
+            min_animation_ms: default_min_animation_ms(),
+            max_animation_ms: default_max_animation_ms(),
+            refresh_rate_hz: default_refresh_rate_hz(),
+            instant_distance_px: default_instant_distance_px(),
         }
     }
 }
@@ -145,31 +292,45 @@ impl ConfigManager {
             return Ok(());
         }
 
+        let config = self.read_config()?;
+
+        // Validate the loaded configuration
+        self.validate_config(&config)?;
+
+        self.current_config = config;
+        info!("Loaded configuration from: {:?}", self.config_path);
+        Ok(())
+    }
+
+    /// Parse and validate a configuration file without loading it
+    ///
+    /// Unlike `load`, a missing file is an error rather than created.
+    pub fn check<P: AsRef<Path>>(config_path: P) -> ConfigResult<AppConfig> {
+        let manager = Self::new(config_path);
+        let config = manager.read_config()?;
+        manager.validate_config(&config)?;
+        Ok(config)
+    }
+
+    /// Read and parse the configuration file, TOML or JSON by extension
+    fn read_config(&self) -> ConfigResult<AppConfig> {
         let content =
             fs::read_to_string(&self.config_path).map_err(|e| ConfigError::LoadFailed {
                 path: self.config_path.display().to_string(),
                 reason: e.to_string(),
             })?;
 
-        // Try to parse as JSON first, then TOML
-        let config = if self.config_path.extension().and_then(|s| s.to_str()) == Some("toml") {
+        if self.config_path.extension().and_then(|s| s.to_str()) == Some("toml") {
             toml::from_str(&content).map_err(|e| ConfigError::LoadFailed {
                 path: self.config_path.display().to_string(),
                 reason: format!("TOML parsing error: {}", e),
-            })?
+            })
         } else {
             serde_json::from_str(&content).map_err(|e| ConfigError::LoadFailed {
                 path: self.config_path.display().to_string(),
                 reason: format!("JSON parsing error: {}", e),
-            })?
-        };
-
-        // Validate the loaded configuration
-        self.validate_config(&config)?;
-
-        self.current_config = config;
-        info!("Loaded configuration from: {:?}", self.config_path);
-        Ok(())
+            })
+        }
     }
 
     /// Save configuration to file
@@ -230,7 +391,7 @@ impl ConfigManager {
             }
         })?;
 
-        self.current_config.keybindings = bindings;
+        self.current_config.keybindings = Some(bindings);
         Ok(())
     }
 
@@ -265,7 +426,8 @@ impl ConfigManager {
     /// Validate entire configuration
     fn validate_config(&self, config: &AppConfig) -> ConfigResult<()> {
         // Validate key bindings
-        crate::input::InputHandler::validate_key_bindings(&config.keybindings).map_err(|e| {
+        let bindings = config.key_bindings()?;
+        crate::input::InputHandler::validate_key_bindings(&bindings).map_err(|e| {
             ConfigError::ValidationFailed {
                 reason: format!("Key binding validation failed: {}", e),
             }
@@ -276,10 +438,53 @@ impl ConfigManager {
         self.validate_movement_config(&config.movement)?;
         self.validate_ui_config(&config.ui)?;
         self.validate_theme(&config.theme)?;
+        config.keyboard.resolve_layout()?;
+        self.validate_grid_config(&config.grid)?;
+        config.area.validate()?;
+        config.scroll.validate()?;
+        config.marks.validate()?;
+        config.scripting.validate()?;
+        config.macros.validate()?;
+        config.session_log.validate()?;
+        self.validate_host_keys(config, &bindings)?;
+
+        Ok(())
+    }
+
+    /// Validate that macro and script keys do not shadow a binding
+    ///
+    /// These keys are checked before the active mode sees a key, so a
+    /// collision would make the binding unreachable.
+    fn validate_host_keys(&self, config: &AppConfig, bindings: &KeyBindings) -> ConfigResult<()> {
+        let mut taken = bindings.bound_keys();
+        let macro_keys = [
+            ("macros.record_key", config.macros.record_key),
+            ("macros.play_key", config.macros.play_key),
+        ];
+        for (field, key) in macro_keys {
+            if let Some(key) = key {
+                Self::check_key_free(&taken, field, key)?;
+                taken.push((field, key));
+            }
+        }
+
+        // Scripts may share a key between modes, so they only check the others
+        for binding in &config.scripting.bindings {
+            Self::check_key_free(&taken, "scripting.bindings.key", binding.key)?;
+        }
 
         Ok(())
     }
 
+    fn check_key_free(taken: &[(&str, char)], field: &str, key: char) -> ConfigResult<()> {
+        match taken.iter().find(|(_, taken)| *taken == key) {
+            Some((name, _)) => Err(ConfigError::ValidationFailed {
+                reason: format!("{} '{}' is already bound to {}", field, key, name),
+            }),
+            None => Ok(()),
+        }
+    }
+
     /// Validate activation configuration
     fn validate_activation_config(&self, activation: &ActivationConfig) -> ConfigResult<()> {
         if activation.double_click_timeout_ms == 0 {
@@ -306,6 +511,35 @@ impl ConfigManager {
         Ok(())
     }
 
+    /// Validate grid configuration
+    fn validate_grid_config(&self, grid: &GridConfig) -> ConfigResult<()> {
+        for (field, value) in [("grid.rows", grid.rows), ("grid.columns", grid.columns)] {
+            if value == 0 {
+                return Err(ConfigError::InvalidValue {
+                    field: field.to_string(),
+                    value: value.to_string(),
+                });
+            }
+        }
+
+        if !(0.0..=1.0).contains(&grid.opacity) {
+            return Err(ConfigError::InvalidValue {
+                field: "grid.opacity".to_string(),
+                value: grid.opacity.to_string(),
+            });
+        }
+
+        if let Some(alphabet) = &grid.label_alphabet {
+            if let Err(e) = HintGenerator::new(alphabet) {
+                return Err(ConfigError::ValidationFailed {
+                    reason: e.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate movement configuration
     fn validate_movement_config(&self, movement: &MovementConfig) -> ConfigResult<()> {
         if movement.fast_speed_multiplier <= 0.0 {
@@ -322,6 +556,20 @@ impl ConfigManager {
             });
         }
 
+        if movement.precision_speed_multiplier <= 0.0 {
+            return Err(ConfigError::InvalidValue {
+                field: "movement.precision_speed_multiplier".to_string(),
+                value: movement.precision_speed_multiplier.to_string(),
+            });
+        }
+
+        if movement.scroll_step <= 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "movement.scroll_step".to_string(),
+                value: movement.scroll_step.to_string(),
+            });
+        }
+
         if movement.step_size <= 0 {
             return Err(ConfigError::InvalidValue {
                 field: "movement.step_size".to_string(),
@@ -333,6 +581,69 @@ impl ConfigManager {
             warn!("Movement step size is very large: {}px", movement.step_size);
         }
 
+        if movement.min_animation_ms > movement.max_animation_ms {
+            return Err(ConfigError::InvalidValue {
+                field: "movement.min_animation_ms".to_string(),
+                value: movement.min_animation_ms.to_string(),
+            });
+        }
+
+        if movement.refresh_rate_hz == 0 || movement.refresh_rate_hz > 1000 {
+            return Err(ConfigError::InvalidValue {
+                field: "movement.refresh_rate_hz".to_string(),
+                value: movement.refresh_rate_hz.to_string(),
+            });
+        }
+
+        if !movement.instant_distance_px.is_finite() || movement.instant_distance_px < 0.0 {
+            return Err(ConfigError::InvalidValue {
+                field: "movement.instant_distance_px".to_string(),
+                value: movement.instant_distance_px.to_string(),
+            });
+        }
+
+        let paths = &movement.paths;
+        for (mode, path) in [
+            ("basic", &paths.basic),
+            ("grid", &paths.grid),
+            ("area", &paths.area),
+            ("prediction", &paths.prediction),
+        ] {
+            self.validate_path_config(mode, path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validate the cursor path settings of a mode
+    fn validate_path_config(&self, mode: &str, path: &PathConfig) -> ConfigResult<()> {
+        let invalid_style = match path.style {
+            PathStyle::Bezier { c1, c2 } => ![c1.0, c1.1, c2.0, c2.1].iter().all(|v| v.is_finite()),
+            PathStyle::Arc { bulge } => !bulge.is_finite() || bulge.abs() > 1.0,
+            PathStyle::Straight | PathStyle::MinimumJerk => false,
+        };
+        if invalid_style {
+            return Err(ConfigError::InvalidValue {
+                field: format!("movement.paths.{}.style", mode),
+                value: format!("{:?}", path.style),
+            });
+        }
+
+        if let Some(easing) = path.easing {
+            // Like CSS, the curve must be a function of time
+            let valid = [easing.x1, easing.y1, easing.x2, easing.y2]
+                .iter()
+                .all(|v| v.is_finite())
+                && (0.0..=1.0).contains(&easing.x1)
+                && (0.0..=1.0).contains(&easing.x2);
+            if !valid {
+                return Err(ConfigError::InvalidValue {
+                    field: format!("movement.paths.{}.easing", mode),
+                    value: format!("{:?}", easing),
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -475,6 +786,11 @@ impl ConfigManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        keyboard_layout::{KeyboardLayout, LayoutPreset},
+        models::Position,
+        scripting::ScriptBinding,
+    };
     use tempfile::TempDir;
 
     #[test]
@@ -506,7 +822,7 @@ mod tests {
         let mut manager = ConfigManager::new(&config_path);
 
         // Modify config
-        manager.current_config.movement.step_size = 20;
+        manager.current_config.movement.step_size = 30;
         manager.current_config.ui.overlay_opacity = 0.5;
 
         // Save
@@ -517,7 +833,7 @@ mod tests {
         let mut new_manager = ConfigManager::new(&config_path);
         assert!(new_manager.load().is_ok());
 
-        assert_eq!(new_manager.current_config.movement.step_size, 20);
+        assert_eq!(new_manager.current_config.movement.step_size, 30);
         assert_eq!(new_manager.current_config.ui.overlay_opacity, 0.5);
     }
 
@@ -531,6 +847,16 @@ mod tests {
         // Modify config
         manager.current_config.movement.step_size = 15;
         manager.current_config.activation.double_click_required = false;
+        manager.current_config.area = AreaLayout::Regions {
+            regions: vec![crate::AreaRegion {
+                name: "left".to_string(),
+                key: 'h',
+                x: 0.0,
+                y: 0.0,
+                width: 0.5,
+                height: 1.0,
+            }],
+        };
 
         // Save
         assert!(manager.save().is_ok());
@@ -542,6 +868,7 @@ mod tests {
 
         assert_eq!(new_manager.current_config.movement.step_size, 15);
         assert!(!new_manager.current_config.activation.double_click_required);
+        assert_eq!(new_manager.current_config.area, manager.current_config.area);
     }
 
     #[test]
@@ -564,6 +891,210 @@ mod tests {
         let mut invalid_config = AppConfig::default();
         invalid_config.movement.step_size = -5;
         assert!(manager.update_config(invalid_config).is_err());
+
+        // Test custom keyboard layout without a layout file
+        let mut invalid_config = AppConfig::default();
+        invalid_config.keyboard.layout = crate::LayoutPreset::Custom;
+        assert!(manager.update_config(invalid_config).is_err());
+
+        // Test area grid without areas
+        let mut invalid_config = AppConfig::default();
+        invalid_config.area = AreaLayout::Grid {
+            rows: 0,
+            columns: 3,
+            keys: None,
+        };
+        assert!(manager.update_config(invalid_config).is_err());
+
+        // Test easing curve that is not a function of time
+        let mut invalid_config = AppConfig::default();
+        invalid_config.movement.paths.grid.easing =
+            Some(crate::CubicBezierEasing::new(1.5, 0.0, 0.5, 1.0));
+        assert!(manager.update_config(invalid_config).is_err());
+
+        // Test arc bulging further than the distance
+        let mut invalid_config = AppConfig::default();
+        invalid_config.movement.paths.area.style = PathStyle::Arc { bulge: 2.0 };
+        assert!(manager.update_config(invalid_config).is_err());
+
+        // Test animation bounds the wrong way round
+        let mut invalid_config = AppConfig::default();
+        invalid_config.movement.min_animation_ms = 500;
+        assert!(manager.update_config(invalid_config).is_err());
+
+        // Test page scroll that scrolls nothing
+        let mut invalid_config = AppConfig::default();
+        invalid_config.scroll.page_fraction = 0.0;
+        assert!(manager.update_config(invalid_config).is_err());
+
+        // Test grid without cells
+        let mut invalid_config = AppConfig::default();
+        invalid_config.grid.columns = 0;
+        assert!(manager.update_config(invalid_config).is_err());
+
+        // Test grid label alphabet with a single key
+        let mut invalid_config = AppConfig::default();
+        invalid_config.grid.label_alphabet = Some("a".to_string());
+        assert!(manager.update_config(invalid_config).is_err());
+    }
+
+    #[test]
+    fn test_speed_tiers() {
+        let movement = MovementConfig::default();
+
+        assert_eq!(movement.step_pixels(MovementSpeed::Precision), 2);
+        assert_eq!(movement.step_pixels(MovementSpeed::Slow), 10);
+        assert_eq!(movement.step_pixels(MovementSpeed::Normal), 20);
+        assert_eq!(movement.step_pixels(MovementSpeed::Fast), 60);
+
+        assert_eq!(movement.scroll_amount(MovementSpeed::Precision), 1);
+        assert_eq!(movement.scroll_amount(MovementSpeed::Normal), 3);
+        assert_eq!(movement.scroll_amount(MovementSpeed::Fast), 9);
+
+        // Steps never shrink to nothing
+        let tiny = MovementConfig {
+            step_size: 1,
+            ..MovementConfig::default()
+        };
+        assert_eq!(tiny.step_pixels(MovementSpeed::Precision), 1);
+    }
+
+    #[test]
+    fn test_mode_paths_from_toml() {
+        let movement: MovementConfig = toml::from_str(
+            r#"
+            default_speed = "Normal"
+            fast_speed_multiplier = 2.0
+            slow_speed_multiplier = 0.5
+            default_animation = "Elastic"
+            step_size = 10
+
+            [paths.grid]
+            style = { type = "bezier", c1 = [0.25, 0.2], c2 = [0.75, 0.1] }
+            easing = { x1 = 0.42, y1 = 0.0, x2 = 0.58, y2 = 1.0 }
+
+            [paths.area.style]
+            type = "minimum_jerk"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(movement.default_animation, AnimationType::Elastic);
+        assert_eq!(
+            movement.paths.for_mode(&crate::InteractionMode::Grid).style,
+            PathStyle::Bezier {
+                c1: (0.25, 0.2),
+                c2: (0.75, 0.1)
+            }
+        );
+        assert!(movement.paths.grid.easing.is_some());
+        assert_eq!(movement.paths.area.style, PathStyle::MinimumJerk);
+        assert_eq!(movement.paths.basic, PathConfig::default());
+
+        // Timing falls back to defaults and takes its base from the UI settings
+        let config = AppConfig {
+            movement,
+            ..AppConfig::default()
+        };
+        let timing = config.animation_timing();
+        assert_eq!(timing.base_duration_ms, config.ui.animation_duration_ms);
+        assert_eq!(timing.refresh_rate_hz, 60);
+    }
+
+    #[test]
+    fn test_scroll_config_from_toml() {
+        let scroll: ScrollConfig = toml::from_str(
+            r#"
+            natural_scroll = true
+            pixels_per_notch = 30.0
+
+            [app_multipliers]
+            firefox = 1.5
+            "#,
+        )
+        .unwrap();
+
+        assert!(scroll.natural_scroll);
+        assert!(scroll.smooth);
+        assert_eq!(scroll.multiplier_for(Some("Firefox")), 1.5);
+        assert_eq!(scroll.multiplier_for(Some("terminal")), 1.0);
+        assert!(scroll.validate().is_ok());
+    }
+
+    #[test]
+    fn test_grid_config_from_toml() {
+        let grid: GridConfig = toml::from_str(
+            r#"
+            rows = 4
+            columns = 5
+            label_alphabet = "asdfjkl"
+            label_priority = "Usage"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!((grid.rows, grid.columns), (4, 5));
+        assert!(grid.show_labels);
+        assert_eq!(grid.label_alphabet.as_deref(), Some("asdfjkl"));
+        assert_eq!(grid.label_priority, crate::LabelPriority::Usage);
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = ConfigManager::new(temp_dir.path().join("config.toml"));
+        let config = AppConfig {
+            grid,
+            ..AppConfig::default()
+        };
+        assert!(manager.update_config(config).is_ok());
+    }
+
+    #[test]
+    fn test_marks_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        let mut manager = ConfigManager::new(&config_path);
+
+        let mut config = AppConfig::default();
+        config.marks.set("editor", Position::new(100, 200)).unwrap();
+        config
+            .marks
+            .set("browser", Position::with_screen(2000, 300, 2))
+            .unwrap();
+        manager.update_config(config).unwrap();
+        manager.save().unwrap();
+
+        let mut loaded = ConfigManager::new(&config_path);
+        loaded.load().unwrap();
+        assert_eq!(
+            loaded.get_config().marks.get("editor"),
+            Some(Position::new(100, 200))
+        );
+        assert_eq!(
+            loaded.get_config().marks.get("browser"),
+            Some(Position::with_screen(2000, 300, 2))
+        );
+    }
+
+    #[test]
+    fn test_check_config_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+
+        // A missing file is reported, not created
+        assert!(ConfigManager::check(&config_path).is_err());
+        assert!(!config_path.exists());
+
+        let manager = ConfigManager::new(&config_path);
+        manager.save().unwrap();
+        assert!(ConfigManager::check(&config_path).is_ok());
+
+        let content = fs::read_to_string(&config_path)
+            .unwrap()
+            .replace("step_size = 20", "step_size = 0");
+        fs::write(&config_path, content).unwrap();
+        assert!(matches!(
+            ConfigManager::check(&config_path),
+            Err(ConfigError::InvalidValue { .. })
+        ));
     }
 
     #[test]
@@ -576,7 +1107,7 @@ mod tests {
         let mut valid_bindings = KeyBindings::default();
         valid_bindings.move_up = 'w';
         assert!(manager.update_key_bindings(valid_bindings.clone()).is_ok());
-        assert_eq!(manager.current_config.keybindings.move_up, 'w');
+        assert_eq!(manager.get_config().key_bindings().unwrap().move_up, 'w');
 
         // Invalid key bindings (duplicate)
         let mut invalid_bindings = KeyBindings::default();
@@ -584,6 +1115,74 @@ mod tests {
         assert!(manager.update_key_bindings(invalid_bindings).is_err());
     }
 
+    #[test]
+    fn test_key_bindings_follow_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        ConfigManager::new(&config_path).save().unwrap();
+
+        let content = fs::read_to_string(&config_path).unwrap();
+        assert!(!content.contains("[keybindings]"));
+        fs::write(
+            &config_path,
+            content.replace("layout = \"Qwerty\"", "layout = \"Dvorak\""),
+        )
+        .unwrap();
+
+        let mut manager = ConfigManager::new(&config_path);
+        manager.load().unwrap();
+        let bindings = manager.get_config().key_bindings().unwrap();
+        assert_eq!(bindings.move_up, 'c');
+        assert_eq!(bindings.move_left, 'h');
+
+        // Explicit bindings are kept whatever the layout
+        let bindings = KeyBindings {
+            move_up: 'w',
+            ..KeyBindings::default()
+        };
+        manager.update_key_bindings(bindings).unwrap();
+        assert_eq!(manager.get_config().key_bindings().unwrap().move_up, 'w');
+
+        // A layout without a key at a bound position is an error
+        let layout = KeyboardLayout {
+            home: "asdf".to_string(),
+            ..KeyboardLayout::qwerty()
+        };
+        assert!(matches!(
+            KeyBindings::for_layout(&layout),
+            Err(ConfigError::ValidationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_host_keys_must_not_shadow_bindings() {
+        let manager = ConfigManager::new(Path::new("config.toml"));
+        let mut config = AppConfig::default();
+        config.keyboard.layout = LayoutPreset::Azerty;
+        assert!(manager.validate_config(&config).is_ok());
+
+        // On AZERTY the key where QWERTY has A is Q, which enters area mode
+        config.macros.record_key = Some('q');
+        assert!(matches!(
+            manager.validate_config(&config),
+            Err(ConfigError::ValidationFailed { reason }) if reason.contains("area_mode")
+        ));
+
+        config.macros.record_key = Some('!');
+        assert!(manager.validate_config(&config).is_ok());
+
+        config.scripting.bindings.push(ScriptBinding {
+            key: '!',
+            script: "corner".to_string(),
+            modes: Vec::new(),
+        });
+        config
+            .scripting
+            .scripts
+            .insert("corner".to_string(), "move_to(0, 0)".to_string());
+        assert!(manager.validate_config(&config).is_err());
+    }
+
     #[test]
     fn test_import_export() {
         let temp_dir = TempDir::new().unwrap();
@@ -601,7 +1200,7 @@ mod tests {
 
         // Reset and import
         manager.reset_to_defaults();
-        assert_eq!(manager.current_config.movement.step_size, 10); // Default
+        assert_eq!(manager.current_config.movement.step_size, 20); // Default
 
         assert!(manager.import_from(&export_path).is_ok());
         assert_eq!(manager.current_config.movement.step_size, 25);
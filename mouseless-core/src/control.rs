@@ -0,0 +1,727 @@
+//! Control socket module
+//!
+//! This module provides functionality for:
+//! - A JSON-RPC 2.0 control socket for scripts, window managers and editors
+//! - Handing control requests to the process that owns the modes and the mouse
+//! - Streaming mode events to subscribers
+//! - A client for requests to a running control socket
+//!
+//! Messages are JSON objects, one per line, in both directions.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader, Lines};
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::{
+    error::{ControlError, ControlResult},
+    mode::ModeEvent,
+    models::{AnimationType, InteractionMode, MouseButton, Position},
+};
+
+/// Version of every JSON-RPC message
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Method of the notifications that carry mode events to subscribers
+pub const MODE_EVENT_METHOD: &str = "mode.event";
+
+/// Methods of the control socket, one per [`ControlRequest`] variant
+pub const CONTROL_METHODS: &[&str] = &[
+    "mode.activate",
+    "mode.deactivate",
+    "mode.get",
+    "cursor.move",
+    "cursor.position",
+    "mouse.click",
+    "mark.set",
+    "mark.list",
+    "config.reload",
+    "screens.list",
+    "events.subscribe",
+];
+
+/// A request to the control socket
+///
+/// The method and params of a JSON-RPC call select the variant, e.g.
+/// `{"jsonrpc": "2.0", "id": 1, "method": "mode.activate", "params": {"mode": "Grid"}}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params")]
+pub enum ControlRequest {
+    /// Activate an interaction mode
+    #[serde(rename = "mode.activate")]
+    ActivateMode { mode: InteractionMode },
+    /// Leave the active mode
+    #[serde(rename = "mode.deactivate")]
+    DeactivateMode,
+    /// Get the active mode, `null` when inactive
+    #[serde(rename = "mode.get")]
+    GetMode,
+    /// Move the cursor to a position or a mark
+    ///
+    /// A position is relative to the screen with the given 1-based number,
+    /// or global without one.
+    #[serde(rename = "cursor.move")]
+    MoveTo {
+        #[serde(flatten)]
+        target: MoveTarget,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        screen: Option<u8>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        animation: Option<AnimationType>,
+    },
+    /// Get the cursor position
+    #[serde(rename = "cursor.position")]
+    GetPosition,
+    /// Click a mouse button one or more times
+    #[serde(rename = "mouse.click")]
+    Click {
+        #[serde(default = "default_click_button")]
+        button: MouseButton,
+        #[serde(default = "default_click_count")]
+        count: u32,
+    },
+    /// Save a position under a name, the cursor position without one
+    #[serde(rename = "mark.set")]
+    SetMark {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        position: Option<Position>,
+    },
+    /// List the marks
+    #[serde(rename = "mark.list")]
+    ListMarks,
+    /// Reload the configuration file
+    #[serde(rename = "config.reload")]
+    ReloadConfig,
+    /// List the connected screens
+    #[serde(rename = "screens.list")]
+    GetScreens,
+    /// Stream mode events as notifications on this connection
+    #[serde(rename = "events.subscribe")]
+    Subscribe,
+}
+
+/// Target of a cursor move
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MoveTarget {
+    Position { x: i32, y: i32 },
+    Mark { mark: String },
+}
+
+fn default_click_button() -> MouseButton {
+    MouseButton::Left
+}
+
+fn default_click_count() -> u32 {
+    1
+}
+
+impl ControlRequest {
+    /// Parse the method and params of a JSON-RPC call
+    pub fn from_call(method: &str, params: Option<Value>) -> Result<Self, RpcError> {
+        if !CONTROL_METHODS.contains(&method) {
+            return Err(RpcError::method_not_found(method));
+        }
+
+        let parse = |params: Option<Value>| {
+            let mut call = Map::new();
+            call.insert("method".to_string(), Value::String(method.to_string()));
+            if let Some(params) = params {
+                call.insert("params".to_string(), params);
+            }
+            serde_json::from_value::<Self>(Value::Object(call))
+        };
+
+        let params = params.filter(|params| !params.is_null());
+        let empty = matches!(&params, Some(Value::Object(params)) if params.is_empty());
+        match parse(params) {
+            Ok(request) => Ok(request),
+            // Methods without params also accept an empty object
+            Err(_) if empty => parse(None).map_err(RpcError::invalid_params),
+            Err(e) => Err(RpcError::invalid_params(e)),
+        }
+    }
+}
+
+/// A JSON-RPC request; requests without an id are notifications and get no response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcRequest {
+    pub jsonrpc: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+impl RpcRequest {
+    /// Create a call of a control request
+    pub fn new(id: u64, request: &ControlRequest) -> Self {
+        let mut call = match serde_json::to_value(request) {
+            Ok(Value::Object(call)) => call,
+            _ => Map::new(),
+        };
+        let method = match call.remove("method") {
+            Some(Value::String(method)) => method,
+            _ => String::new(),
+        };
+
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: Some(json!(id)),
+            method,
+            params: call.remove("params"),
+        }
+    }
+}
+
+/// A JSON-RPC response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcResponse {
+    pub jsonrpc: String,
+    pub id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+impl RpcResponse {
+    /// Create the response to a call
+    pub fn new(id: Value, outcome: Result<Value, RpcError>) -> Self {
+        let (result, error) = match outcome {
+            Ok(result) => (Some(result), None),
+            Err(error) => (None, Some(error)),
+        };
+
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result,
+            error,
+        }
+    }
+}
+
+/// A JSON-RPC notification, a message without a response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcNotification {
+    pub jsonrpc: String,
+    pub method: String,
+    pub params: Value,
+}
+
+/// A JSON-RPC error object
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i32,
+    pub message: String,
+}
+
+impl RpcError {
+    pub const PARSE_ERROR: i32 = -32700;
+    pub const INVALID_REQUEST: i32 = -32600;
+    pub const METHOD_NOT_FOUND: i32 = -32601;
+    pub const INVALID_PARAMS: i32 = -32602;
+    /// A valid request that failed, e.g. a mouse error
+    pub const REQUEST_FAILED: i32 = -32000;
+
+    pub fn parse_error(reason: impl std::fmt::Display) -> Self {
+        Self::new(Self::PARSE_ERROR, format!("Parse error: {}", reason))
+    }
+
+    pub fn invalid_request(reason: impl std::fmt::Display) -> Self {
+        Self::new(
+            Self::INVALID_REQUEST,
+            format!("Invalid request: {}", reason),
+        )
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(
+            Self::METHOD_NOT_FOUND,
+            format!("Method not found: {}", method),
+        )
+    }
+
+    pub fn invalid_params(reason: impl std::fmt::Display) -> Self {
+        Self::new(Self::INVALID_PARAMS, format!("Invalid params: {}", reason))
+    }
+
+    pub fn failed(reason: impl std::fmt::Display) -> Self {
+        Self::new(Self::REQUEST_FAILED, reason.to_string())
+    }
+
+    fn new(code: i32, message: String) -> Self {
+        Self { code, message }
+    }
+}
+
+/// A control request for the host, with the channel for its result
+#[derive(Debug)]
+pub struct ControlCommand {
+    pub request: ControlRequest,
+    pub response_tx: oneshot::Sender<Result<Value, RpcError>>,
+}
+
+/// The control socket server
+///
+/// Requests are handed to the host as [`ControlCommand`]s; subscriptions are
+/// served from the mode event channel directly. The socket file is removed
+/// when the server is dropped.
+pub struct ControlServer {
+    path: PathBuf,
+    task: JoinHandle<()>,
+}
+
+impl ControlServer {
+    /// Get the default socket path
+    ///
+    /// `$MOUSELESS_SOCKET` when set, else `mouseless.sock` in
+    /// `$XDG_RUNTIME_DIR`, else a per-user socket in the temporary directory.
+    pub fn default_socket_path() -> PathBuf {
+        if let Some(path) = std::env::var_os("MOUSELESS_SOCKET") {
+            return PathBuf::from(path);
+        }
+
+        match std::env::var_os("XDG_RUNTIME_DIR") {
+            Some(dir) => PathBuf::from(dir).join("mouseless.sock"),
+            None => {
+                let user = std::env::var("USER").unwrap_or_else(|_| "user".to_string());
+                std::env::temp_dir().join(format!("mouseless-{}.sock", user))
+            }
+        }
+    }
+
+    /// Bind the socket and start serving on the current tokio runtime
+    pub fn bind<P: AsRef<Path>>(
+        path: P,
+        commands: mpsc::UnboundedSender<ControlCommand>,
+        events: broadcast::Receiver<ModeEvent>,
+    ) -> ControlResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let bind_failed = |reason: String| ControlError::BindFailed {
+            path: path.display().to_string(),
+            reason,
+        };
+
+        if path.exists() {
+            // A socket nobody listens on is left over from a crash
+            if std::os::unix::net::UnixStream::connect(&path).is_ok() {
+                return Err(bind_failed("Another instance is listening".to_string()));
+            }
+            fs::remove_file(&path).map_err(|e| bind_failed(e.to_string()))?;
+        }
+
+        let listener = UnixListener::bind(&path).map_err(|e| bind_failed(e.to_string()))?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))
+            .map_err(|e| bind_failed(e.to_string()))?;
+
+        let task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        let commands = commands.clone();
+                        let events = events.resubscribe();
+                        tokio::spawn(async move {
+                            if let Err(e) = serve_connection(stream, commands, events).await {
+                                debug!("Control connection closed: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        warn!("Failed to accept control connection: {}", e);
+                    }
+                }
+            }
+        });
+
+        info!("Control socket listening on {:?}", path);
+        Ok(Self { path, task })
+    }
+
+    /// Get the socket path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        self.task.abort();
+        if let Err(e) = fs::remove_file(&self.path) {
+            debug!("Failed to remove control socket {:?}: {}", self.path, e);
+        }
+    }
+}
+
+async fn serve_connection(
+    stream: UnixStream,
+    commands: mpsc::UnboundedSender<ControlCommand>,
+    events: broadcast::Receiver<ModeEvent>,
+) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let call = match serde_json::from_str::<RpcRequest>(&line) {
+            Ok(call) => call,
+            Err(e) => {
+                let response = RpcResponse::new(Value::Null, Err(RpcError::parse_error(e)));
+                write_message(&mut writer, &response).await?;
+                continue;
+            }
+        };
+
+        let outcome = if call.jsonrpc != JSONRPC_VERSION {
+            Err(RpcError::invalid_request(format!(
+                "Unsupported JSON-RPC version {:?}",
+                call.jsonrpc
+            )))
+        } else {
+            match ControlRequest::from_call(&call.method, call.params) {
+                Ok(ControlRequest::Subscribe) => {
+                    if let Some(id) = call.id {
+                        let response = RpcResponse::new(id, Ok(json!({ "subscribed": true })));
+                        write_message(&mut writer, &response).await?;
+                    }
+                    return stream_events(lines, writer, events).await;
+                }
+                Ok(request) => dispatch(&commands, request).await,
+                Err(e) => Err(e),
+            }
+        };
+
+        if let Some(id) = call.id {
+            write_message(&mut writer, &RpcResponse::new(id, outcome)).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn dispatch(
+    commands: &mpsc::UnboundedSender<ControlCommand>,
+    request: ControlRequest,
+) -> Result<Value, RpcError> {
+    let (response_tx, response_rx) = oneshot::channel();
+
+    commands
+        .send(ControlCommand {
+            request,
+            response_tx,
+        })
+        .map_err(|_| RpcError::failed("Mouseless is shutting down"))?;
+
+    response_rx
+        .await
+        .map_err(|_| RpcError::failed("Mouseless dropped the request"))?
+}
+
+/// Forward mode events until the subscriber disconnects
+async fn stream_events(
+    mut lines: Lines<BufReader<OwnedReadHalf>>,
+    mut writer: OwnedWriteHalf,
+    mut events: broadcast::Receiver<ModeEvent>,
+) -> std::io::Result<()> {
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) => {
+                    let notification = RpcNotification {
+                        jsonrpc: JSONRPC_VERSION.to_string(),
+                        method: MODE_EVENT_METHOD.to_string(),
+                        params: serde_json::to_value(&event)?,
+                    };
+                    write_message(&mut writer, &notification).await?;
+                }
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    warn!("Event subscriber missed {} mode events", missed);
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+            line = lines.next_line() => match line? {
+                // Requests on a subscription are ignored
+                Some(_) => {}
+                None => return Ok(()),
+            },
+        }
+    }
+}
+
+async fn write_message<W, T>(writer: &mut W, message: &T) -> std::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    writer.write_all(line.as_bytes()).await?;
+    writer.flush().await
+}
+
+/// A client of the control socket
+pub struct ControlClient {
+    path: PathBuf,
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+    next_id: u64,
+}
+
+impl ControlClient {
+    /// Connect to a control socket
+    pub async fn connect<P: AsRef<Path>>(path: P) -> ControlResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let stream =
+            UnixStream::connect(&path)
+                .await
+                .map_err(|e| ControlError::ConnectionFailed {
+                    path: path.display().to_string(),
+                    reason: e.to_string(),
+                })?;
+        let (reader, writer) = stream.into_split();
+
+        Ok(Self {
+            path,
+            lines: BufReader::new(reader).lines(),
+            writer,
+            next_id: 1,
+        })
+    }
+
+    /// Send a request and wait for its result
+    pub async fn call(&mut self, request: &ControlRequest) -> ControlResult<Value> {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let call = RpcRequest::new(id, request);
+        write_message(&mut self.writer, &call)
+            .await
+            .map_err(|e| self.connection_error(e))?;
+
+        loop {
+            let line = self.next_line().await?;
+            // Skip event notifications that arrive before the response
+            let Ok(response) = serde_json::from_str::<RpcResponse>(&line) else {
+                continue;
+            };
+            if response.id != json!(id) {
+                continue;
+            }
+
+            return match response.error {
+                Some(error) => Err(ControlError::RequestFailed {
+                    code: error.code,
+                    message: error.message,
+                }),
+                None => Ok(response.result.unwrap_or(Value::Null)),
+            };
+        }
+    }
+
+    /// Wait for the next mode event after a `Subscribe` request
+    pub async fn next_event(&mut self) -> ControlResult<ModeEvent> {
+        loop {
+            let line = self.next_line().await?;
+            let notification = serde_json::from_str::<RpcNotification>(&line).map_err(|e| {
+                ControlError::InvalidResponse {
+                    reason: e.to_string(),
+                }
+            })?;
+
+            if notification.method == MODE_EVENT_METHOD {
+                return serde_json::from_value(notification.params).map_err(|e| {
+                    ControlError::InvalidResponse {
+                        reason: e.to_string(),
+                    }
+                });
+            }
+        }
+    }
+
+    async fn next_line(&mut self) -> ControlResult<String> {
+        match self.lines.next_line().await {
+            Ok(Some(line)) => Ok(line),
+            Ok(None) => Err(ControlError::ConnectionFailed {
+                path: self.path.display().to_string(),
+                reason: "Connection closed".to_string(),
+            }),
+            Err(e) => Err(self.connection_error(e)),
+        }
+    }
+
+    fn connection_error(&self, e: std::io::Error) -> ControlError {
+        ControlError::ConnectionFailed {
+            path: self.path.display().to_string(),
+            reason: e.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn all_requests() -> Vec<ControlRequest> {
+        vec![
+            ControlRequest::ActivateMode {
+                mode: InteractionMode::Grid,
+            },
+            ControlRequest::DeactivateMode,
+            ControlRequest::GetMode,
+            ControlRequest::MoveTo {
+                target: MoveTarget::Position { x: 100, y: 200 },
+                screen: Some(2),
+                animation: Some(AnimationType::Smooth),
+            },
+            ControlRequest::GetPosition,
+            ControlRequest::Click {
+                button: MouseButton::Right,
+                count: 2,
+            },
+            ControlRequest::SetMark {
+                name: "editor".to_string(),
+                position: None,
+            },
+            ControlRequest::ListMarks,
+            ControlRequest::ReloadConfig,
+            ControlRequest::GetScreens,
+            ControlRequest::Subscribe,
+        ]
+    }
+
+    #[test]
+    fn test_requests_round_trip() {
+        let requests = all_requests();
+        assert_eq!(requests.len(), CONTROL_METHODS.len());
+
+        for request in requests {
+            let call = RpcRequest::new(1, &request);
+            assert!(CONTROL_METHODS.contains(&call.method.as_str()));
+            assert_eq!(
+                ControlRequest::from_call(&call.method, call.params).unwrap(),
+                request
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_calls() {
+        let request = ControlRequest::from_call(
+            "cursor.move",
+            Some(json!({ "mark": "editor", "animation": "Instant" })),
+        )
+        .unwrap();
+        assert_eq!(
+            request,
+            ControlRequest::MoveTo {
+                target: MoveTarget::Mark {
+                    mark: "editor".to_string()
+                },
+                screen: None,
+                animation: Some(AnimationType::Instant),
+            }
+        );
+
+        let request = ControlRequest::from_call("mouse.click", Some(json!({}))).unwrap();
+        assert_eq!(
+            request,
+            ControlRequest::Click {
+                button: MouseButton::Left,
+                count: 1,
+            }
+        );
+
+        let error = ControlRequest::from_call("cursor.teleport", None).unwrap_err();
+        assert_eq!(error.code, RpcError::METHOD_NOT_FOUND);
+
+        let error =
+            ControlRequest::from_call("mode.activate", Some(json!({ "mode": "Telepathy" })))
+                .unwrap_err();
+        assert_eq!(error.code, RpcError::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_server_hands_requests_to_host() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("mouseless.sock");
+        let (commands, mut requests) = mpsc::unbounded_channel();
+        let (_events_tx, events) = broadcast::channel(16);
+        let _server = ControlServer::bind(&path, commands, events).unwrap();
+
+        tokio::spawn(async move {
+            while let Some(command) = requests.recv().await {
+                let result = match command.request {
+                    ControlRequest::GetMode => Ok(json!({ "mode": "Grid" })),
+                    _ => Err(RpcError::failed("Not supported")),
+                };
+                let _ = command.response_tx.send(result);
+            }
+        });
+
+        let mut client = ControlClient::connect(&path).await.unwrap();
+        let result = client.call(&ControlRequest::GetMode).await.unwrap();
+        assert_eq!(result, json!({ "mode": "Grid" }));
+
+        match client.call(&ControlRequest::ReloadConfig).await {
+            Err(ControlError::RequestFailed { code, message }) => {
+                assert_eq!(code, RpcError::REQUEST_FAILED);
+                assert_eq!(message, "Not supported");
+            }
+            other => panic!("expected a failed request, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_streams_mode_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("mouseless.sock");
+        let (commands, _requests) = mpsc::unbounded_channel();
+        let (events_tx, events) = broadcast::channel(16);
+        let _server = ControlServer::bind(&path, commands, events).unwrap();
+
+        let mut client = ControlClient::connect(&path).await.unwrap();
+        let result = client.call(&ControlRequest::Subscribe).await.unwrap();
+        assert_eq!(result, json!({ "subscribed": true }));
+
+        let event = ModeEvent::ModeChanged {
+            from: InteractionMode::Basic,
+            to: InteractionMode::Area,
+        };
+        events_tx.send(event.clone()).unwrap();
+        assert_eq!(client.next_event().await.unwrap(), event);
+    }
+
+    #[tokio::test]
+    async fn test_socket_file_lifecycle() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("mouseless.sock");
+        fs::write(&path, "stale").unwrap();
+
+        let (commands, _requests) = mpsc::unbounded_channel();
+        let (_events_tx, events) = broadcast::channel(16);
+        let server = ControlServer::bind(&path, commands.clone(), events.resubscribe()).unwrap();
+
+        // A live socket is not taken over
+        assert!(matches!(
+            ControlServer::bind(&path, commands, events),
+            Err(ControlError::BindFailed { .. })
+        ));
+
+        drop(server);
+        assert!(!path.exists());
+    }
+}
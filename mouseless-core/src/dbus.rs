@@ -0,0 +1,505 @@
+//! D-Bus module
+//!
+//! This module provides functionality for:
+//! - Exporting `org.mouseless.Control` on the session bus
+//! - Mode methods and properties for desktop integration
+//! - A signal for each mode event
+//!
+//! Mode changes are handed to the host as [`ControlCommand`]s, the same way
+//! the control socket does, so they run through the host's action executor.
+
+use std::sync::Arc;
+
+use dbus::arg::{PropMap, RefArg, Variant};
+use dbus::blocking::stdintf::org_freedesktop_dbus::PropertiesPropertiesChanged;
+use dbus::channel::{Channel, MatchingReceiver, Sender, Token};
+use dbus::message::{MatchRule, SignalArgs};
+use dbus::nonblock::SyncConnection;
+use dbus::strings::{Interface, Member, Path};
+use dbus::Message;
+use dbus_crossroads::{Crossroads, IfaceBuilder, MethodErr};
+use serde_json::Value;
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::{
+    control::{ControlCommand, ControlRequest},
+    error::{ControlError, ControlResult},
+    mode::{ModeEvent, ModeManager},
+    models::{InteractionMode, MovementSpeed},
+    traits::ModeController,
+};
+
+/// Well-known bus name of the service
+pub const DBUS_NAME: &str = "org.mouseless.Control";
+
+/// Object path of the control object
+pub const DBUS_PATH: &str = "/org/mouseless/Control";
+
+/// Interface exported on the control object
+pub const DBUS_INTERFACE: &str = "org.mouseless.Control";
+
+/// State shared with the method and property handlers
+struct ControlObject {
+    commands: mpsc::UnboundedSender<ControlCommand>,
+    modes: ModeManager,
+}
+
+/// The `org.mouseless.Control` D-Bus service
+///
+/// The bus name is released when the service is dropped.
+pub struct DbusService {
+    connection: Arc<SyncConnection>,
+    receive_token: Token,
+    io_task: JoinHandle<()>,
+    event_task: JoinHandle<()>,
+}
+
+impl DbusService {
+    /// Export the service on the session bus
+    pub async fn start(
+        commands: mpsc::UnboundedSender<ControlCommand>,
+        modes: ModeManager,
+    ) -> ControlResult<Self> {
+        let channel = Channel::get_private(dbus::channel::BusType::Session).map_err(dbus_error)?;
+        Self::serve(channel, commands, modes).await
+    }
+
+    /// Export the service on the bus at `address`, e.g. a private test bus
+    pub async fn start_on(
+        address: &str,
+        commands: mpsc::UnboundedSender<ControlCommand>,
+        modes: ModeManager,
+    ) -> ControlResult<Self> {
+        let mut channel = Channel::open_private(address).map_err(dbus_error)?;
+        channel.register().map_err(dbus_error)?;
+        Self::serve(channel, commands, modes).await
+    }
+
+    async fn serve(
+        channel: Channel,
+        commands: mpsc::UnboundedSender<ControlCommand>,
+        modes: ModeManager,
+    ) -> ControlResult<Self> {
+        let (resource, connection) =
+            dbus_tokio::connection::from_channel::<SyncConnection>(channel).map_err(dbus_error)?;
+        let io_task = tokio::spawn(async move {
+            let error = resource.await;
+            warn!("⚠️ D-Bus connection lost: {}", error);
+        });
+
+        if let Err(e) = connection.request_name(DBUS_NAME, false, true, true).await {
+            io_task.abort();
+            return Err(dbus_error(e));
+        }
+
+        let mut crossroads = Crossroads::new();
+        crossroads.set_async_support(Some((
+            connection.clone(),
+            Box::new(|future| {
+                tokio::spawn(future);
+            }),
+        )));
+        let interface = crossroads.register(DBUS_INTERFACE, register_interface);
+        crossroads.insert(
+            DBUS_PATH,
+            &[interface],
+            ControlObject {
+                commands,
+                modes: modes.clone(),
+            },
+        );
+
+        let receive_token = connection.start_receive(
+            MatchRule::new_method_call(),
+            Box::new(move |message, connection| {
+                if crossroads.handle_message(message, connection).is_err() {
+                    debug!("Ignoring D-Bus message for an unknown object");
+                }
+                true
+            }),
+        );
+
+        let event_task = tokio::spawn(forward_events(
+            connection.clone(),
+            modes.subscribe_to_events(),
+        ));
+
+        info!("✅ D-Bus service {} ready", DBUS_NAME);
+        Ok(Self {
+            connection,
+            receive_token,
+            io_task,
+            event_task,
+        })
+    }
+}
+
+impl Drop for DbusService {
+    fn drop(&mut self) {
+        self.event_task.abort();
+        self.connection.stop_receive(self.receive_token);
+        self.io_task.abort();
+    }
+}
+
+fn register_interface(builder: &mut IfaceBuilder<ControlObject>) {
+    builder.method_with_cr_async(
+        "ActivateMode",
+        ("mode",),
+        (),
+        |mut ctx, crossroads, (name,): (String,)| {
+            let commands = control_object(&ctx, crossroads).map(|object| object.commands.clone());
+            async move {
+                let result = match (commands, InteractionMode::from_name(&name)) {
+                    (Ok(commands), Some(mode)) => {
+                        request(&commands, ControlRequest::ActivateMode { mode })
+                            .await
+                            .map(|_| ())
+                    }
+                    (Ok(_), None) => Err(MethodErr::invalid_arg(&name)),
+                    (Err(e), _) => Err(e),
+                };
+                ctx.reply(result)
+            }
+        },
+    );
+    builder.method_with_cr_async("DeactivateMode", (), (), |mut ctx, crossroads, (): ()| {
+        let commands = control_object(&ctx, crossroads).map(|object| object.commands.clone());
+        async move {
+            let result = match commands {
+                Ok(commands) => request(&commands, ControlRequest::DeactivateMode)
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(e),
+            };
+            ctx.reply(result)
+        }
+    });
+    builder.method("GetCurrentMode", (), ("mode",), |_, object, (): ()| {
+        Ok((current_mode_name(&object.modes),))
+    });
+    builder.method("IsActive", (), ("active",), |_, object, (): ()| {
+        Ok((object.modes.is_active(),))
+    });
+    builder.method("GetModeHistory", (), ("modes",), |_, object, (): ()| {
+        let history: Vec<String> = object
+            .modes
+            .get_mode_history()
+            .iter()
+            .map(|mode| mode.name().to_string())
+            .collect();
+        Ok((history,))
+    });
+
+    builder
+        .property::<String, _>("CurrentMode")
+        .get(|_, object| Ok(current_mode_name(&object.modes)));
+    builder
+        .property::<String, _>("MovementSpeed")
+        .emits_changed_false()
+        .get(|_, object| Ok(speed_name(object.modes.get_speed()).to_string()));
+    builder
+        .property::<bool, _>("Holding")
+        .emits_changed_false()
+        .get(|_, object| Ok(object.modes.is_holding()));
+
+    builder.signal::<(String,), _>("ModeActivated", ("mode",));
+    builder.signal::<(String,), _>("ModeDeactivated", ("mode",));
+    builder.signal::<(String, String), _>("ModeChanged", ("from", "to"));
+    builder.signal::<(String, String), _>("InputProcessed", ("mode", "action"));
+}
+
+fn control_object<'a>(
+    ctx: &dbus_crossroads::Context,
+    crossroads: &'a mut Crossroads,
+) -> Result<&'a mut ControlObject, MethodErr> {
+    crossroads
+        .data_mut(ctx.path())
+        .ok_or_else(|| MethodErr::no_path(ctx.path()))
+}
+
+/// Hand a request to the host and wait for its result
+async fn request(
+    commands: &mpsc::UnboundedSender<ControlCommand>,
+    request: ControlRequest,
+) -> Result<Value, MethodErr> {
+    let (response_tx, response_rx) = oneshot::channel();
+    commands
+        .send(ControlCommand {
+            request,
+            response_tx,
+        })
+        .map_err(|_| MethodErr::failed("mouseless is shutting down"))?;
+
+    response_rx
+        .await
+        .map_err(|_| MethodErr::failed("mouseless dropped the request"))?
+        .map_err(|e| MethodErr::failed(&e.message))
+}
+
+/// Emit a signal for each mode event, and `PropertiesChanged` for the mode
+async fn forward_events(
+    connection: Arc<SyncConnection>,
+    mut events: broadcast::Receiver<ModeEvent>,
+) {
+    loop {
+        let event = match events.recv().await {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("⚠️ D-Bus service skipped {} mode events", skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        let current_mode = match &event {
+            ModeEvent::ModeActivated(mode) => Some(mode.name()),
+            ModeEvent::ModeChanged { to, .. } => Some(to.name()),
+            ModeEvent::ModeDeactivated(_) => Some(""),
+            ModeEvent::InputProcessed { .. } => None,
+        };
+
+        let mut messages = vec![event_signal(&event)];
+        if let Some(mode) = current_mode {
+            messages.push(mode_changed_message(mode));
+        }
+        for message in messages {
+            if connection.send(message).is_err() {
+                warn!("⚠️ Failed to send D-Bus signal for {:?}", event);
+            }
+        }
+    }
+}
+
+fn event_signal(event: &ModeEvent) -> Message {
+    let signal = |member: &'static str| {
+        Message::signal(
+            &Path::from(DBUS_PATH),
+            &Interface::from(DBUS_INTERFACE),
+            &Member::from(member),
+        )
+    };
+
+    match event {
+        ModeEvent::ModeActivated(mode) => signal("ModeActivated").append1(mode.name()),
+        ModeEvent::ModeDeactivated(mode) => signal("ModeDeactivated").append1(mode.name()),
+        ModeEvent::ModeChanged { from, to } => {
+            signal("ModeChanged").append2(from.name(), to.name())
+        }
+        ModeEvent::InputProcessed { mode, action } => signal("InputProcessed").append2(
+            mode.name(),
+            serde_json::to_string(action).unwrap_or_else(|_| format!("{:?}", action)),
+        ),
+    }
+}
+
+fn mode_changed_message(mode: &str) -> Message {
+    let mut changed_properties = PropMap::new();
+    changed_properties.insert(
+        "CurrentMode".to_string(),
+        Variant(Box::new(mode.to_string()) as Box<dyn RefArg>),
+    );
+    PropertiesPropertiesChanged {
+        interface_name: DBUS_INTERFACE.to_string(),
+        changed_properties,
+        invalidated_properties: Vec::new(),
+    }
+    .to_emit_message(&Path::from(DBUS_PATH))
+}
+
+/// The current mode name, or an empty string when inactive
+fn current_mode_name(modes: &ModeManager) -> String {
+    modes
+        .get_current_mode()
+        .map(|mode| mode.name().to_string())
+        .unwrap_or_default()
+}
+
+fn speed_name(speed: MovementSpeed) -> &'static str {
+    match speed {
+        MovementSpeed::Precision => "precision",
+        MovementSpeed::Slow => "slow",
+        MovementSpeed::Normal => "normal",
+        MovementSpeed::Fast => "fast",
+    }
+}
+
+fn dbus_error(error: dbus::Error) -> ControlError {
+    ControlError::DbusFailed {
+        reason: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::RpcError;
+    use crate::traits::KeyBindings;
+    use dbus::nonblock::stdintf::org_freedesktop_dbus::Properties;
+    use dbus::nonblock::Proxy;
+    use serde_json::json;
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+    use std::time::Duration;
+
+    /// A private session bus, stopped when dropped
+    struct PrivateBus {
+        daemon: Child,
+        address: String,
+    }
+
+    impl PrivateBus {
+        /// Start a bus, or `None` if `dbus-daemon` is not installed
+        fn start() -> Option<Self> {
+            let mut daemon = Command::new("dbus-daemon")
+                .args(["--session", "--nofork", "--print-address=1"])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .ok()?;
+            let mut address = String::new();
+            BufReader::new(daemon.stdout.take()?)
+                .read_line(&mut address)
+                .ok()?;
+            Some(Self {
+                daemon,
+                address: address.trim().to_string(),
+            })
+        }
+    }
+
+    impl Drop for PrivateBus {
+        fn drop(&mut self) {
+            let _ = self.daemon.kill();
+            let _ = self.daemon.wait();
+        }
+    }
+
+    /// Answer mode requests the way the daemon does
+    fn spawn_host(mut modes: ModeManager) -> mpsc::UnboundedSender<ControlCommand> {
+        let (commands, mut command_rx) = mpsc::unbounded_channel::<ControlCommand>();
+        tokio::spawn(async move {
+            while let Some(command) = command_rx.recv().await {
+                let result = match command.request {
+                    ControlRequest::ActivateMode { mode } => modes.activate_mode(mode).await,
+                    ControlRequest::DeactivateMode => modes.deactivate_current_mode().await,
+                    _ => Ok(()),
+                };
+                let _ = command
+                    .response_tx
+                    .send(result.map(|_| json!({})).map_err(RpcError::failed));
+            }
+        });
+        commands
+    }
+
+    fn connect(address: &str) -> Arc<SyncConnection> {
+        let mut channel = Channel::open_private(address).unwrap();
+        channel.register().unwrap();
+        let (resource, connection) =
+            dbus_tokio::connection::from_channel::<SyncConnection>(channel).unwrap();
+        tokio::spawn(resource);
+        connection
+    }
+
+    #[tokio::test]
+    async fn test_dbus_service_on_private_bus() {
+        let Some(bus) = PrivateBus::start() else {
+            eprintln!("dbus-daemon not found, skipping D-Bus test");
+            return;
+        };
+
+        let modes = ModeManager::new(KeyBindings::default());
+        let _service =
+            DbusService::start_on(&bus.address, spawn_host(modes.clone()), modes.clone())
+                .await
+                .unwrap();
+
+        let connection = connect(&bus.address);
+        let (signal_tx, mut signal_rx) = mpsc::unbounded_channel();
+        let _signal_match = connection
+            .add_match(MatchRule::new_signal(DBUS_INTERFACE, "ModeChanged"))
+            .await
+            .unwrap()
+            .cb(move |_, (from, to): (String, String)| {
+                let _ = signal_tx.send((from, to));
+                true
+            });
+
+        let proxy = Proxy::new(
+            DBUS_NAME,
+            DBUS_PATH,
+            Duration::from_secs(5),
+            connection.clone(),
+        );
+
+        let mode: String = proxy.get(DBUS_INTERFACE, "CurrentMode").await.unwrap();
+        assert_eq!(mode, "");
+
+        proxy
+            .method_call::<(), _, _, _>(DBUS_INTERFACE, "ActivateMode", ("basic",))
+            .await
+            .unwrap();
+        proxy
+            .method_call::<(), _, _, _>(DBUS_INTERFACE, "ActivateMode", ("Grid",))
+            .await
+            .unwrap();
+        assert!(proxy
+            .method_call::<(), _, _, _>(DBUS_INTERFACE, "ActivateMode", ("telepathy",))
+            .await
+            .is_err());
+
+        let (mode,): (String,) = proxy
+            .method_call(DBUS_INTERFACE, "GetCurrentMode", ())
+            .await
+            .unwrap();
+        assert_eq!(mode, "grid");
+        let (history,): (Vec<String>,) = proxy
+            .method_call(DBUS_INTERFACE, "GetModeHistory", ())
+            .await
+            .unwrap();
+        assert_eq!(history, vec!["basic".to_string()]);
+
+        let speed: String = proxy.get(DBUS_INTERFACE, "MovementSpeed").await.unwrap();
+        assert_eq!(speed, "normal");
+        modes.set_hold_state(true);
+        let holding: bool = proxy.get(DBUS_INTERFACE, "Holding").await.unwrap();
+        assert!(holding);
+
+        let signal = tokio::time::timeout(Duration::from_secs(5), signal_rx.recv())
+            .await
+            .unwrap();
+        assert_eq!(signal, Some(("basic".to_string(), "grid".to_string())));
+
+        proxy
+            .method_call::<(), _, _, _>(DBUS_INTERFACE, "DeactivateMode", ())
+            .await
+            .unwrap();
+        let (active,): (bool,) = proxy
+            .method_call(DBUS_INTERFACE, "IsActive", ())
+            .await
+            .unwrap();
+        assert!(!active);
+    }
+
+    #[test]
+    fn test_event_signals() {
+        let message = event_signal(&ModeEvent::ModeChanged {
+            from: InteractionMode::Basic,
+            to: InteractionMode::Area,
+        });
+        assert_eq!(&*message.member().unwrap(), "ModeChanged");
+        assert_eq!(message.read2::<&str, &str>().unwrap(), ("basic", "area"));
+
+        let message = event_signal(&ModeEvent::InputProcessed {
+            mode: InteractionMode::Basic,
+            action: crate::models::Action::Exit,
+        });
+        assert_eq!(
+            message.read2::<&str, &str>().unwrap(),
+            ("basic", "\"Exit\"")
+        );
+    }
+}
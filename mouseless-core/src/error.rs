@@ -21,6 +21,15 @@ pub enum MouselessError {
     #[error("Prediction model error: {0}")]
     PredictionError(String),
 
+    #[error("Control error: {0}")]
+    ControlError(#[from] ControlError),
+
+    #[error("Script error: {0}")]
+    ScriptError(#[from] ScriptError),
+
+    #[error("Session log error: {0}")]
+    SessionError(#[from] SessionError),
+
     #[error("Mode management error: {0}")]
     ModeError(String),
 
@@ -61,6 +70,9 @@ pub enum MouseError {
 
     #[error("Animation error: {reason}")]
     AnimationError { reason: String },
+
+    #[error("Mouse service unavailable: {reason}")]
+    ServiceUnavailable { reason: String },
 }
 
 /// UI rendering errors
@@ -98,6 +110,57 @@ pub enum ConfigError {
     ValidationFailed { reason: String },
 }
 
+/// Control socket errors
+#[derive(Debug, Error)]
+pub enum ControlError {
+    #[error("Failed to bind control socket {path}: {reason}")]
+    BindFailed { path: String, reason: String },
+
+    #[error("Failed to connect to control socket {path}: {reason}")]
+    ConnectionFailed { path: String, reason: String },
+
+    #[error("Control request failed ({code}): {message}")]
+    RequestFailed { code: i32, message: String },
+
+    #[error("Invalid control response: {reason}")]
+    InvalidResponse { reason: String },
+
+    #[error("D-Bus service failed: {reason}")]
+    DbusFailed { reason: String },
+}
+
+/// Scripting errors
+#[derive(Debug, Error)]
+pub enum ScriptError {
+    #[error("Unknown script: {name}")]
+    UnknownScript { name: String },
+
+    #[error("Failed to compile script {name}: {reason}")]
+    CompileFailed { name: String, reason: String },
+
+    #[error("Script {name} failed: {reason}")]
+    RunFailed { name: String, reason: String },
+
+    #[error("Script {name} exceeded its time limit of {limit_ms}ms")]
+    TimedOut { name: String, limit_ms: u64 },
+}
+
+/// Session log and replay errors
+#[derive(Debug, Error)]
+pub enum SessionError {
+    #[error("Failed to open session log {path}: {reason}")]
+    OpenFailed { path: String, reason: String },
+
+    #[error("Failed to write session log: {reason}")]
+    WriteFailed { reason: String },
+
+    #[error("Invalid session log entry on line {line}: {reason}")]
+    InvalidEntry { line: usize, reason: String },
+
+    #[error("Session cannot be replayed: {reason}")]
+    NotReplayable { reason: String },
+}
+
 /// Result type alias for convenience
 pub type Result<T> = std::result::Result<T, MouselessError>;
 
@@ -112,3 +175,12 @@ pub type UIResult<T> = std::result::Result<T, UIError>;
 
 /// Config-specific result type
 pub type ConfigResult<T> = std::result::Result<T, ConfigError>;
+
+/// Control-specific result type
+pub type ControlResult<T> = std::result::Result<T, ControlError>;
+
+/// Script-specific result type
+pub type ScriptResult<T> = std::result::Result<T, ScriptError>;
+
+/// Session-specific result type
+pub type SessionResult<T> = std::result::Result<T, SessionError>;
@@ -0,0 +1,864 @@
+//! Action executor module
+//!
+//! This module provides functionality for:
+//! - Turning the actions returned by modes into mouse and UI side effects
+//! - Applying relative and absolute cursor moves
+//! - Switching modes and tearing the UI down on exit
+//! - Undoing and redoing absolute moves and mode switches
+//! - Running middleware hooks before and after every action
+
+use tracing::{debug, info};
+
+use crate::{
+    config::MovementConfig,
+    error::Result,
+    models::{
+        Action, ActionOutcome, AnimationType, InteractionMode, KeyInput, Position, ScrollDirection,
+    },
+    mouse::MouseController,
+    scroll::{page_viewport, ScrollConfig},
+    traits::{ActionMiddleware, ModeController, MouseOperations, UIRenderer},
+    undo::{UndoStack, UndoState},
+};
+
+/// Executes the actions produced by a mode controller
+///
+/// Mouse actions go to the `MouseOperations` implementation, visual feedback
+/// and mode changes to the `UIRenderer` and the mode controller. Scrolls are
+/// sent at once, with natural scrolling applied, unless the mouse glides them
+/// itself like the `MouseService` does, with holds, inertia and its own scroll
+/// configuration. The cursor position and mode before every
+/// absolute move and mode switch are kept for `Action::Undo`.
+pub struct ActionExecutor<C, M, U> {
+    modes: C,
+    mouse: M,
+    ui: U,
+    scroll: ScrollConfig,
+    animation: AnimationType,
+    middleware: Vec<Box<dyn ActionMiddleware>>,
+    history: UndoStack,
+}
+
+impl<C, M, U> ActionExecutor<C, M, U>
+where
+    C: ModeController,
+    M: MouseOperations,
+    U: UIRenderer,
+{
+    /// Create a new executor
+    pub fn new(modes: C, mouse: M, ui: U) -> Self {
+        Self {
+            modes,
+            mouse,
+            ui,
+            scroll: ScrollConfig::default(),
+            animation: MovementConfig::default().default_animation,
+            middleware: Vec::new(),
+            history: UndoStack::new(),
+        }
+    }
+
+    /// Set the scrolling configuration
+    pub fn set_scroll_config(&mut self, scroll: ScrollConfig) {
+        self.scroll = scroll;
+    }
+
+    /// Set the animation type for moves that no action chose one for, e.g. undo
+    pub fn set_default_animation(&mut self, animation: AnimationType) {
+        self.animation = animation;
+    }
+
+    /// Add a middleware; middleware runs in the order it was added
+    pub fn add_middleware(&mut self, middleware: Box<dyn ActionMiddleware>) {
+        self.middleware.push(middleware);
+    }
+
+    /// Get the mode controller
+    pub fn modes(&self) -> &C {
+        &self.modes
+    }
+
+    /// Get the mode controller mutably
+    pub fn modes_mut(&mut self) -> &mut C {
+        &mut self.modes
+    }
+
+    /// Get the mouse
+    pub fn mouse(&self) -> &M {
+        &self.mouse
+    }
+
+    /// Get the mouse mutably
+    pub fn mouse_mut(&mut self) -> &mut M {
+        &mut self.mouse
+    }
+
+    /// Get the UI renderer
+    pub fn ui(&self) -> &U {
+        &self.ui
+    }
+
+    /// Get the undo and redo stacks
+    pub fn undo_history(&self) -> &UndoStack {
+        &self.history
+    }
+
+    /// Pass input to the active mode and execute the resulting action
+    pub async fn handle_input(&mut self, input: KeyInput) -> Result<ActionOutcome> {
+        let action = self.modes.handle_input(input).await?;
+        self.execute(action).await
+    }
+
+    /// Execute an action, running the middleware around it
+    pub async fn execute(&mut self, action: Action) -> Result<ActionOutcome> {
+        let mut action = action;
+        for middleware in &mut self.middleware {
+            match middleware.before(action) {
+                Some(next) => action = next,
+                None => {
+                    debug!("Action skipped by middleware");
+                    return Ok(ActionOutcome::Continue);
+                }
+            }
+        }
+
+        let result = self.apply(&action).await;
+
+        for middleware in &mut self.middleware {
+            middleware.after(&action, &result);
+        }
+
+        result
+    }
+
+    async fn apply(&mut self, action: &Action) -> Result<ActionOutcome> {
+        debug!("Executing action: {:?}", action);
+
+        match action {
+            Action::MoveCursor(position, animation) => {
+                self.record_undo()?;
+                self.move_cursor(*position, *animation).await?;
+            }
+            Action::MoveRelative(dx, dy, animation) => {
+                let from = self.mouse.get_current_position()?;
+                let target = self.mouse.move_relative(*dx, *dy, *animation)?;
+                self.ui
+                    .animate_cursor_movement(from, target, *animation)
+                    .await?;
+            }
+            Action::SwitchScreen(target, animation) => {
+                let screens = self.mouse.get_screen_bounds()?;
+                let current = self.mouse.get_current_position()?;
+                let destination =
+                    MouseController::screen_switch_destination(&screens, current, *target)?;
+                self.record_undo()?;
+                self.move_cursor(destination, *animation).await?;
+            }
+            Action::Click(button) => self.mouse.click(*button)?,
+            Action::Scroll(direction, amount) if self.mouse.glides_scrolls() => {
+                self.mouse.scroll(*direction, *amount)?
+            }
+            Action::Scroll(direction, amount) => {
+                let pixels = *amount as f64 * self.scroll.pixels_per_notch;
+                self.scroll_by(*direction, pixels)?;
+            }
+            Action::ScrollPage(direction) if self.mouse.glides_scrolls() => {
+                self.mouse.scroll_page(*direction)?
+            }
+            Action::ScrollPage(direction) => {
+                let screens = self.mouse.get_screen_bounds()?;
+                let position = self.mouse.get_current_position()?;
+                let viewport = page_viewport(&screens, position, *direction);
+                self.scroll_by(*direction, viewport * self.scroll.page_fraction)?;
+            }
+            Action::ScrollRelease(direction) => self.mouse.release_scroll(*direction)?,
+            // The mode controller owns the speed, the mouse follows it
+            Action::ToggleSpeed => {
+                let speed = self.modes.toggle_speed();
+                self.mouse.set_movement_speed(speed);
+            }
+            Action::SetSpeed(speed) => self.mouse.set_movement_speed(*speed),
+            Action::HighlightArea(key) => self.ui.highlight_area(*key).await?,
+            Action::ActivateMode(mode) => {
+                if self.modes.get_current_mode().as_ref() != Some(mode) {
+                    self.record_undo()?;
+                }
+                self.switch_mode(mode.clone()).await?;
+            }
+            Action::DeactivateMode => self.deactivate().await?,
+            Action::Exit => {
+                self.deactivate().await?;
+                info!("Exit requested");
+                return Ok(ActionOutcome::Exit);
+            }
+            Action::RunScript(name) => {
+                debug!("Script {} is left to the host's script engine", name)
+            }
+            Action::RecordMacro(_) | Action::StopMacroRecording | Action::PlayMacro(..) => {
+                debug!("Macro action {:?} is left to the host", action)
+            }
+            Action::Undo => {
+                let current = self.undo_state()?;
+                match self.history.undo(current) {
+                    Some(state) => self.restore(state).await?,
+                    None => debug!("Nothing to undo"),
+                }
+            }
+            Action::Redo => {
+                let current = self.undo_state()?;
+                match self.history.redo(current) {
+                    Some(state) => self.restore(state).await?,
+                    None => debug!("Nothing to redo"),
+                }
+            }
+            Action::NoAction => {}
+        }
+
+        Ok(ActionOutcome::Continue)
+    }
+
+    async fn move_cursor(&mut self, target: Position, animation: AnimationType) -> Result<()> {
+        let from = self.mouse.get_current_position()?;
+        self.mouse.move_to(target, animation)?;
+        self.ui
+            .animate_cursor_movement(from, target, animation)
+            .await?;
+        Ok(())
+    }
+
+    async fn switch_mode(&mut self, mode: InteractionMode) -> Result<()> {
+        self.modes.activate_mode(mode.clone()).await?;
+        self.ui.show_mode_indicator(mode).await?;
+        Ok(())
+    }
+
+    fn undo_state(&self) -> Result<UndoState> {
+        let position = self.mouse.get_current_position()?;
+        Ok(UndoState::new(position, self.modes.get_current_mode()))
+    }
+
+    fn record_undo(&mut self) -> Result<()> {
+        let state = self.undo_state()?;
+        self.history.record(state);
+        Ok(())
+    }
+
+    /// Go back to an undone or redone state; a state from while mouseless
+    /// was inactive only restores the cursor
+    async fn restore(&mut self, state: UndoState) -> Result<()> {
+        if let Some(mode) = state.mode {
+            if self.modes.get_current_mode().as_ref() != Some(&mode) {
+                self.switch_mode(mode).await?;
+            }
+        }
+        self.move_cursor(state.position, self.animation).await
+    }
+
+    fn scroll_by(&mut self, direction: ScrollDirection, pixels: f64) -> Result<()> {
+        let direction = self.scroll.oriented(direction);
+        if self.mouse.supports_pixel_scroll() {
+            self.mouse.scroll_pixels(direction, pixels.round() as i32)?;
+        } else {
+            self.mouse.scroll(direction, self.scroll.notches(pixels))?;
+        }
+        Ok(())
+    }
+
+    async fn deactivate(&mut self) -> Result<()> {
+        if self.modes.get_current_mode().is_some() {
+            self.record_undo()?;
+        }
+        self.modes.deactivate_current_mode().await?;
+        self.ui.hide_all_overlays().await?;
+        self.ui.hide_mode_indicator().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        animation_scheduler::VirtualClock,
+        config::MovementConfig,
+        error::{MouseResult, UIResult},
+        grid::GridCell,
+        mode::ModeManager,
+        models::{
+            Area, GridConfig, InteractionMode, MouseButton, MovementSpeed, PredictionTarget,
+            ScreenBounds, ScrollDirection,
+        },
+        recording_mouse::{MouseOp, RecordingMouse, VirtualMouse},
+        scroll::SmoothScroller,
+        traits::{KeyBindings, Theme},
+    };
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    struct MockMouse {
+        position: Position,
+        speed: MovementSpeed,
+        calls: Vec<String>,
+    }
+
+    impl MouseOperations for MockMouse {
+        fn move_to(&mut self, position: Position, _animation: AnimationType) -> MouseResult<()> {
+            self.calls
+                .push(format!("move_to({}, {})", position.x, position.y));
+            self.position = position;
+            Ok(())
+        }
+
+        fn click(&mut self, button: MouseButton) -> MouseResult<()> {
+            self.calls.push(format!("click({:?})", button));
+            Ok(())
+        }
+
+        fn scroll(&mut self, direction: ScrollDirection, amount: i32) -> MouseResult<()> {
+            self.calls
+                .push(format!("scroll({:?}, {})", direction, amount));
+            Ok(())
+        }
+
+        fn get_current_position(&self) -> MouseResult<Position> {
+            Ok(self.position)
+        }
+
+        fn get_screen_bounds(&self) -> MouseResult<Vec<ScreenBounds>> {
+            Ok(Vec::new())
+        }
+
+        fn set_movement_speed(&mut self, speed: MovementSpeed) {
+            self.speed = speed;
+        }
+
+        fn get_movement_speed(&self) -> MovementSpeed {
+            self.speed
+        }
+    }
+
+    #[derive(Default)]
+    struct MockUi {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl MockUi {
+        fn record(&self, call: String) -> UIResult<()> {
+            self.calls.lock().unwrap().push(call);
+            Ok(())
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl UIRenderer for MockUi {
+        async fn show_grid_overlay(
+            &self,
+            _grid_config: GridConfig,
+            _cells: Vec<GridCell>,
+        ) -> UIResult<()> {
+            self.record("show_grid_overlay".to_string())
+        }
+
+        async fn show_area_overlay(&self, _areas: Vec<Area>) -> UIResult<()> {
+            self.record("show_area_overlay".to_string())
+        }
+
+        async fn show_prediction_targets(&self, _targets: Vec<PredictionTarget>) -> UIResult<()> {
+            self.record("show_prediction_targets".to_string())
+        }
+
+        async fn animate_cursor_movement(
+            &self,
+            from: Position,
+            to: Position,
+            _animation: AnimationType,
+        ) -> UIResult<()> {
+            self.record(format!(
+                "animate({}, {} -> {}, {})",
+                from.x, from.y, to.x, to.y
+            ))
+        }
+
+        async fn hide_all_overlays(&self) -> UIResult<()> {
+            self.record("hide_all_overlays".to_string())
+        }
+
+        async fn show_mode_indicator(&self, mode: InteractionMode) -> UIResult<()> {
+            self.record(format!("show_mode_indicator({:?})", mode))
+        }
+
+        async fn hide_mode_indicator(&self) -> UIResult<()> {
+            self.record("hide_mode_indicator".to_string())
+        }
+
+        async fn update_theme(&self, _theme: Theme) -> UIResult<()> {
+            self.record("update_theme".to_string())
+        }
+
+        async fn highlight_area(&self, key: char) -> UIResult<()> {
+            self.record(format!("highlight_area({})", key))
+        }
+    }
+
+    /// Skips clicks and records what ran
+    struct SkipClicks {
+        seen: Arc<Mutex<Vec<Action>>>,
+    }
+
+    impl ActionMiddleware for SkipClicks {
+        fn before(&mut self, action: Action) -> Option<Action> {
+            match action {
+                Action::Click(_) => None,
+                other => Some(other),
+            }
+        }
+
+        fn after(&mut self, action: &Action, result: &Result<ActionOutcome>) {
+            assert!(result.is_ok());
+            self.seen.lock().unwrap().push(action.clone());
+        }
+    }
+
+    fn create_executor() -> ActionExecutor<ModeManager, MockMouse, MockUi> {
+        let mouse = MockMouse {
+            position: Position::new(100, 100),
+            speed: MovementSpeed::Normal,
+            calls: Vec::new(),
+        };
+        ActionExecutor::new(
+            ModeManager::new(KeyBindings::default()),
+            mouse,
+            MockUi::default(),
+        )
+    }
+
+    fn create_test_input(key: char) -> KeyInput {
+        KeyInput {
+            key,
+            modifiers: vec![],
+            timestamp: std::time::SystemTime::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_relative_and_absolute_moves() {
+        let mut executor = create_executor();
+
+        executor
+            .execute(Action::MoveRelative(10, -20, AnimationType::Smooth))
+            .await
+            .unwrap();
+        assert_eq!(executor.mouse().position, Position::new(110, 80));
+
+        executor
+            .execute(Action::MoveCursor(
+                Position::new(500, 400),
+                AnimationType::Linear,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(executor.mouse().position, Position::new(500, 400));
+        assert_eq!(
+            executor.ui().calls(),
+            vec![
+                "animate(100, 100 -> 110, 80)",
+                "animate(110, 80 -> 500, 400)"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_relative_move_goes_through_the_backend() {
+        let screen = |id, x| ScreenBounds {
+            id,
+            x,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            is_primary: id == 0,
+            scale_factor: 1.0,
+        };
+        let mut mouse = RecordingMouse::new(VirtualMouse::new(
+            Position::with_screen(1900, 500, 0),
+            vec![screen(0, 0), screen(1, 1920)],
+        ));
+        mouse.set_recording(true);
+        let mut executor = ActionExecutor::new(
+            ModeManager::new(KeyBindings::default()),
+            mouse,
+            MockUi::default(),
+        );
+
+        executor
+            .execute(Action::MoveRelative(50, 0, AnimationType::Linear))
+            .await
+            .unwrap();
+
+        // The move crosses onto the second screen
+        assert_eq!(
+            executor.mouse_mut().take_ops(),
+            vec![MouseOp::MoveRelative {
+                dx: 50,
+                dy: 0,
+                animation: AnimationType::Linear,
+            }]
+        );
+        assert_eq!(
+            executor.mouse().get_current_position().unwrap(),
+            Position::with_screen(1950, 500, 1)
+        );
+        assert_eq!(
+            executor.ui().calls(),
+            vec!["animate(1900, 500 -> 1950, 500)"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_input_dispatches_mode_output() {
+        let mut executor = create_executor();
+
+        // Basic mode emits a relative move for 'i'
+        let outcome = executor.handle_input(create_test_input('i')).await.unwrap();
+        assert_eq!(outcome, ActionOutcome::Continue);
+        assert_eq!(executor.mouse().position, Position::new(100, 80));
+
+        executor.handle_input(create_test_input('n')).await.unwrap();
+        assert_eq!(executor.mouse().calls.last().unwrap(), "click(Left)");
+    }
+
+    #[tokio::test]
+    async fn test_mode_activation_and_exit() {
+        let mut executor = create_executor();
+
+        executor
+            .execute(Action::ActivateMode(InteractionMode::Grid))
+            .await
+            .unwrap();
+        assert_eq!(
+            executor.modes().get_current_mode(),
+            Some(InteractionMode::Grid)
+        );
+
+        executor.execute(Action::HighlightArea('q')).await.unwrap();
+
+        let outcome = executor.execute(Action::Exit).await.unwrap();
+        assert_eq!(outcome, ActionOutcome::Exit);
+        assert_eq!(executor.modes().get_current_mode(), None);
+        assert_eq!(
+            executor.ui().calls(),
+            vec![
+                "show_mode_indicator(Grid)",
+                "highlight_area(q)",
+                "hide_all_overlays",
+                "hide_mode_indicator",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_undo_and_redo() {
+        let mut executor = create_executor();
+        executor
+            .execute(Action::ActivateMode(InteractionMode::Basic))
+            .await
+            .unwrap();
+        executor
+            .execute(Action::MoveCursor(
+                Position::new(500, 400),
+                AnimationType::Instant,
+            ))
+            .await
+            .unwrap();
+        executor
+            .execute(Action::ActivateMode(InteractionMode::Grid))
+            .await
+            .unwrap();
+        // Relative moves are not undone on their own
+        executor
+            .execute(Action::MoveRelative(10, 10, AnimationType::Instant))
+            .await
+            .unwrap();
+        assert_eq!(executor.undo_history().undo_len(), 3);
+
+        executor.execute(Action::Undo).await.unwrap();
+        assert_eq!(executor.mouse().position, Position::new(500, 400));
+        assert_eq!(
+            executor.modes().get_current_mode(),
+            Some(InteractionMode::Basic)
+        );
+
+        executor.execute(Action::Undo).await.unwrap();
+        assert_eq!(executor.mouse().position, Position::new(100, 100));
+
+        // The activation from inactive only restores the cursor
+        executor.execute(Action::Undo).await.unwrap();
+        executor.execute(Action::Undo).await.unwrap();
+        assert_eq!(executor.undo_history().undo_len(), 0);
+        assert_eq!(
+            executor.modes().get_current_mode(),
+            Some(InteractionMode::Basic)
+        );
+
+        for _ in 0..3 {
+            executor.execute(Action::Redo).await.unwrap();
+        }
+        assert_eq!(executor.mouse().position, Position::new(510, 410));
+        assert_eq!(
+            executor.modes().get_current_mode(),
+            Some(InteractionMode::Grid)
+        );
+        assert_eq!(executor.undo_history().redo_len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_undo_deactivation_with_default_animation() {
+        let mut executor = ActionExecutor::new(
+            ModeManager::new(KeyBindings::default()),
+            RecordingMouse::new(VirtualMouse::new(Position::new(100, 100), Vec::new())),
+            MockUi::default(),
+        );
+        executor.set_default_animation(AnimationType::Linear);
+
+        executor
+            .execute(Action::ActivateMode(InteractionMode::Basic))
+            .await
+            .unwrap();
+        executor.execute(Action::DeactivateMode).await.unwrap();
+        // Exiting while inactive leaves nothing to undo
+        executor.execute(Action::Exit).await.unwrap();
+        assert_eq!(executor.undo_history().undo_len(), 2);
+
+        executor.mouse_mut().set_recording(true);
+        executor.execute(Action::Undo).await.unwrap();
+        assert_eq!(
+            executor.modes().get_current_mode(),
+            Some(InteractionMode::Basic)
+        );
+        assert_eq!(
+            executor.mouse_mut().take_ops(),
+            vec![MouseOp::MoveTo {
+                position: Position::new(100, 100),
+                animation: AnimationType::Linear,
+            }]
+        );
+
+        executor.execute(Action::Exit).await.unwrap();
+        assert_eq!(executor.undo_history().undo_len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_speed_actions() {
+        let mut executor = create_executor();
+        executor.modes().set_movement_config(MovementConfig {
+            default_speed: MovementSpeed::Slow,
+            ..MovementConfig::default()
+        });
+
+        executor.execute(Action::ToggleSpeed).await.unwrap();
+        assert_eq!(executor.mouse().speed, MovementSpeed::Fast);
+        assert_eq!(executor.modes().get_speed(), MovementSpeed::Fast);
+
+        executor.execute(Action::ToggleSpeed).await.unwrap();
+        assert_eq!(executor.mouse().speed, MovementSpeed::Slow);
+        assert_eq!(executor.modes().get_speed(), MovementSpeed::Slow);
+
+        executor
+            .execute(Action::SetSpeed(MovementSpeed::Precision))
+            .await
+            .unwrap();
+        assert_eq!(executor.mouse().speed, MovementSpeed::Precision);
+    }
+
+    #[tokio::test]
+    async fn test_scroll_actions() {
+        let mut executor = create_executor();
+        executor.set_scroll_config(ScrollConfig {
+            natural_scroll: true,
+            ..ScrollConfig::default()
+        });
+
+        executor
+            .execute(Action::Scroll(ScrollDirection::Down, 3))
+            .await
+            .unwrap();
+        // No screens are known, so a page is 1080 * 0.875 = 945px or 24 notches
+        executor
+            .execute(Action::ScrollPage(ScrollDirection::Up))
+            .await
+            .unwrap();
+        executor
+            .execute(Action::ScrollRelease(ScrollDirection::Up))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            executor.mouse().calls,
+            vec!["scroll(Up, 3)", "scroll(Down, 24)"]
+        );
+    }
+
+    /// Glides scrolls with a `SmoothScroller` like the `MouseService`, into a pixel counter
+    struct GlidingMouse {
+        clock: Arc<VirtualClock>,
+        scroller: Option<SmoothScroller>,
+        screens: Vec<ScreenBounds>,
+        scrolled_down: i32,
+    }
+
+    impl GlidingMouse {
+        fn new(screens: Vec<ScreenBounds>) -> Self {
+            let clock = Arc::new(VirtualClock::new());
+            let scroller = SmoothScroller::new(clock.clone(), ScrollConfig::default());
+            Self {
+                clock,
+                scroller: Some(scroller),
+                screens,
+                scrolled_down: 0,
+            }
+        }
+
+        fn scroller(&mut self) -> &mut SmoothScroller {
+            self.scroller.as_mut().unwrap()
+        }
+
+        /// Tick the scroller at 8ms frames for `duration`
+        fn run_for(&mut self, duration: Duration) {
+            let mut scroller = self.scroller.take().unwrap();
+            let frame = Duration::from_millis(8);
+            let mut elapsed = Duration::ZERO;
+            while elapsed < duration {
+                self.clock.advance(frame);
+                elapsed += frame;
+                scroller.tick(self).unwrap();
+            }
+            self.scroller = Some(scroller);
+        }
+    }
+
+    impl MouseOperations for GlidingMouse {
+        fn move_to(&mut self, _position: Position, _animation: AnimationType) -> MouseResult<()> {
+            Ok(())
+        }
+
+        fn click(&mut self, _button: MouseButton) -> MouseResult<()> {
+            Ok(())
+        }
+
+        fn scroll(&mut self, direction: ScrollDirection, amount: i32) -> MouseResult<()> {
+            self.scroller().scroll(direction, amount);
+            Ok(())
+        }
+
+        fn supports_pixel_scroll(&self) -> bool {
+            true
+        }
+
+        fn scroll_pixels(&mut self, direction: ScrollDirection, pixels: i32) -> MouseResult<()> {
+            match direction {
+                ScrollDirection::Down => self.scrolled_down += pixels,
+                ScrollDirection::Up => self.scrolled_down -= pixels,
+                _ => {}
+            }
+            Ok(())
+        }
+
+        fn release_scroll(&mut self, direction: ScrollDirection) -> MouseResult<()> {
+            self.scroller().release(direction);
+            Ok(())
+        }
+
+        fn glides_scrolls(&self) -> bool {
+            true
+        }
+
+        fn scroll_page(&mut self, direction: ScrollDirection) -> MouseResult<()> {
+            let viewport = page_viewport(&self.screens, Position::new(0, 0), direction);
+            self.scroller().page(direction, viewport);
+            Ok(())
+        }
+
+        fn get_current_position(&self) -> MouseResult<Position> {
+            Ok(Position::new(0, 0))
+        }
+
+        fn get_screen_bounds(&self) -> MouseResult<Vec<ScreenBounds>> {
+            Ok(self.screens.clone())
+        }
+
+        fn set_movement_speed(&mut self, _speed: MovementSpeed) {}
+
+        fn get_movement_speed(&self) -> MovementSpeed {
+            MovementSpeed::Normal
+        }
+    }
+
+    #[tokio::test]
+    async fn test_page_scroll_moves_one_page_on_gliding_mouse() {
+        let screens = vec![ScreenBounds {
+            id: 0,
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1200,
+            is_primary: true,
+            scale_factor: 1.0,
+        }];
+        let mut executor = ActionExecutor::new(
+            ModeManager::new(KeyBindings::default()),
+            GlidingMouse::new(screens),
+            MockUi::default(),
+        );
+        let page = (1200.0 * ScrollConfig::default().page_fraction) as i32;
+
+        // A page scroll is not a held key, so it neither keeps scrolling...
+        executor
+            .execute(Action::ScrollPage(ScrollDirection::Down))
+            .await
+            .unwrap();
+        executor.mouse_mut().run_for(Duration::from_secs(5));
+        assert_eq!(executor.mouse().scrolled_down, page);
+
+        // ...nor is it swallowed by a hold of the same direction
+        executor
+            .execute(Action::Scroll(ScrollDirection::Down, 1))
+            .await
+            .unwrap();
+        executor
+            .execute(Action::ScrollPage(ScrollDirection::Down))
+            .await
+            .unwrap();
+        executor
+            .execute(Action::ScrollRelease(ScrollDirection::Down))
+            .await
+            .unwrap();
+        executor.mouse_mut().run_for(Duration::from_secs(5));
+        assert_eq!(executor.mouse().scrolled_down, 2 * page + 40);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_can_skip_actions() {
+        let mut executor = create_executor();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        executor.add_middleware(Box::new(SkipClicks { seen: seen.clone() }));
+
+        executor
+            .execute(Action::Click(MouseButton::Left))
+            .await
+            .unwrap();
+        executor
+            .execute(Action::Scroll(ScrollDirection::Down, 3))
+            .await
+            .unwrap();
+
+        assert_eq!(executor.mouse().calls, vec!["scroll(Down, 3)"]);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![Action::Scroll(ScrollDirection::Down, 3)]
+        );
+    }
+}
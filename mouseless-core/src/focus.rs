@@ -0,0 +1,121 @@
+//! Focus module
+//!
+//! This module provides functionality for:
+//! - Detecting the application whose window has the keyboard focus
+//! - Keeping the display connection open between lookups
+
+/// Looks up the application that has the keyboard focus
+///
+/// On Linux the focused window comes from the X11 `_NET_ACTIVE_WINDOW`
+/// property of the root window and its application name from the class part
+/// of `WM_CLASS`, e.g. `firefox`. Under Wayland this only sees XWayland
+/// windows. The connection is opened on the first lookup; when it cannot be
+/// opened lookups return `None` without trying again.
+#[derive(Default)]
+pub struct FocusTracker {
+    #[cfg(target_os = "linux")]
+    x11: linux::X11Focus,
+}
+
+impl FocusTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the name of the focused application, if it can be detected
+    pub fn focused_application(&mut self) -> Option<String> {
+        #[cfg(target_os = "linux")]
+        {
+            self.x11.focused_application()
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            //TODO: Use NSWorkspace.frontmostApplication on macOS
+            None
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use tracing::debug;
+    use x11rb::{
+        connection::Connection as _,
+        protocol::xproto::{Atom, AtomEnum, ConnectionExt as _, Window},
+        rust_connection::RustConnection,
+    };
+
+    pub(super) struct Display {
+        conn: RustConnection,
+        root: Window,
+        active_window: Atom,
+    }
+
+    #[derive(Default)]
+    pub(super) enum X11Focus {
+        #[default]
+        Unconnected,
+        Connected(Box<Display>),
+        Unavailable,
+    }
+
+    impl X11Focus {
+        pub(super) fn focused_application(&mut self) -> Option<String> {
+            if let Self::Unconnected = self {
+                *self = match Self::connect() {
+                    Ok(display) => Self::Connected(Box::new(display)),
+                    Err(e) => {
+                        debug!("Focused application detection unavailable: {}", e);
+                        Self::Unavailable
+                    }
+                };
+            }
+
+            match self {
+                Self::Connected(display) => display.focused_application(),
+                _ => None,
+            }
+        }
+
+        fn connect() -> Result<Display, Box<dyn std::error::Error>> {
+            let (conn, screen_num) = x11rb::connect(None)?;
+            let root = conn.setup().roots[screen_num].root;
+            let active_window = conn.intern_atom(true, b"_NET_ACTIVE_WINDOW")?.reply()?.atom;
+            Ok(Display {
+                conn,
+                root,
+                active_window,
+            })
+        }
+    }
+
+    impl Display {
+        fn focused_application(&self) -> Option<String> {
+            let window = self
+                .conn
+                .get_property(false, self.root, self.active_window, AtomEnum::WINDOW, 0, 1)
+                .ok()?
+                .reply()
+                .ok()?
+                .value32()?
+                .next()
+                .filter(|window| *window != 0)?;
+
+            let class = self
+                .conn
+                .get_property(false, window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, 256)
+                .ok()?
+                .reply()
+                .ok()?
+                .value;
+
+            // WM_CLASS holds the instance and the class name, each NUL-terminated
+            class
+                .split(|byte| *byte == 0)
+                .filter(|part| !part.is_empty())
+                .nth(1)
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+        }
+    }
+}
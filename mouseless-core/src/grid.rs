@@ -1,6 +1,10 @@
-use crate::{GridConfig, MouselessError, Position, Result, ScreenBounds};
+use crate::{
+    GridConfig, HintGenerator, HintMatch, HintTrie, KeyRow, KeyboardLayout, LabelPriority,
+    MouselessError, MultiScreenLayout, Position, Result, ScreenBounds, ScreenManager,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::Range;
 
 /// Represents a single grid cell with its position and key combination
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,7 +16,7 @@ pub struct GridCell {
     pub center_position: Position,
 }
 
-/// Grid cell boundary information
+/// Grid cell boundary information in logical pixels
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GridCellBounds {
     pub x: i32,
@@ -38,91 +42,249 @@ impl GridCellBounds {
 }
 
 /// Grid manager handles grid calculations and key combinations
+///
+/// With several screens the grid either spans the whole desktop or is laid
+/// out once per screen. Per-screen labels are prefixed with the 1-based screen
+/// number; the cells of the active screen can also be selected without prefix.
+/// Cells are computed in logical pixels, so they cover the same share of a
+/// scaled screen as of an unscaled one.
 #[derive(Clone)]
 pub struct GridManager {
     config: GridConfig,
     cells: Vec<GridCell>,
     key_to_cell: HashMap<String, usize>,
-    screen_bounds: ScreenBounds,
-    //TODO: Add support for multi-screen grid management
-    //TODO: Implement cross-screen grid cell navigation
-    //TODO: Add screen-aware key combination generation
+    label_trie: HintTrie,
+    cell_usage: HashMap<(u32, u32), u32>,
+    screens: Vec<ScreenBounds>,
+    multi_screen: MultiScreenLayout,
+    active_screen: usize,
+    keyboard_layout: KeyboardLayout,
 }
 
 impl GridManager {
     /// Create a new grid manager with the specified configuration and screen bounds
     pub fn new(config: GridConfig, screen_bounds: ScreenBounds) -> Result<Self> {
+        Self::with_layout(config, screen_bounds, KeyboardLayout::default())
+    }
+
+    /// Create a new grid manager whose key combinations follow a keyboard layout
+    pub fn with_layout(
+        config: GridConfig,
+        screen_bounds: ScreenBounds,
+        keyboard_layout: KeyboardLayout,
+    ) -> Result<Self> {
+        Self::for_screens(
+            config,
+            vec![screen_bounds],
+            MultiScreenLayout::default(),
+            keyboard_layout,
+        )
+    }
+
+    /// Create a new grid manager covering several screens
+    pub fn for_screens(
+        config: GridConfig,
+        screens: Vec<ScreenBounds>,
+        multi_screen: MultiScreenLayout,
+        keyboard_layout: KeyboardLayout,
+    ) -> Result<Self> {
+        let active_screen = screens.iter().position(|s| s.is_primary).unwrap_or(0);
         let mut manager = Self {
             config,
             cells: Vec::new(),
             key_to_cell: HashMap::new(),
-            screen_bounds,
+            label_trie: HintTrie::new(),
+            cell_usage: HashMap::new(),
+            screens,
+            multi_screen,
+            active_screen,
+            keyboard_layout,
         };
 
         manager.calculate_grid()?;
         Ok(manager)
     }
 
+    /// Check whether labels carry a screen number prefix
+    fn uses_screen_prefix(&self) -> bool {
+        self.multi_screen == MultiScreenLayout::PerScreen && self.screens.len() > 1
+    }
+
+    /// Get the regions covered by one grid each, with their screen (if any)
+    fn grid_regions(&self) -> Result<Vec<(ScreenBounds, Option<u32>)>> {
+        match self.multi_screen {
+            MultiScreenLayout::PerScreen => Ok(self
+                .screens
+                .iter()
+                .map(|screen| (screen.clone(), Some(screen.id)))
+                .collect()),
+            MultiScreenLayout::SpanDesktop => {
+                let (x, y, width, height) = ScreenManager::desktop_bounds_of(&self.screens)?;
+                let desktop = ScreenBounds {
+                    id: 0,
+                    x,
+                    y,
+                    width,
+                    height,
+                    is_primary: true,
+                    scale_factor: 1.0,
+                };
+                Ok(vec![(desktop, None)])
+            }
+        }
+    }
+
     /// Calculate grid cells and generate key combinations
     fn calculate_grid(&mut self) -> Result<()> {
         self.cells.clear();
-        self.key_to_cell.clear();
 
-        let cell_width = self.screen_bounds.width / self.config.columns;
-        let cell_height = self.screen_bounds.height / self.config.rows;
+        if self.screens.is_empty() {
+            return Err(invalid_input("Grid needs at least one screen".to_string()));
+        }
+        if self.uses_screen_prefix() && self.screens.len() > 9 {
+            return Err(invalid_input(format!(
+                "Cannot prefix grid labels for {} screens",
+                self.screens.len()
+            )));
+        }
+
+        for (region, screen_id) in self.grid_regions()? {
+            let cell_width = region.width / self.config.columns;
+            let cell_height = region.height / self.config.rows;
+
+            for row in 0..self.config.rows {
+                for col in 0..self.config.columns {
+                    let x = region.x + (col * cell_width) as i32;
+                    let y = region.y + (row * cell_height) as i32;
+
+                    let bounds = GridCellBounds {
+                        x,
+                        y,
+                        width: cell_width,
+                        height: cell_height,
+                    };
+
+                    let center = bounds.center();
+                    let center_position = match screen_id {
+                        Some(id) => Position::with_screen(center.x, center.y, id),
+                        None => ScreenManager::place_on_screens(&self.screens, center),
+                    };
+
+                    self.cells.push(GridCell {
+                        row,
+                        column: col,
+                        bounds,
+                        key_combination: String::new(),
+                        center_position,
+                    });
+                }
+            }
+        }
+
+        self.assign_labels()
+    }
 
-        // Generate key combinations for grid cells
+    /// Assign key combinations to cells, shortest labels to the highest priority cells
+    fn assign_labels(&mut self) -> Result<()> {
         let key_combinations = self.generate_key_combinations()?;
+        let prefixed = self.uses_screen_prefix();
+
+        if prefixed
+            && key_combinations
+                .iter()
+                .any(|label| label.starts_with(|c: char| c.is_ascii_digit()))
+        {
+            return Err(invalid_input(
+                "Grid labels must not start with a digit when screen prefixes are used".to_string(),
+            ));
+        }
 
-        let mut key_index = 0;
+        let cells_per_grid = key_combinations.len();
+        for (grid_index, start) in (0..self.cells.len())
+            .step_by(cells_per_grid.max(1))
+            .enumerate()
+        {
+            let cell_order = self.cells_by_priority(start..start + cells_per_grid);
+
+            for (key_combination, index) in key_combinations.iter().zip(cell_order) {
+                self.cells[index].key_combination = if prefixed {
+                    format!("{}{}", grid_index + 1, key_combination)
+                } else {
+                    key_combination.clone()
+                };
+            }
+        }
 
-        for row in 0..self.config.rows {
-            for col in 0..self.config.columns {
-                if key_index >= key_combinations.len() {
-                    return Err(MouselessError::SystemError(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "Not enough key combinations for grid size",
-                    )));
-                }
+        self.key_to_cell = self
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (cell.key_combination.clone(), index))
+            .collect();
 
-                let x = self.screen_bounds.x + (col * cell_width) as i32;
-                let y = self.screen_bounds.y + (row * cell_height) as i32;
+        self.build_label_trie();
+        Ok(())
+    }
 
-                let bounds = GridCellBounds {
-                    x,
-                    y,
-                    width: cell_width,
-                    height: cell_height,
-                };
+    /// Build the label trie, adding unprefixed labels for the active screen
+    fn build_label_trie(&mut self) {
+        let mut trie = HintTrie::new();
+        for (key_combination, &index) in &self.key_to_cell {
+            trie.insert(key_combination, index);
+        }
 
-                let center_position = bounds.center();
-                let key_combination = key_combinations[key_index].clone();
+        if self.uses_screen_prefix() {
+            let prefix = char::from_digit(self.active_screen as u32 + 1, 10).unwrap_or('1');
+            for (key_combination, &index) in &self.key_to_cell {
+                if let Some(label) = key_combination.strip_prefix(prefix) {
+                    trie.insert(label, index);
+                }
+            }
+        }
 
-                let cell = GridCell {
-                    row,
-                    column: col,
-                    bounds,
-                    key_combination: key_combination.clone(),
-                    center_position,
-                };
+        self.label_trie = trie;
+    }
 
-                self.key_to_cell.insert(key_combination, self.cells.len());
-                self.cells.push(cell);
-                key_index += 1;
+    /// Get cell indices of one grid ordered by the configured label priority
+    fn cells_by_priority(&self, grid: Range<usize>) -> Vec<usize> {
+        let mut order: Vec<usize> = grid.collect();
+
+        match self.config.label_priority {
+            LabelPriority::RowMajor => {}
+            LabelPriority::Center => {
+                // Distances in cell units, doubled to stay in integers
+                let center_row = self.config.rows as i64 - 1;
+                let center_col = self.config.columns as i64 - 1;
+                order.sort_by_key(|&index| {
+                    let cell = &self.cells[index];
+                    let dy = 2 * cell.row as i64 - center_row;
+                    let dx = 2 * cell.column as i64 - center_col;
+                    dx * dx + dy * dy
+                });
+            }
+            LabelPriority::Usage => {
+                order.sort_by_key(|&index| {
+                    let cell = &self.cells[index];
+                    std::cmp::Reverse(self.cell_usage_count(cell.row, cell.column))
+                });
             }
         }
 
-        Ok(())
+        order
     }
 
-    /// Generate two-key combinations for grid cells
+    /// Generate key combinations for grid cells, shortest first
     fn generate_key_combinations(&self) -> Result<Vec<String>> {
         let total_cells = (self.config.rows * self.config.columns) as usize;
 
-        // Use common keyboard keys for combinations
+        if let Some(alphabet) = &self.config.label_alphabet {
+            return Ok(HintGenerator::new(alphabet)?.generate(total_cells));
+        }
+
+        // Use common keyboard keys for combinations, taken from physical positions
         // First set: home row keys for better ergonomics
-        let first_keys = ['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l'];
-        let second_keys = ['q', 'w', 'e', 'r', 't', 'y', 'u', 'i', 'o', 'p'];
+        let first_keys = Self::first_keys(&self.keyboard_layout);
+        let second_keys = Self::second_keys(&self.keyboard_layout);
 
         let mut combinations = Vec::new();
 
@@ -141,8 +303,8 @@ impl GridManager {
 
         // If we need more combinations, use additional keys
         if combinations.len() < total_cells {
-            let additional_first = ['z', 'x', 'c', 'v', 'b', 'n', 'm'];
-            let additional_second = ['1', '2', '3', '4', '5', '6', '7', '8', '9', '0'];
+            let additional_first = Self::additional_first_keys(&self.keyboard_layout);
+            let additional_second = self.keyboard_layout.row(KeyRow::Number);
 
             for &first in &additional_first {
                 for &second in &additional_second {
@@ -157,14 +319,11 @@ impl GridManager {
             }
         }
 
+        // Beyond the two-key pairs, fall back to variable length labels over
+        // the home and top rows
         if combinations.len() < total_cells {
-            return Err(MouselessError::SystemError(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!(
-                    "Cannot generate enough key combinations for {}x{} grid",
-                    self.config.rows, self.config.columns
-                ),
-            )));
+            let alphabet: String = first_keys.iter().chain(&second_keys).collect();
+            return Ok(HintGenerator::new(&alphabet)?.generate(total_cells));
         }
 
         // Take only the number we need
@@ -172,11 +331,50 @@ impl GridManager {
         Ok(combinations)
     }
 
+    /// First keys of a combination: the home row without the rightmost key
+    pub fn first_keys(layout: &KeyboardLayout) -> Vec<char> {
+        layout.row(KeyRow::Home).into_iter().take(9).collect()
+    }
+
+    /// Second keys of a combination: the whole top row
+    pub fn second_keys(layout: &KeyboardLayout) -> Vec<char> {
+        layout.row(KeyRow::Top)
+    }
+
+    /// Extra first keys used once the home row is exhausted: the left of the bottom row
+    pub fn additional_first_keys(layout: &KeyboardLayout) -> Vec<char> {
+        layout.row(KeyRow::Bottom).into_iter().take(7).collect()
+    }
+
     /// Get grid cell by key combination
     pub fn get_cell_by_keys(&self, key_combination: &str) -> Option<&GridCell> {
-        self.key_to_cell
-            .get(key_combination)
-            .and_then(|&index| self.cells.get(index))
+        match self.label_trie.lookup(key_combination) {
+            HintMatch::Complete(index) => self.cells.get(index),
+            _ => None,
+        }
+    }
+
+    /// Match a typed key sequence against the cell labels
+    pub fn match_keys(&self, key_sequence: &str) -> HintMatch {
+        self.label_trie.lookup(key_sequence)
+    }
+
+    /// Record that a cell was selected, for usage based label priority
+    ///
+    /// Labels are kept stable until the grid is recalculated or
+    /// [`GridManager::refresh_labels`] is called.
+    pub fn record_cell_use(&mut self, row: u32, column: u32) {
+        *self.cell_usage.entry((row, column)).or_insert(0) += 1;
+    }
+
+    /// Get how often a cell was selected
+    pub fn cell_usage_count(&self, row: u32, column: u32) -> u32 {
+        self.cell_usage.get(&(row, column)).copied().unwrap_or(0)
+    }
+
+    /// Reassign labels using the current label priority and usage counts
+    pub fn refresh_labels(&mut self) -> Result<()> {
+        self.assign_labels()
     }
 
     /// Get all grid cells
@@ -197,7 +395,51 @@ impl GridManager {
 
     /// Update screen bounds and recalculate grid
     pub fn update_screen_bounds(&mut self, screen_bounds: ScreenBounds) -> Result<()> {
-        self.screen_bounds = screen_bounds;
+        self.update_screens(vec![screen_bounds])
+    }
+
+    /// Update the covered screens and recalculate grid
+    pub fn update_screens(&mut self, screens: Vec<ScreenBounds>) -> Result<()> {
+        let active_id = self.screens.get(self.active_screen).map(|s| s.id);
+        self.active_screen = screens
+            .iter()
+            .position(|s| Some(s.id) == active_id)
+            .or_else(|| screens.iter().position(|s| s.is_primary))
+            .unwrap_or(0);
+        self.screens = screens;
+        self.calculate_grid()
+    }
+
+    /// Get the covered screens
+    pub fn screens(&self) -> &[ScreenBounds] {
+        &self.screens
+    }
+
+    /// Update how the grid is laid out over several screens
+    pub fn update_multi_screen_layout(&mut self, multi_screen: MultiScreenLayout) -> Result<()> {
+        self.multi_screen = multi_screen;
+        self.calculate_grid()
+    }
+
+    /// Set the screen whose cells can be selected without a screen prefix
+    pub fn set_active_screen(&mut self, screen_id: u32) -> Result<()> {
+        self.active_screen = self
+            .screens
+            .iter()
+            .position(|s| s.id == screen_id)
+            .ok_or_else(|| invalid_input(format!("Screen with ID {} not found", screen_id)))?;
+        self.build_label_trie();
+        Ok(())
+    }
+
+    /// Get the keyboard layout used for key combinations
+    pub fn keyboard_layout(&self) -> &KeyboardLayout {
+        &self.keyboard_layout
+    }
+
+    /// Update keyboard layout and regenerate key combinations
+    pub fn update_keyboard_layout(&mut self, keyboard_layout: KeyboardLayout) -> Result<()> {
+        self.keyboard_layout = keyboard_layout;
         self.calculate_grid()
     }
 
@@ -217,10 +459,18 @@ impl GridManager {
     }
 }
 
+fn invalid_input(reason: String) -> MouselessError {
+    MouselessError::SystemError(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        reason,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::AnimationType;
+    use crate::MultiScreenLayout;
+    use crate::{AnimationType, LabelPriority, PhysicalPosition};
 
     fn create_test_screen_bounds() -> ScreenBounds {
         ScreenBounds {
@@ -230,6 +480,7 @@ mod tests {
             width: 1920,
             height: 1080,
             is_primary: true,
+            scale_factor: 1.0,
         }
     }
 
@@ -242,6 +493,8 @@ mod tests {
             cell_padding: 2,
             border_width: 1,
             opacity: 0.8,
+            label_alphabet: None,
+            label_priority: LabelPriority::RowMajor,
         }
     }
 
@@ -341,6 +594,8 @@ mod tests {
             cell_padding: 2,
             border_width: 1,
             opacity: 0.8,
+            label_alphabet: None,
+            label_priority: LabelPriority::RowMajor,
         };
         let screen_bounds = create_test_screen_bounds();
 
@@ -421,6 +676,277 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_key_combinations_follow_layout() {
+        let config = create_test_grid_config();
+        let screen_bounds = create_test_screen_bounds();
+
+        let manager =
+            GridManager::with_layout(config, screen_bounds, KeyboardLayout::dvorak()).unwrap();
+
+        // Same physical keys as QWERTY "aq", "aw", "ae"
+        let keys: Vec<&str> = manager
+            .get_cells()
+            .iter()
+            .take(3)
+            .map(|cell| cell.key_combination.as_str())
+            .collect();
+        assert_eq!(keys, vec!["a'", "a,", "a."]);
+
+        for cell in manager.get_cells() {
+            let first_char = cell.key_combination.chars().next().unwrap();
+            assert!("aoeuidhtn".contains(first_char));
+        }
+    }
+
+    #[test]
+    fn test_update_keyboard_layout() {
+        let config = create_test_grid_config();
+        let screen_bounds = create_test_screen_bounds();
+
+        let mut manager = GridManager::new(config, screen_bounds).unwrap();
+        assert!(manager.get_cell_by_keys("ae").is_some());
+
+        manager
+            .update_keyboard_layout(KeyboardLayout::colemak())
+            .unwrap();
+        assert_eq!(manager.keyboard_layout().name, "colemak");
+        assert!(manager.get_cell_by_keys("aq").is_some()); // Q stays in place on Colemak
+        assert!(manager.get_cell_by_keys("af").is_some()); // QWERTY E becomes F
+        assert!(manager.get_cell_by_keys("ae").is_none()); // E is not on the Colemak top row
+    }
+
+    #[test]
+    fn test_grid_beyond_two_key_pairs() {
+        let config = GridConfig {
+            rows: 20,
+            columns: 20,
+            ..GridConfig::default()
+        };
+        let screen_bounds = create_test_screen_bounds();
+
+        let manager = GridManager::new(config, screen_bounds).unwrap();
+        assert_eq!(manager.get_cells().len(), 400);
+
+        for cell in manager.get_cells() {
+            assert!(matches!(
+                manager.match_keys(&cell.key_combination),
+                HintMatch::Complete(_)
+            ));
+        }
+        assert_eq!(manager.key_to_cell.len(), 400);
+    }
+
+    #[test]
+    fn test_custom_alphabet_with_center_priority() {
+        let config = GridConfig {
+            rows: 3,
+            columns: 3,
+            label_alphabet: Some("jkl;".to_string()),
+            label_priority: LabelPriority::Center,
+            ..GridConfig::default()
+        };
+        let screen_bounds = create_test_screen_bounds();
+
+        let manager = GridManager::new(config, screen_bounds).unwrap();
+
+        // The center cell gets the first single key label
+        let center = manager.get_cell_by_keys("l").unwrap();
+        assert_eq!((center.row, center.column), (1, 1));
+
+        // Corners are furthest from the center and get two key labels
+        let corner = manager.find_cell_at_position(Position::new(0, 0)).unwrap();
+        assert_eq!(corner.key_combination.len(), 2);
+
+        // Invalid alphabets are rejected
+        let config = GridConfig {
+            label_alphabet: Some("j".to_string()),
+            ..GridConfig::default()
+        };
+        assert!(GridManager::new(config, create_test_screen_bounds()).is_err());
+    }
+
+    #[test]
+    fn test_usage_priority() {
+        let config = GridConfig {
+            rows: 3,
+            columns: 3,
+            label_alphabet: Some("asdf".to_string()),
+            label_priority: LabelPriority::Usage,
+            ..GridConfig::default()
+        };
+        let screen_bounds = create_test_screen_bounds();
+
+        let mut manager = GridManager::new(config, screen_bounds).unwrap();
+        assert_eq!(manager.get_cell_by_keys("d").unwrap().row, 0);
+
+        manager.record_cell_use(2, 2);
+        manager.record_cell_use(2, 2);
+        manager.record_cell_use(1, 0);
+
+        // Labels stay stable until refreshed
+        assert_eq!(manager.get_cell_by_keys("d").unwrap().row, 0);
+
+        manager.refresh_labels().unwrap();
+        let most_used = manager.get_cell_by_keys("d").unwrap();
+        assert_eq!((most_used.row, most_used.column), (2, 2));
+        let second = manager.get_cell_by_keys("f").unwrap();
+        assert_eq!((second.row, second.column), (1, 0));
+    }
+
+    fn create_test_screens() -> Vec<ScreenBounds> {
+        vec![
+            ScreenBounds {
+                id: 1,
+                x: 0,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: true,
+                scale_factor: 1.0,
+            },
+            ScreenBounds {
+                id: 2,
+                x: -1280,
+                y: -200,
+                width: 1280,
+                height: 1024,
+                is_primary: false,
+                scale_factor: 1.0,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_per_screen_grids() {
+        let config = create_test_grid_config();
+        let mut manager = GridManager::for_screens(
+            config,
+            create_test_screens(),
+            MultiScreenLayout::PerScreen,
+            KeyboardLayout::default(),
+        )
+        .unwrap();
+
+        assert_eq!(manager.get_cells().len(), 18);
+
+        // Screen number prefixes select the screen
+        let primary = manager.get_cell_by_keys("1aq").unwrap();
+        assert_eq!(primary.center_position, Position::with_screen(320, 180, 1));
+
+        let secondary = manager.get_cell_by_keys("2aq").unwrap();
+        assert_eq!(secondary.bounds.x, -1280);
+        assert_eq!(secondary.bounds.y, -200);
+        assert_eq!(
+            secondary.center_position,
+            Position::with_screen(-1067, -30, 2)
+        );
+
+        // Labels without prefix select the active screen
+        assert_eq!(manager.get_cell_by_keys("aq").unwrap().bounds.x, 0);
+        manager.set_active_screen(2).unwrap();
+        assert_eq!(manager.get_cell_by_keys("aq").unwrap().bounds.x, -1280);
+        assert!(manager.set_active_screen(7).is_err());
+
+        // Single screen grids have no prefix
+        manager
+            .update_screens(vec![create_test_screen_bounds()])
+            .unwrap();
+        assert_eq!(manager.get_cells()[0].key_combination, "aq");
+    }
+
+    #[test]
+    fn test_mixed_dpi_grid() {
+        // 2x laptop screen next to a 1x external screen
+        let screens = vec![
+            ScreenBounds {
+                id: 1,
+                x: 0,
+                y: 0,
+                width: 1440,
+                height: 900,
+                is_primary: true,
+                scale_factor: 2.0,
+            },
+            ScreenBounds {
+                id: 2,
+                x: 1440,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: false,
+                scale_factor: 1.0,
+            },
+        ];
+        let manager = GridManager::for_screens(
+            create_test_grid_config(),
+            screens.clone(),
+            MultiScreenLayout::PerScreen,
+            KeyboardLayout::default(),
+        )
+        .unwrap();
+
+        // Cells are logical, so both screens get the same share per cell
+        let center = |screen_id| {
+            manager
+                .get_cells()
+                .iter()
+                .find(|c| {
+                    c.row == 1 && c.column == 1 && c.center_position.screen_id == Some(screen_id)
+                })
+                .unwrap()
+                .center_position
+        };
+        assert_eq!(center(1), Position::with_screen(720, 450, 1));
+        assert_eq!(center(2), Position::with_screen(2400, 540, 2));
+
+        assert_eq!(
+            ScreenManager::to_physical(&screens, center(1)),
+            Some(PhysicalPosition::new(1440, 900, 1))
+        );
+        let origins = ScreenManager::physical_origins(&screens);
+        for cell in manager.get_cells() {
+            let physical = ScreenManager::to_physical(&screens, cell.center_position).unwrap();
+            let index = screens
+                .iter()
+                .position(|s| s.id == physical.screen_id)
+                .unwrap();
+            assert!(screens[index].contains_physical(origins[index], physical));
+        }
+    }
+
+    #[test]
+    fn test_desktop_spanning_grid() {
+        let config = GridConfig {
+            rows: 4,
+            columns: 4,
+            ..GridConfig::default()
+        };
+        let manager = GridManager::for_screens(
+            config,
+            create_test_screens(),
+            MultiScreenLayout::SpanDesktop,
+            KeyboardLayout::default(),
+        )
+        .unwrap();
+
+        assert_eq!(manager.get_cells().len(), 16);
+
+        // Desktop spans x -1280..1920 and y -200..1080
+        let first = &manager.get_cells()[0];
+        assert_eq!(first.key_combination, "aq");
+        assert_eq!((first.bounds.x, first.bounds.y), (-1280, -200));
+        assert_eq!(first.center_position, Position::with_screen(-880, -40, 2));
+
+        // Cell centers below the second screen are clamped onto it
+        let bottom_left = manager.get_cell_by_keys("se").unwrap();
+        assert_eq!((bottom_left.row, bottom_left.column), (3, 0));
+        assert_eq!(
+            bottom_left.center_position,
+            Position::with_screen(-880, 823, 2)
+        );
+    }
+
     #[test]
     fn test_comprehensive_grid_functionality() {
         let test_cases = vec![
@@ -441,6 +967,8 @@ mod tests {
                 cell_padding: 2,
                 border_width: 1,
                 opacity: 0.8,
+                label_alphabet: None,
+                label_priority: LabelPriority::RowMajor,
             };
 
             let manager = GridManager::new(config, screen_bounds.clone()).unwrap();
@@ -1,33 +1,29 @@
-use std::time::{Duration, SystemTime};
+use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
 use crate::{
+    animation_scheduler::{SharedClock, SystemClock},
     error::Result,
-    models::{Action, AnimationType, InteractionMode, KeyInput, Position},
-    traits::KeyBindings,
-    GridManager,
+    models::{
+        Action, AnimationType, InteractionMode, KeyInput, LabelPriority, MultiScreenLayout,
+        ScreenBounds,
+    },
+    traits::{KeyBindings, Mode, ModeOverlay},
+    GridManager, HintMatch, KeyboardLayout,
 };
 
-/// Maximum time between key presses for a valid two-key combination (in milliseconds)
+/// Maximum time between key presses within one key sequence (in milliseconds)
 const KEY_COMBINATION_TIMEOUT_MS: u64 = 1000;
 
-/// State for tracking two-key input sequences
-#[derive(Debug, Clone)]
+/// State for tracking key input sequences
+#[derive(Debug, Clone, Default)]
 struct KeySequenceState {
-    first_key: Option<char>,
-    first_key_time: Option<SystemTime>,
+    keys: String,
+    last_key_time: Option<Duration>,
 }
 
-impl Default for KeySequenceState {
-    fn default() -> Self {
-        Self {
-            first_key: None,
-            first_key_time: None,
-        }
-    }
-}
-
-/// Grid mode handler for processing two-key input sequences
+/// Grid mode handler for processing key sequences of any length
 pub struct GridMode {
     /// Current key sequence state
     key_sequence: KeySequenceState,
@@ -37,6 +33,15 @@ pub struct GridMode {
 
     /// Whether grid mode is currently active
     is_active: bool,
+
+    /// Keyboard layout used to generate grid labels
+    keyboard_layout: KeyboardLayout,
+
+    /// Animation used for cursor movement
+    animation_type: AnimationType,
+
+    /// Clock for key sequence timeouts
+    clock: SharedClock,
 }
 
 impl GridMode {
@@ -46,24 +51,81 @@ impl GridMode {
             key_sequence: KeySequenceState::default(),
             grid_manager: None,
             is_active: false,
+            keyboard_layout: KeyboardLayout::default(),
+            animation_type: AnimationType::Smooth,
+            clock: Arc::new(SystemClock::new()),
         }
     }
 
+    /// Set the animation used for cursor movement
+    pub fn set_animation_type(&mut self, animation_type: AnimationType) {
+        self.animation_type = animation_type;
+    }
+
     /// Set the grid manager for this mode
     pub fn set_grid_manager(&mut self, grid_manager: Option<GridManager>) {
+        // Key combinations are generated by the grid manager, so follow its layout
+        if let Some(manager) = &grid_manager {
+            self.keyboard_layout = manager.keyboard_layout().clone();
+        }
         self.grid_manager = grid_manager;
+        self.reset_key_sequence();
         debug!("Grid manager updated in grid mode");
     }
 
+    /// Set the keyboard layout and regenerate the grid's key combinations
+    pub fn set_keyboard_layout(&mut self, keyboard_layout: KeyboardLayout) -> Result<()> {
+        if let Some(manager) = &mut self.grid_manager {
+            manager.update_keyboard_layout(keyboard_layout.clone())?;
+        }
+        self.keyboard_layout = keyboard_layout;
+        self.reset_key_sequence();
+        debug!("Keyboard layout updated in grid mode");
+        Ok(())
+    }
+
+    /// Update the screens covered by the grid
+    pub fn update_screens(&mut self, screens: Vec<ScreenBounds>) -> Result<()> {
+        if let Some(manager) = &mut self.grid_manager {
+            manager.update_screens(screens)?;
+        }
+        self.reset_key_sequence();
+        Ok(())
+    }
+
+    /// Update how the grid is laid out over several screens
+    pub fn update_multi_screen_layout(&mut self, multi_screen: MultiScreenLayout) -> Result<()> {
+        if let Some(manager) = &mut self.grid_manager {
+            manager.update_multi_screen_layout(multi_screen)?;
+        }
+        self.reset_key_sequence();
+        Ok(())
+    }
+
+    /// Get the keyboard layout used to generate grid labels
+    pub fn keyboard_layout(&self) -> &KeyboardLayout {
+        &self.keyboard_layout
+    }
+
     /// Get the current grid manager
     pub fn get_grid_manager(&self) -> Option<&GridManager> {
         self.grid_manager.as_ref()
     }
 
     /// Activate grid mode
+    ///
+    /// With usage based label priority, labels are reassigned here, so they
+    /// stay stable while grid mode is active.
     pub fn activate(&mut self) {
         self.is_active = true;
         self.reset_key_sequence();
+        if let Some(manager) = &mut self.grid_manager {
+            if manager.get_config().label_priority == LabelPriority::Usage {
+                if let Err(e) = manager.refresh_labels() {
+                    warn!("Failed to refresh grid labels: {}", e);
+                }
+            }
+        }
         info!("Grid mode activated");
     }
 
@@ -87,14 +149,21 @@ impl GridMode {
 
     /// Check if the key sequence has timed out
     fn is_sequence_timed_out(&self) -> bool {
-        if let Some(first_key_time) = self.key_sequence.first_key_time {
-            if let Ok(elapsed) = first_key_time.elapsed() {
-                return elapsed > Duration::from_millis(KEY_COMBINATION_TIMEOUT_MS);
-            }
+        if let Some(last_key_time) = self.key_sequence.last_key_time {
+            let elapsed = self.clock.now().saturating_sub(last_key_time);
+            return elapsed > Duration::from_millis(KEY_COMBINATION_TIMEOUT_MS);
         }
         false
     }
 
+    /// Match a key sequence against the current grid labels
+    fn match_keys(&self, key_sequence: &str) -> HintMatch {
+        self.grid_manager
+            .as_ref()
+            .map(|manager| manager.match_keys(key_sequence))
+            .unwrap_or(HintMatch::DeadEnd)
+    }
+
     /// Process input for grid mode
     pub fn process_input(
         &mut self,
@@ -111,112 +180,73 @@ impl GridMode {
             return Ok(Action::Exit);
         }
 
+        // Check if sequence has timed out
+        if self.is_sequence_timed_out() {
+            debug!("Key sequence timed out, resetting");
+            self.reset_key_sequence();
+        }
+
+        let key_sequence = format!("{}{}", self.key_sequence.keys, input.key);
+        let key_match = self.match_keys(&key_sequence);
+
         // Handle mode switching keys only if we're not in the middle of a key sequence
-        // and the key is not a valid grid key
-        if self.key_sequence.first_key.is_none() {
+        // and the key does not start a grid label
+        if self.key_sequence.keys.is_empty() && key_match == HintMatch::DeadEnd {
             match input.key {
                 'g' => return Ok(Action::ActivateMode(InteractionMode::Grid)),
                 'p' => return Ok(Action::ActivateMode(InteractionMode::Prediction)),
                 'b' => return Ok(Action::ActivateMode(InteractionMode::Basic)),
-                // 'a' is handled as a grid key since it's a valid first key
                 _ => {}
             }
         }
 
         // Check if we have a grid manager
-        if self.grid_manager.is_none() {
+        let Some(grid_manager) = &mut self.grid_manager else {
             warn!("No grid manager available for grid mode");
             return Ok(Action::NoAction);
-        }
+        };
 
-        // Check if sequence has timed out
-        if self.is_sequence_timed_out() {
-            debug!("Key sequence timed out, resetting");
-            self.reset_key_sequence();
-        }
+        match key_match {
+            HintMatch::Complete(index) => {
+                let cell = &grid_manager.get_cells()[index];
+                let (row, column, center) = (cell.row, cell.column, cell.center_position);
+                info!(
+                    "Grid cell selected: {} -> ({}, {})",
+                    key_sequence, center.x, center.y
+                );
 
-        // Process the key input
-        match self.key_sequence.first_key {
-            None => {
-                // This is the first key in the sequence
-                if self.is_valid_first_key(input.key) {
-                    self.key_sequence.first_key = Some(input.key);
-                    self.key_sequence.first_key_time = Some(input.timestamp);
-                    debug!("First key in sequence: '{}'", input.key);
-
-                    // Return a visual feedback action to show the first key was pressed
-                    Ok(Action::NoAction) // For now, just acknowledge the key
-                } else {
-                    debug!("Invalid first key: '{}'", input.key);
-                    Ok(Action::NoAction)
-                }
+                grid_manager.record_cell_use(row, column);
+                self.reset_key_sequence();
+
+                // Return action to move cursor to the grid cell center
+                Ok(Action::MoveCursor(center, self.animation_type))
             }
-            Some(first_key) => {
-                // This is the second key in the sequence
-                if self.is_valid_second_key(input.key) {
-                    let key_combination = format!("{}{}", first_key, input.key);
-                    debug!("Complete key combination: '{}'", key_combination);
-
-                    // Look up the grid cell for this key combination before resetting
-                    let result = if let Some(grid_manager) = &self.grid_manager {
-                        if let Some(cell) = grid_manager.get_cell_by_keys(&key_combination) {
-                            info!(
-                                "Grid cell selected: {} -> ({}, {})",
-                                key_combination, cell.center_position.x, cell.center_position.y
-                            );
-
-                            // Return action to move cursor to the grid cell center
-                            Ok(Action::MoveCursor(
-                                cell.center_position,
-                                AnimationType::Smooth,
-                            ))
-                        } else {
-                            warn!(
-                                "No grid cell found for key combination: '{}'",
-                                key_combination
-                            );
-                            Ok(Action::NoAction)
-                        }
-                    } else {
-                        Ok(Action::NoAction)
-                    };
-
-                    // Reset sequence for next input
-                    self.reset_key_sequence();
-                    result
-                } else {
-                    debug!("Invalid second key: '{}', resetting sequence", input.key);
-                    self.reset_key_sequence();
-                    Ok(Action::NoAction)
-                }
+            HintMatch::Prefix => {
+                debug!("Partial key sequence: '{}'", key_sequence);
+                self.key_sequence.keys = key_sequence;
+                self.key_sequence.last_key_time = Some(self.clock.now());
+                Ok(Action::NoAction)
+            }
+            HintMatch::DeadEnd => {
+                debug!("No grid label starts with '{}', resetting", key_sequence);
+                self.reset_key_sequence();
+                Ok(Action::NoAction)
             }
         }
     }
 
-    /// Check if a character is a valid first key in the sequence
-    fn is_valid_first_key(&self, key: char) -> bool {
-        // First keys are from the home row
-        ['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l'].contains(&key)
-    }
-
-    /// Check if a character is a valid second key in the sequence
-    fn is_valid_second_key(&self, key: char) -> bool {
-        // Second keys are from the top row
-        ['q', 'w', 'e', 'r', 't', 'y', 'u', 'i', 'o', 'p'].contains(&key)
-    }
-
     /// Get the current key sequence state for visual feedback
     pub fn get_current_sequence(&self) -> Option<String> {
-        if let Some(first_key) = self.key_sequence.first_key {
-            Some(format!("{}_", first_key))
-        } else {
+        if self.key_sequence.keys.is_empty() {
             None
+        } else {
+            Some(format!("{}_", self.key_sequence.keys))
         }
     }
 
-    /// Check if we're waiting for a second key
+    /// Check if we're waiting for further keys of a label
     pub fn is_waiting_for_second_key(&self) -> bool {
-        self.key_sequence.first_key.is_some() && !self.is_sequence_timed_out()
+        !self.key_sequence.keys.is_empty() && !self.is_sequence_timed_out()
     }
 
     /// Get all valid key combinations for the current grid
@@ -239,10 +269,50 @@ impl Default for GridMode {
     }
 }
 
+impl Mode for GridMode {
+    fn name(&self) -> &str {
+        InteractionMode::Grid.name()
+    }
+
+    fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    fn activate(&mut self) {
+        GridMode::activate(self)
+    }
+
+    fn deactivate(&mut self) {
+        GridMode::deactivate(self)
+    }
+
+    fn process_input(&mut self, input: KeyInput, bindings: &KeyBindings) -> Result<Action> {
+        GridMode::process_input(self, input, bindings)
+    }
+
+    fn overlay(&self) -> ModeOverlay {
+        match &self.grid_manager {
+            Some(manager) => {
+                ModeOverlay::Grid(manager.get_config().clone(), manager.get_cells().to_vec())
+            }
+            None => ModeOverlay::None,
+        }
+    }
+
+    /// Drop a partial label once the key sequence times out
+    fn tick(&mut self) -> Result<Action> {
+        if !self.key_sequence.keys.is_empty() && self.is_sequence_timed_out() {
+            debug!("Key sequence timed out, resetting");
+            self.reset_key_sequence();
+        }
+        Ok(Action::NoAction)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{GridConfig, ScreenBounds};
+    use crate::{GridConfig, LabelPriority, Position};
     use std::time::SystemTime;
 
     fn create_test_input(key: char) -> KeyInput {
@@ -262,6 +332,8 @@ mod tests {
             cell_padding: 2,
             border_width: 1,
             opacity: 0.8,
+            label_alphabet: None,
+            label_priority: LabelPriority::RowMajor,
         };
 
         let screen_bounds = ScreenBounds {
@@ -271,6 +343,7 @@ mod tests {
             width: 1920,
             height: 1080,
             is_primary: true,
+            scale_factor: 1.0,
         };
 
         GridManager::new(config, screen_bounds).unwrap()
@@ -296,29 +369,138 @@ mod tests {
 
     #[test]
     fn test_valid_key_checking() {
-        let grid_mode = GridMode::new();
+        let mut grid_mode = GridMode::new();
+
+        // Without a grid manager no sequence matches
+        assert_eq!(grid_mode.match_keys("a"), HintMatch::DeadEnd);
+
+        grid_mode.set_grid_manager(Some(create_test_grid_manager()));
+
+        // Home row keys start labels, top row keys complete them
+        assert_eq!(grid_mode.match_keys("a"), HintMatch::Prefix);
+        assert_eq!(grid_mode.match_keys("aq"), HintMatch::Complete(0));
+        assert_eq!(grid_mode.match_keys("ao"), HintMatch::Complete(8));
+
+        // Invalid keys
+        assert_eq!(grid_mode.match_keys("q"), HintMatch::DeadEnd);
+        assert_eq!(grid_mode.match_keys("1"), HintMatch::DeadEnd);
+        assert_eq!(grid_mode.match_keys("a1"), HintMatch::DeadEnd);
+        assert_eq!(grid_mode.match_keys("aqq"), HintMatch::DeadEnd);
+    }
+
+    #[test]
+    fn test_valid_keys_follow_layout() {
+        let mut grid_mode = GridMode::new();
+        grid_mode.set_grid_manager(Some(create_test_grid_manager()));
+        grid_mode
+            .set_keyboard_layout(KeyboardLayout::dvorak())
+            .unwrap();
+
+        // Dvorak top row replaces the QWERTY top row
+        assert_eq!(grid_mode.keyboard_layout().name, "dvorak");
+        assert_eq!(grid_mode.match_keys("a,"), HintMatch::Complete(1));
+        assert_eq!(grid_mode.match_keys("aw"), HintMatch::DeadEnd);
+
+        // Setting a grid manager adopts its layout
+        grid_mode.set_grid_manager(Some(create_test_grid_manager()));
+        assert_eq!(grid_mode.keyboard_layout().name, "qwerty");
+        assert_eq!(grid_mode.match_keys("aw"), HintMatch::Complete(1));
+    }
+
+    #[test]
+    fn test_variable_length_sequences() {
+        let config = GridConfig {
+            rows: 3,
+            columns: 3,
+            label_alphabet: Some("asdf".to_string()),
+            label_priority: LabelPriority::Center,
+            ..GridConfig::default()
+        };
+        let screen_bounds = ScreenBounds {
+            id: 1,
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            is_primary: true,
+            scale_factor: 1.0,
+        };
+
+        let mut grid_mode = GridMode::new();
+        grid_mode.set_grid_manager(Some(GridManager::new(config, screen_bounds).unwrap()));
+        grid_mode.activate();
+
+        let key_bindings = KeyBindings::default();
+
+        // The center cell gets the first single key label
+        let action = grid_mode
+            .process_input(create_test_input('d'), &key_bindings)
+            .unwrap();
+        assert_eq!(
+            action,
+            Action::MoveCursor(Position::with_screen(960, 540, 1), AnimationType::Smooth)
+        );
+
+        // Two key labels wait for the second key
+        let label = grid_mode
+            .get_valid_combinations()
+            .into_iter()
+            .find(|label| label.len() == 2)
+            .unwrap();
+        let mut keys = label.chars();
 
-        // Test valid first keys (home row)
-        assert!(grid_mode.is_valid_first_key('a'));
-        assert!(grid_mode.is_valid_first_key('s'));
-        assert!(grid_mode.is_valid_first_key('j'));
-        assert!(grid_mode.is_valid_first_key('l'));
-
-        // Test invalid first keys
-        assert!(!grid_mode.is_valid_first_key('q'));
-        assert!(!grid_mode.is_valid_first_key('1'));
-        assert!(!grid_mode.is_valid_first_key('z'));
-
-        // Test valid second keys (top row)
-        assert!(grid_mode.is_valid_second_key('q'));
-        assert!(grid_mode.is_valid_second_key('w'));
-        assert!(grid_mode.is_valid_second_key('i'));
-        assert!(grid_mode.is_valid_second_key('p'));
-
-        // Test invalid second keys
-        assert!(!grid_mode.is_valid_second_key('a'));
-        assert!(!grid_mode.is_valid_second_key('1'));
-        assert!(!grid_mode.is_valid_second_key('z'));
+        let action = grid_mode
+            .process_input(create_test_input(keys.next().unwrap()), &key_bindings)
+            .unwrap();
+        assert_eq!(action, Action::NoAction);
+        assert!(grid_mode.is_waiting_for_second_key());
+
+        let action = grid_mode
+            .process_input(create_test_input(keys.next().unwrap()), &key_bindings)
+            .unwrap();
+        assert!(matches!(action, Action::MoveCursor(_, _)));
+        assert!(!grid_mode.is_waiting_for_second_key());
+
+        // Selections are counted for usage based priority
+        let manager = grid_mode.get_grid_manager().unwrap();
+        assert_eq!(manager.cell_usage_count(1, 1), 1);
+    }
+
+    #[test]
+    fn test_usage_labels_refresh_on_activation() {
+        let mut grid_manager = create_test_grid_manager();
+        let mut config = grid_manager.get_config().clone();
+        config.label_priority = LabelPriority::Usage;
+        grid_manager.update_config(config).unwrap();
+
+        let mut grid_mode = GridMode::new();
+        grid_mode.set_grid_manager(Some(grid_manager));
+        grid_mode.activate();
+
+        let cells = grid_mode.get_grid_manager().unwrap().get_cells();
+        let first_label = cells[0].key_combination.clone();
+        let last_label = cells[8].key_combination.clone();
+
+        let key_bindings = KeyBindings::default();
+        for key in last_label.chars() {
+            grid_mode
+                .process_input(create_test_input(key), &key_bindings)
+                .unwrap();
+        }
+        // Labels stay put while the mode is active
+        let cells = grid_mode.get_grid_manager().unwrap().get_cells();
+        assert_eq!(cells[8].key_combination, last_label);
+
+        grid_mode.deactivate();
+        grid_mode.activate();
+        let cells = grid_mode.get_grid_manager().unwrap().get_cells();
+        assert_eq!(cells[8].key_combination, first_label);
+
+        // The overlay shows the reassigned labels
+        let ModeOverlay::Grid(_, cells) = grid_mode.overlay() else {
+            panic!("grid mode should draw a grid");
+        };
+        assert_eq!(cells[8].key_combination, first_label);
     }
 
     #[test]
@@ -0,0 +1,242 @@
+//! Hint label module
+//!
+//! This module provides functionality for:
+//! - Generating prefix-free hint labels of variable length from an alphabet
+//! - Matching typed key sequences against labels with a trie
+
+use std::collections::HashMap;
+
+use crate::{MouselessError, Result};
+
+/// Generates prefix-free hint labels from a configurable alphabet
+///
+/// Labels are as short as possible: with an alphabet of `n` keys the first
+/// `n` targets get single-key labels, and only as many labels as needed are
+/// extended with a further key. Labels are returned shortest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HintGenerator {
+    alphabet: Vec<char>,
+}
+
+impl HintGenerator {
+    /// Create a generator for an alphabet
+    ///
+    /// The alphabet needs at least two distinct keys and must not contain
+    /// whitespace, which is reserved for exiting.
+    pub fn new(alphabet: &str) -> Result<Self> {
+        let mut keys: Vec<char> = Vec::new();
+
+        for key in alphabet.chars() {
+            if key.is_whitespace() || key.is_control() {
+                return Err(invalid_alphabet(format!(
+                    "Label alphabet '{}' contains a reserved key",
+                    alphabet.escape_default()
+                )));
+            }
+            if keys.contains(&key) {
+                return Err(invalid_alphabet(format!(
+                    "Label alphabet '{}' uses key '{}' more than once",
+                    alphabet, key
+                )));
+            }
+            keys.push(key);
+        }
+
+        if keys.len() < 2 {
+            return Err(invalid_alphabet(format!(
+                "Label alphabet '{}' needs at least two keys",
+                alphabet
+            )));
+        }
+
+        Ok(Self { alphabet: keys })
+    }
+
+    /// Get the alphabet keys in order of preference
+    pub fn alphabet(&self) -> &[char] {
+        &self.alphabet
+    }
+
+    /// Generate `count` prefix-free labels, shortest first
+    pub fn generate(&self, count: usize) -> Vec<String> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        // Breadth-first expansion: every label before `offset` has been
+        // replaced by its children, so the labels from `offset` on are leaves
+        // and no leaf is a prefix of another.
+        let mut labels = vec![String::new()];
+        let mut offset = 0;
+
+        while labels.len() - offset < count || offset == 0 {
+            let prefix = labels[offset].clone();
+            offset += 1;
+
+            for &key in &self.alphabet {
+                labels.push(format!("{}{}", prefix, key));
+            }
+        }
+
+        labels.drain(offset..offset + count).collect()
+    }
+}
+
+fn invalid_alphabet(reason: String) -> MouselessError {
+    MouselessError::SystemError(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        reason,
+    ))
+}
+
+/// Result of matching a key sequence against the hint labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintMatch {
+    /// The sequence is a complete label for the target with this index
+    Complete(usize),
+    /// The sequence is the start of at least one label
+    Prefix,
+    /// No label starts with the sequence
+    DeadEnd,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: HashMap<char, usize>,
+    target: Option<usize>,
+}
+
+/// Trie of hint labels used to resolve typed key sequences
+#[derive(Debug, Clone)]
+pub struct HintTrie {
+    nodes: Vec<TrieNode>,
+}
+
+impl HintTrie {
+    /// Create an empty trie
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+        }
+    }
+
+    /// Build a trie from labels, using each label's position as its target index
+    pub fn from_labels<S: AsRef<str>>(labels: &[S]) -> Self {
+        let mut trie = Self::new();
+        for (index, label) in labels.iter().enumerate() {
+            trie.insert(label.as_ref(), index);
+        }
+        trie
+    }
+
+    /// Insert a label for a target index
+    pub fn insert(&mut self, label: &str, target: usize) {
+        let mut node = 0;
+
+        for key in label.chars() {
+            node = match self.nodes[node].children.get(&key) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(key, child);
+                    child
+                }
+            };
+        }
+
+        self.nodes[node].target = Some(target);
+    }
+
+    /// Match a key sequence against the labels
+    pub fn lookup(&self, sequence: &str) -> HintMatch {
+        let mut node = 0;
+
+        for key in sequence.chars() {
+            match self.nodes[node].children.get(&key) {
+                Some(&child) => node = child,
+                None => return HintMatch::DeadEnd,
+            }
+        }
+
+        match self.nodes[node].target {
+            Some(target) => HintMatch::Complete(target),
+            None if node != 0 => HintMatch::Prefix,
+            None => HintMatch::DeadEnd,
+        }
+    }
+
+    /// Check whether the trie contains no labels
+    pub fn is_empty(&self) -> bool {
+        self.nodes[0].children.is_empty() && self.nodes[0].target.is_none()
+    }
+}
+
+impl Default for HintTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_prefix_free(labels: &[String]) -> bool {
+        labels.iter().all(|a| {
+            labels
+                .iter()
+                .all(|b| std::ptr::eq(a, b) || !b.starts_with(a.as_str()))
+        })
+    }
+
+    #[test]
+    fn test_invalid_alphabets() {
+        assert!(HintGenerator::new("a").is_err());
+        assert!(HintGenerator::new("asa").is_err());
+        assert!(HintGenerator::new("as df").is_err());
+        assert!(HintGenerator::new("asdf").is_ok());
+    }
+
+    #[test]
+    fn test_single_key_labels_when_alphabet_is_large_enough() {
+        let generator = HintGenerator::new("asdf").unwrap();
+
+        assert_eq!(generator.generate(0), Vec::<String>::new());
+        assert_eq!(generator.generate(1), vec!["a"]);
+        assert_eq!(generator.generate(4), vec!["a", "s", "d", "f"]);
+    }
+
+    #[test]
+    fn test_labels_are_prefix_free_and_shortest_first() {
+        let generator = HintGenerator::new("asdf").unwrap();
+
+        let labels = generator.generate(5);
+        assert_eq!(labels, vec!["s", "d", "f", "aa", "as"]);
+
+        for count in [2, 7, 16, 17, 100, 500] {
+            let labels = generator.generate(count);
+            assert_eq!(labels.len(), count);
+            assert!(is_prefix_free(&labels), "{} labels overlap", count);
+            assert!(labels.windows(2).all(|w| w[0].len() <= w[1].len()));
+        }
+
+        // 500 targets fit into 5 keys with a 4 key alphabet
+        assert!(generator.generate(500).iter().all(|l| l.len() <= 5));
+    }
+
+    #[test]
+    fn test_trie_lookup() {
+        let trie = HintTrie::from_labels(&["s", "d", "aa", "as"]);
+
+        assert_eq!(trie.lookup("s"), HintMatch::Complete(0));
+        assert_eq!(trie.lookup("as"), HintMatch::Complete(3));
+        assert_eq!(trie.lookup("a"), HintMatch::Prefix);
+        assert_eq!(trie.lookup("ad"), HintMatch::DeadEnd);
+        assert_eq!(trie.lookup("q"), HintMatch::DeadEnd);
+        assert_eq!(trie.lookup(""), HintMatch::DeadEnd);
+
+        assert!(!trie.is_empty());
+        assert!(HintTrie::new().is_empty());
+    }
+}
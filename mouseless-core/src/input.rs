@@ -17,7 +17,7 @@ use tracing::{debug, info};
 
 use crate::{
     error::{InputError, InputResult},
-    models::{Action, AnimationType, InteractionMode, KeyInput, KeyModifier, Position},
+    models::{Action, AnimationType, InteractionMode, KeyInput, KeyModifier},
     traits::{InputProcessor, KeyBindings},
 };
 
@@ -366,12 +366,28 @@ impl InputHandler {
             }
         }
 
-        // Add optional middle click if present
-        if let Some(middle_click) = bindings.middle_click {
-            if !used_keys.insert(middle_click) {
-                return Err(InputError::InvalidKeyBinding {
-                    binding: format!("Duplicate key binding for middle_click: '{}'", middle_click),
-                });
+        // Add optional keys if present
+        let optional_keys = [
+            ("middle_click", bindings.middle_click),
+            ("page_up", bindings.page_up),
+            ("page_down", bindings.page_down),
+            ("precision_toggle", bindings.precision_toggle),
+            ("screen_next", bindings.screen_next),
+            ("screen_previous", bindings.screen_previous),
+            ("screen_left", bindings.screen_left),
+            ("screen_right", bindings.screen_right),
+            ("screen_up", bindings.screen_up),
+            ("screen_down", bindings.screen_down),
+            ("undo", bindings.undo),
+            ("redo", bindings.redo),
+        ];
+        for (name, key) in optional_keys {
+            if let Some(key) = key {
+                if !used_keys.insert(key) {
+                    return Err(InputError::InvalidKeyBinding {
+                        binding: format!("Duplicate key binding for {}: '{}'", name, key),
+                    });
+                }
             }
         }
 
@@ -407,19 +423,10 @@ impl InputProcessor for InputHandler {
 
         // Map key to action based on bindings
         let action = match event.key {
-            k if k == bindings.move_up => Action::MoveCursor(
-                Position::new(0, -10), // Relative movement, will be handled by mouse controller
-                AnimationType::Smooth,
-            ),
-            k if k == bindings.move_down => {
-                Action::MoveCursor(Position::new(0, 10), AnimationType::Smooth)
-            }
-            k if k == bindings.move_left => {
-                Action::MoveCursor(Position::new(-10, 0), AnimationType::Smooth)
-            }
-            k if k == bindings.move_right => {
-                Action::MoveCursor(Position::new(10, 0), AnimationType::Smooth)
-            }
+            k if k == bindings.move_up => Action::MoveRelative(0, -10, AnimationType::Smooth),
+            k if k == bindings.move_down => Action::MoveRelative(0, 10, AnimationType::Smooth),
+            k if k == bindings.move_left => Action::MoveRelative(-10, 0, AnimationType::Smooth),
+            k if k == bindings.move_right => Action::MoveRelative(10, 0, AnimationType::Smooth),
             k if k == bindings.left_click => Action::Click(crate::models::MouseButton::Left),
             k if k == bindings.right_click => Action::Click(crate::models::MouseButton::Right),
             k if k == bindings.exit_key => Action::Exit,
@@ -614,11 +621,11 @@ mod tests {
 
             let action = handler.process_key_event(key_input).await.unwrap();
             match action {
-                Action::MoveCursor(pos, AnimationType::Smooth) => {
-                    assert_eq!(pos.x, 0);
-                    assert_eq!(pos.y, -10);
+                Action::MoveRelative(dx, dy, AnimationType::Smooth) => {
+                    assert_eq!(dx, 0);
+                    assert_eq!(dy, -10);
                 }
-                _ => panic!("Expected MoveCursor action"),
+                _ => panic!("Expected MoveRelative action"),
             }
         } else {
             // Test passed - we can't create a GlobalHotKeyManager in this environment
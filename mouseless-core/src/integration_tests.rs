@@ -9,7 +9,7 @@ mod integrations_tests {
 
     use crate::{
         traits::ModeController, Action, AnimationType, InteractionMode, KeyBindings, KeyInput,
-        ModeEvent, ModeManager, MouseButton,
+        ModeEvent, ModeManager, MouseButton, MovementSpeed,
     };
     use std::time::SystemTime;
 
@@ -64,8 +64,8 @@ mod integrations_tests {
             let manager = ModeManager::new(KeyBindings::default());
 
             // Test movement speed synchronization
-            manager.set_movement_speed(2.5);
-            assert_eq!(manager.get_movement_speed(), 2.5);
+            manager.set_speed(MovementSpeed::Slow);
+            assert_eq!(manager.get_speed(), MovementSpeed::Slow);
 
             // Test hold state synchronization
             assert!(!manager.is_holding());
@@ -74,16 +74,16 @@ mod integrations_tests {
 
             // Test speed toggle synchronization
             manager.toggle_speed();
-            // The internal fast_mode should be toggled
+            assert_eq!(manager.get_speed(), MovementSpeed::Fast);
 
             // Test that basic mode input processing uses the synchronized state
             let action = manager.handle_input(create_test_input('i')).await.unwrap();
             match action {
-                Action::MoveCursor(pos, _) => {
-                    // Should be 2.5 * 3 (fast mode) * 20 (base distance) = 150
-                    assert_eq!(pos.y, -150);
+                Action::MoveRelative(_, dy, _) => {
+                    // Should be 3 (fast tier) * 20 (step size) = 60
+                    assert_eq!(dy, -60);
                 }
-                _ => panic!("Expected MoveCursor action"),
+                _ => panic!("Expected MoveRelative action"),
             }
         });
     }
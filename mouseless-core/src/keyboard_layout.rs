@@ -0,0 +1,401 @@
+//! Keyboard layout module
+//!
+//! This module provides functionality for:
+//! - Describing keyboard layouts as characters on physical key positions
+//! - Built-in presets (QWERTY, Dvorak, Colemak, AZERTY)
+//! - Loading custom layouts from a TOML description file
+//! - Looking up the physical position of a character
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{ConfigError, ConfigResult};
+
+/// Number of keys per row in the main typing block
+pub const KEYS_PER_ROW: usize = 10;
+
+/// Physical rows of the main typing block, from top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyRow {
+    Number,
+    Top,
+    Home,
+    Bottom,
+}
+
+impl KeyRow {
+    /// All rows from top to bottom
+    pub const ALL: [KeyRow; 4] = [KeyRow::Number, KeyRow::Top, KeyRow::Home, KeyRow::Bottom];
+}
+
+/// A physical key position, independent of the character printed on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PhysicalKey {
+    pub row: KeyRow,
+    /// Column counted from the left edge of the row (0-based)
+    pub column: usize,
+}
+
+impl PhysicalKey {
+    pub const fn new(row: KeyRow, column: usize) -> Self {
+        Self { row, column }
+    }
+}
+
+/// Built-in keyboard layout presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LayoutPreset {
+    #[default]
+    Qwerty,
+    Dvorak,
+    Colemak,
+    Azerty,
+    /// Layout loaded from a custom layout file
+    Custom,
+}
+
+/// Keyboard layout mapping physical key positions to characters
+///
+/// Each row holds the unshifted characters of the main typing block from left
+/// to right. The layout file format uses the same field names, for example:
+///
+/// ```toml
+/// name = "workman"
+/// number = "1234567890"
+/// top = "qdrwbjfup;"
+/// home = "ashtgyneoi"
+/// bottom = "zxmcvkl,./"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyboardLayout {
+    pub name: String,
+    pub number: String,
+    pub top: String,
+    pub home: String,
+    pub bottom: String,
+}
+
+impl KeyboardLayout {
+    /// Create a layout from its rows and validate it
+    pub fn new(
+        name: impl Into<String>,
+        number: impl Into<String>,
+        top: impl Into<String>,
+        home: impl Into<String>,
+        bottom: impl Into<String>,
+    ) -> ConfigResult<Self> {
+        let layout = Self {
+            name: name.into(),
+            number: number.into(),
+            top: top.into(),
+            home: home.into(),
+            bottom: bottom.into(),
+        };
+
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    /// US QWERTY layout
+    pub fn qwerty() -> Self {
+        Self::from_static(
+            "qwerty",
+            "1234567890",
+            "qwertyuiop",
+            "asdfghjkl;",
+            "zxcvbnm,./",
+        )
+    }
+
+    /// US Dvorak layout
+    pub fn dvorak() -> Self {
+        Self::from_static(
+            "dvorak",
+            "1234567890",
+            "',.pyfgcrl",
+            "aoeuidhtns",
+            ";qjkxbmwvz",
+        )
+    }
+
+    /// Colemak layout
+    pub fn colemak() -> Self {
+        Self::from_static(
+            "colemak",
+            "1234567890",
+            "qwfpgjluy;",
+            "arstdhneio",
+            "zxcvbkm,./",
+        )
+    }
+
+    /// French AZERTY layout
+    ///
+    /// The number row is treated as the digits AZERTY types with Shift, not
+    /// the `&é"'(-è_çà` it types unshifted, so screen keys and grid labels
+    /// derived from it are digits as on the other presets.
+    pub fn azerty() -> Self {
+        Self::from_static(
+            "azerty",
+            "1234567890",
+            "azertyuiop",
+            "qsdfghjklm",
+            "wxcvbn,;:!",
+        )
+    }
+
+    fn from_static(name: &str, number: &str, top: &str, home: &str, bottom: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            number: number.to_string(),
+            top: top.to_string(),
+            home: home.to_string(),
+            bottom: bottom.to_string(),
+        }
+    }
+
+    /// Get the layout for a built-in preset
+    ///
+    /// Returns `None` for [`LayoutPreset::Custom`], which needs a layout file.
+    pub fn preset(preset: LayoutPreset) -> Option<Self> {
+        match preset {
+            LayoutPreset::Qwerty => Some(Self::qwerty()),
+            LayoutPreset::Dvorak => Some(Self::dvorak()),
+            LayoutPreset::Colemak => Some(Self::colemak()),
+            LayoutPreset::Azerty => Some(Self::azerty()),
+            LayoutPreset::Custom => None,
+        }
+    }
+
+    /// Load a custom layout from a TOML layout file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ConfigResult<Self> {
+        let path = path.as_ref();
+
+        let content = fs::read_to_string(path).map_err(|e| ConfigError::LoadFailed {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let layout: Self = toml::from_str(&content).map_err(|e| ConfigError::LoadFailed {
+            path: path.display().to_string(),
+            reason: format!("TOML parsing error: {}", e),
+        })?;
+
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    /// Validate that every row is complete and no character is used twice
+    pub fn validate(&self) -> ConfigResult<()> {
+        let mut seen = std::collections::HashSet::new();
+
+        for row in KeyRow::ALL {
+            let chars = self.row(row);
+            if chars.len() != KEYS_PER_ROW {
+                return Err(ConfigError::InvalidValue {
+                    field: format!("keyboard_layout.{}", Self::row_field(row)),
+                    value: self.row_str(row).to_string(),
+                });
+            }
+
+            for c in chars {
+                if !seen.insert(c) {
+                    return Err(ConfigError::ValidationFailed {
+                        reason: format!(
+                            "Keyboard layout '{}' uses character '{}' more than once",
+                            self.name, c
+                        ),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn row_field(row: KeyRow) -> &'static str {
+        match row {
+            KeyRow::Number => "number",
+            KeyRow::Top => "top",
+            KeyRow::Home => "home",
+            KeyRow::Bottom => "bottom",
+        }
+    }
+
+    fn row_str(&self, row: KeyRow) -> &str {
+        match row {
+            KeyRow::Number => &self.number,
+            KeyRow::Top => &self.top,
+            KeyRow::Home => &self.home,
+            KeyRow::Bottom => &self.bottom,
+        }
+    }
+
+    /// Get the characters of a row from left to right
+    pub fn row(&self, row: KeyRow) -> Vec<char> {
+        self.row_str(row).chars().collect()
+    }
+
+    /// Get the character on a physical key
+    pub fn key_at(&self, key: PhysicalKey) -> Option<char> {
+        self.row_str(key.row).chars().nth(key.column)
+    }
+
+    /// Find the physical key that produces a character (case-insensitive)
+    pub fn locate(&self, c: char) -> Option<PhysicalKey> {
+        let c = c.to_lowercase().next().unwrap_or(c);
+
+        KeyRow::ALL.iter().find_map(|&row| {
+            self.row_str(row)
+                .chars()
+                .position(|k| k == c)
+                .map(|column| PhysicalKey::new(row, column))
+        })
+    }
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        Self::qwerty()
+    }
+}
+
+/// Keyboard layout configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KeyboardConfig {
+    /// Layout preset to use
+    pub layout: LayoutPreset,
+    /// Path to a custom layout file, used when `layout` is `Custom`
+    pub custom_layout_file: Option<PathBuf>,
+}
+
+impl KeyboardConfig {
+    /// Resolve the configured layout, loading the custom layout file if needed
+    pub fn resolve_layout(&self) -> ConfigResult<KeyboardLayout> {
+        if let Some(layout) = KeyboardLayout::preset(self.layout) {
+            return Ok(layout);
+        }
+
+        let path = self
+            .custom_layout_file
+            .as_ref()
+            .ok_or_else(|| ConfigError::MissingField {
+                field: "keyboard.custom_layout_file".to_string(),
+            })?;
+
+        KeyboardLayout::from_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::KeyBindings;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_presets_are_valid() {
+        for preset in [
+            LayoutPreset::Qwerty,
+            LayoutPreset::Dvorak,
+            LayoutPreset::Colemak,
+            LayoutPreset::Azerty,
+        ] {
+            let layout = KeyboardLayout::preset(preset).unwrap();
+            assert!(layout.validate().is_ok(), "{} should be valid", layout.name);
+        }
+
+        assert!(KeyboardLayout::preset(LayoutPreset::Custom).is_none());
+    }
+
+    #[test]
+    fn test_key_at_and_locate() {
+        let qwerty = KeyboardLayout::qwerty();
+        let dvorak = KeyboardLayout::dvorak();
+
+        let key = PhysicalKey::new(KeyRow::Top, 7);
+        assert_eq!(qwerty.key_at(key), Some('i'));
+        assert_eq!(dvorak.key_at(key), Some('c'));
+
+        assert_eq!(dvorak.locate('c'), Some(key));
+        assert_eq!(qwerty.locate('A'), Some(PhysicalKey::new(KeyRow::Home, 0)));
+        assert_eq!(qwerty.locate('§'), None);
+        assert_eq!(qwerty.key_at(PhysicalKey::new(KeyRow::Home, 42)), None);
+    }
+
+    #[test]
+    fn test_azerty_number_row_is_digits() {
+        let azerty = KeyboardLayout::azerty();
+        assert_eq!(
+            azerty.row(KeyRow::Number),
+            "1234567890".chars().collect::<Vec<_>>()
+        );
+
+        let bindings = KeyBindings::for_layout(&azerty).unwrap();
+        assert_eq!(bindings.screen_1, '1');
+        assert_eq!(bindings.screen_previous, Some('9'));
+        assert_eq!(bindings.screen_next, Some('0'));
+        // The letters follow the layout
+        assert_eq!(bindings.area_mode, 'q');
+    }
+
+    #[test]
+    fn test_invalid_layouts() {
+        // Short row
+        assert!(
+            KeyboardLayout::new("short", "1234567890", "qwerty", "asdfghjkl;", "zxcvbnm,./")
+                .is_err()
+        );
+
+        // Duplicate character
+        assert!(KeyboardLayout::new(
+            "duplicate",
+            "1234567890",
+            "qwertyuiop",
+            "asdfghjklq",
+            "zxcvbnm,./"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_custom_layout_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let layout_path = temp_dir.path().join("workman.toml");
+
+        fs::write(
+            &layout_path,
+            r#"
+name = "workman"
+number = "1234567890"
+top = "qdrwbjfup;"
+home = "ashtgyneoi"
+bottom = "zxmcvkl,./"
+"#,
+        )
+        .unwrap();
+
+        let config = KeyboardConfig {
+            layout: LayoutPreset::Custom,
+            custom_layout_file: Some(layout_path),
+        };
+
+        let layout = config.resolve_layout().unwrap();
+        assert_eq!(layout.name, "workman");
+        assert_eq!(layout.key_at(PhysicalKey::new(KeyRow::Home, 2)), Some('h'));
+    }
+
+    #[test]
+    fn test_custom_layout_requires_file() {
+        let config = KeyboardConfig {
+            layout: LayoutPreset::Custom,
+            custom_layout_file: None,
+        };
+
+        assert!(matches!(
+            config.resolve_layout(),
+            Err(ConfigError::MissingField { .. })
+        ));
+    }
+}
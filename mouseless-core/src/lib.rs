@@ -5,15 +5,26 @@
 //! of the mouseless application.
 
 pub mod animation;
+pub mod animation_scheduler;
 pub mod area_mode;
 pub mod basic_mode;
 pub mod config;
+pub mod control;
+#[cfg(feature = "dbus")]
+pub mod dbus;
 pub mod error;
+pub mod executor;
+pub mod focus;
 pub mod grid;
 pub mod grid_mode;
+pub mod hints;
 pub mod input;
+pub mod keyboard_layout;
 pub mod logging;
+pub mod macros;
+pub mod marks;
 pub mod mode;
+pub mod mode_registry;
 pub mod models;
 pub mod mouse;
 pub mod mouse_service;
@@ -21,27 +32,54 @@ pub mod mouse_service;
 //TODO: Implement PredictionEngine with screen content analysis
 //TODO: Add machine learning model integration (candle-core or tch)
 //TODO: Create usage pattern tracking and learning system
+pub mod recording_mouse;
+pub mod renderer;
 pub mod screen;
+pub mod screen_events;
+pub mod screen_provider;
+pub mod scripting;
+pub mod scroll;
+pub mod session_log;
 pub mod traits;
+pub mod undo;
 
 #[cfg(test)]
 pub mod integration_tests;
 
 // Re-export commonly used types
 pub use animation::*;
+pub use animation_scheduler::*;
 pub use area_mode::*;
 pub use basic_mode::*;
 pub use config::*;
+pub use control::*;
+#[cfg(feature = "dbus")]
+pub use dbus::*;
 pub use error::{MouselessError, Result};
+pub use executor::*;
+pub use focus::*;
 pub use grid::*;
 pub use grid_mode::*;
+pub use hints::*;
 pub use input::*;
+pub use keyboard_layout::*;
+pub use macros::*;
+pub use marks::*;
 pub use mode::*;
+pub use mode_registry::*;
 pub use models::*;
 pub use mouse::*;
 pub use mouse_service::*;
+pub use recording_mouse::*;
+pub use renderer::*;
 pub use screen::*;
+pub use screen_events::*;
+pub use screen_provider::*;
+pub use scripting::*;
+pub use scroll::*;
+pub use session_log::*;
 pub use traits::*;
+pub use undo::*;
 
 /// Version information
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -110,6 +148,7 @@ mod tests {
             width: 1920,
             height: 1080,
             is_primary: true,
+            scale_factor: 1.0,
         };
 
         let center = bounds.center();
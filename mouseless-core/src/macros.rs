@@ -0,0 +1,693 @@
+//! Macros module
+//!
+//! This module provides functionality for:
+//! - Recording the actions coming out of the modes, with their relative timing
+//! - Storing recordings in registers, optionally relative to the screen or cursor
+//! - Reading the record and play keys, a register letter and a repeat count
+//! - Saving macros to a human-editable `macros.toml` next to the configuration
+//!
+//! Like scripts, macros do not drive the mouse themselves. The host records
+//! the actions it runs and plays a macro back by running its steps with the
+//! recorded delays.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tracing::info;
+
+use crate::{
+    error::{ConfigError, ConfigResult},
+    models::{Action, InteractionMode, Position, ScreenBounds},
+};
+
+/// Most times a macro may be repeated by one play
+pub const MAX_MACRO_REPEAT: u32 = 100;
+
+/// Most steps one macro may hold
+const MAX_MACRO_STEPS: usize = 10_000;
+
+/// Name of the macros file, next to the configuration file
+const MACROS_FILE_NAME: &str = "macros.toml";
+
+/// How recorded cursor positions are stored and played back
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MacroPositions {
+    /// Positions are replayed exactly as recorded
+    #[default]
+    Absolute,
+    /// Positions are offsets from the screen they were on, replayed on the cursor's screen
+    Screen,
+    /// Positions are offsets from the cursor when recording started
+    Cursor,
+}
+
+/// Macro configuration
+///
+/// ```toml
+/// [macros]
+/// record_key = "q"
+/// play_key = "@"
+/// positions = "screen"
+/// modes = ["basic"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MacroConfig {
+    /// Key that starts recording into the register typed next, and stops it
+    pub record_key: Option<char>,
+    /// Key that plays the register typed next; digits in between repeat it
+    pub play_key: Option<char>,
+    /// How new recordings store cursor positions
+    pub positions: MacroPositions,
+    /// Modes the record and play keys apply in, all modes when empty
+    pub modes: Vec<String>,
+    /// Macros file, `macros.toml` next to the configuration file when unset
+    pub file: Option<PathBuf>,
+}
+
+impl Default for MacroConfig {
+    fn default() -> Self {
+        Self {
+            // Recording is opt-in, its key is checked before every mode's own keys
+            record_key: None,
+            play_key: Some('@'),
+            positions: MacroPositions::Absolute,
+            modes: vec!["basic".to_string()],
+            file: None,
+        }
+    }
+}
+
+impl MacroConfig {
+    /// Validate the keys and modes
+    pub fn validate(&self) -> ConfigResult<()> {
+        for (field, key) in [("record_key", self.record_key), ("play_key", self.play_key)] {
+            if let Some(key) = key {
+                if key.is_ascii_digit() || key.is_control() {
+                    return Err(ConfigError::InvalidValue {
+                        field: format!("macros.{}", field),
+                        value: format!("{:?}", key),
+                    });
+                }
+            }
+        }
+        if self.record_key.is_some() && self.record_key == self.play_key {
+            return Err(ConfigError::ValidationFailed {
+                reason: "macros.record_key and macros.play_key must differ".to_string(),
+            });
+        }
+
+        if let Some(mode) = self
+            .modes
+            .iter()
+            .find(|mode| !InteractionMode::is_valid_name(mode))
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "macros.modes".to_string(),
+                value: format!("{:?}", mode),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check if the record and play keys apply in a mode
+    pub fn applies_to(&self, mode: &InteractionMode) -> bool {
+        self.modes.is_empty()
+            || self
+                .modes
+                .iter()
+                .any(|name| InteractionMode::from_name(name).as_ref() == Some(mode))
+    }
+
+    /// Path of the macros file for a configuration file
+    pub fn file_for(&self, config_path: &Path) -> PathBuf {
+        self.file
+            .clone()
+            .unwrap_or_else(|| config_path.with_file_name(MACROS_FILE_NAME))
+    }
+}
+
+/// Registers are single letters, like in vim
+pub fn is_register(key: char) -> bool {
+    key.is_ascii_lowercase()
+}
+
+/// One recorded action and the time since the step before it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MacroStep {
+    /// Milliseconds to wait before running the action
+    #[serde(default)]
+    pub delay_ms: u64,
+    /// Action to run
+    pub action: Action,
+}
+
+/// A recorded sequence of actions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Macro {
+    /// How the cursor positions of the steps are stored
+    #[serde(default)]
+    pub positions: MacroPositions,
+    /// Steps in the order they run
+    #[serde(default)]
+    pub steps: Vec<MacroStep>,
+}
+
+impl Macro {
+    /// Resolve the steps for a playback starting at a cursor position
+    ///
+    /// Returns each action with the delay to wait before it, with recorded
+    /// offsets turned back into absolute positions.
+    pub fn resolve(&self, cursor: Position, screens: &[ScreenBounds]) -> Vec<(Duration, Action)> {
+        let screen = screens.iter().find(|screen| screen.contains(cursor));
+        self.steps
+            .iter()
+            .map(|step| {
+                let action = match &step.action {
+                    Action::MoveCursor(position, animation) => {
+                        let position = match (self.positions, screen) {
+                            (MacroPositions::Absolute, _) => *position,
+                            (MacroPositions::Screen, Some(screen)) => Position::with_screen(
+                                screen.x + position.x,
+                                screen.y + position.y,
+                                screen.id,
+                            ),
+                            (MacroPositions::Screen, None) => *position,
+                            (MacroPositions::Cursor, _) => {
+                                Position::new(cursor.x + position.x, cursor.y + position.y)
+                            }
+                        };
+                        Action::MoveCursor(position, *animation)
+                    }
+                    action => action.clone(),
+                };
+                (Duration::from_millis(step.delay_ms), action)
+            })
+            .collect()
+    }
+
+    /// Total time the recorded delays add up to
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.steps.iter().map(|step| step.delay_ms).sum())
+    }
+}
+
+/// Records actions into a macro
+#[derive(Debug, Clone)]
+pub struct MacroRecorder {
+    register: char,
+    origin: Position,
+    screens: Vec<ScreenBounds>,
+    last_step: Option<Instant>,
+    recording: Macro,
+}
+
+impl MacroRecorder {
+    /// Start recording into a register, with the cursor and screens at the start
+    pub fn start(
+        register: char,
+        positions: MacroPositions,
+        cursor: Position,
+        screens: Vec<ScreenBounds>,
+    ) -> Self {
+        Self {
+            register,
+            origin: cursor,
+            screens,
+            last_step: None,
+            recording: Macro {
+                positions,
+                steps: Vec::new(),
+            },
+        }
+    }
+
+    /// Register the macro is recorded into
+    pub fn register(&self) -> char {
+        self.register
+    }
+
+    /// Record an action that ran now
+    pub fn record(&mut self, action: &Action) {
+        self.record_at(action, Instant::now());
+    }
+
+    /// Record an action that ran at an instant
+    ///
+    /// Actions that do nothing or control macros themselves are skipped, so
+    /// playing a macro never records or plays another one.
+    pub fn record_at(&mut self, action: &Action, at: Instant) {
+        if matches!(
+            action,
+            Action::NoAction
+                | Action::RecordMacro(_)
+                | Action::StopMacroRecording
+                | Action::PlayMacro(..)
+        ) || self.recording.steps.len() >= MAX_MACRO_STEPS
+        {
+            return;
+        }
+
+        // The first step runs at once; the wait before it is not part of the macro
+        let delay_ms = self
+            .last_step
+            .map(|last| at.saturating_duration_since(last).as_millis() as u64)
+            .unwrap_or(0);
+        self.last_step = Some(at);
+
+        let action = match action {
+            Action::MoveCursor(position, animation) => {
+                Action::MoveCursor(self.store_position(*position), *animation)
+            }
+            action => action.clone(),
+        };
+        self.recording.steps.push(MacroStep { delay_ms, action });
+    }
+
+    /// Stop recording, returning the register and the macro
+    pub fn finish(self) -> (char, Macro) {
+        (self.register, self.recording)
+    }
+
+    /// Turn a position into what the macro stores for it
+    fn store_position(&self, position: Position) -> Position {
+        match self.recording.positions {
+            MacroPositions::Absolute => position,
+            MacroPositions::Screen => self
+                .screens
+                .iter()
+                .find(|screen| screen.contains(position))
+                .map(|screen| Position::new(position.x - screen.x, position.y - screen.y))
+                .unwrap_or(position),
+            MacroPositions::Cursor => {
+                Position::new(position.x - self.origin.x, position.y - self.origin.y)
+            }
+        }
+    }
+}
+
+/// What the macro keys are waiting for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum PendingMacroKey {
+    #[default]
+    None,
+    /// The record key was pressed, the register follows
+    Record,
+    /// The play key was pressed, a repeat count and the register follow
+    Play { count: u32 },
+}
+
+/// Reads the record and play key sequences from key presses
+#[derive(Debug, Clone, Default)]
+pub struct MacroKeys {
+    config: MacroConfig,
+    pending: PendingMacroKey,
+    recording: bool,
+}
+
+impl MacroKeys {
+    /// Create the key reader for a configuration
+    pub fn new(config: MacroConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Replace the configuration, dropping a half-typed sequence
+    pub fn set_config(&mut self, config: MacroConfig) {
+        self.config = config;
+        self.pending = PendingMacroKey::None;
+    }
+
+    /// Check if a recording was started and not stopped yet
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Drop a half-typed sequence
+    pub fn cancel(&mut self) {
+        self.pending = PendingMacroKey::None;
+    }
+
+    /// Process a key press in a mode
+    ///
+    /// Returns `None` when the key is not part of a macro key sequence and
+    /// should go to the mode.
+    pub fn process_key(&mut self, key: char, mode: &InteractionMode) -> Option<Action> {
+        match self.pending {
+            PendingMacroKey::Record => {
+                self.pending = PendingMacroKey::None;
+                if !is_register(key) {
+                    return Some(Action::NoAction);
+                }
+                self.recording = true;
+                Some(Action::RecordMacro(key))
+            }
+            PendingMacroKey::Play { count } => {
+                if let Some(digit) = key.to_digit(10) {
+                    let count = (count * 10 + digit).min(MAX_MACRO_REPEAT);
+                    self.pending = PendingMacroKey::Play { count };
+                    return Some(Action::NoAction);
+                }
+                self.pending = PendingMacroKey::None;
+                if !is_register(key) {
+                    return Some(Action::NoAction);
+                }
+                Some(Action::PlayMacro(key, count.max(1)))
+            }
+            PendingMacroKey::None => {
+                if !self.config.applies_to(mode) {
+                    return None;
+                }
+                if Some(key) == self.config.record_key {
+                    if self.recording {
+                        self.recording = false;
+                        return Some(Action::StopMacroRecording);
+                    }
+                    self.pending = PendingMacroKey::Record;
+                    return Some(Action::NoAction);
+                }
+                if Some(key) == self.config.play_key {
+                    self.pending = PendingMacroKey::Play { count: 0 };
+                    return Some(Action::NoAction);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Macros by register, stored in a TOML file
+///
+/// ```toml
+/// [a]
+/// positions = "screen"
+///
+/// [[a.steps]]
+/// delay_ms = 0
+/// action = { MoveCursor = [{ x = 120, y = 40 }, "Smooth"] }
+///
+/// [[a.steps]]
+/// delay_ms = 250
+/// action = { Click = "Left" }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MacroStore {
+    path: PathBuf,
+    macros: BTreeMap<char, Macro>,
+}
+
+impl MacroStore {
+    /// Create an empty store saved to a path
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            macros: BTreeMap::new(),
+        }
+    }
+
+    /// Load the macros from a file; a missing file holds no macros
+    pub fn load<P: AsRef<Path>>(path: P) -> ConfigResult<Self> {
+        let mut store = Self::new(path);
+        if !store.path.exists() {
+            return Ok(store);
+        }
+
+        let content = fs::read_to_string(&store.path).map_err(|e| ConfigError::LoadFailed {
+            path: store.path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+        // TOML keys are strings, so registers are read as one-letter strings
+        let macros: BTreeMap<String, Macro> =
+            toml::from_str(&content).map_err(|e| ConfigError::LoadFailed {
+                path: store.path.display().to_string(),
+                reason: format!("TOML parsing error: {}", e),
+            })?;
+        for (register, recording) in macros {
+            let mut chars = register.chars();
+            match (chars.next(), chars.next()) {
+                (Some(key), None) => store.set(key, recording)?,
+                _ => {
+                    return Err(ConfigError::InvalidValue {
+                        field: "macros register".to_string(),
+                        value: format!("{:?}", register),
+                    })
+                }
+            }
+        }
+
+        info!("Loaded {} macros from: {:?}", store.len(), store.path);
+        Ok(store)
+    }
+
+    /// Save the macros to their file
+    pub fn save(&self) -> ConfigResult<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| ConfigError::SaveFailed {
+                path: parent.display().to_string(),
+                reason: e.to_string(),
+            })?;
+        }
+
+        let macros: BTreeMap<String, &Macro> = self
+            .macros
+            .iter()
+            .map(|(register, recording)| (register.to_string(), recording))
+            .collect();
+        let content = toml::to_string_pretty(&macros).map_err(|e| ConfigError::SaveFailed {
+            path: self.path.display().to_string(),
+            reason: format!("TOML serialization error: {}", e),
+        })?;
+        fs::write(&self.path, content).map_err(|e| ConfigError::SaveFailed {
+            path: self.path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+        info!("Saved macros to: {:?}", self.path);
+        Ok(())
+    }
+
+    /// Path the macros are saved to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get the macro in a register
+    pub fn get(&self, register: char) -> Option<&Macro> {
+        self.macros.get(&register)
+    }
+
+    /// Store a macro in a register, replacing the one there
+    pub fn set(&mut self, register: char, recording: Macro) -> ConfigResult<()> {
+        if !is_register(register) {
+            return Err(ConfigError::InvalidValue {
+                field: "macros register".to_string(),
+                value: format!("{:?}", register),
+            });
+        }
+        self.macros.insert(register, recording);
+        Ok(())
+    }
+
+    /// Registers holding a macro, in order
+    pub fn registers(&self) -> Vec<char> {
+        self.macros.keys().copied().collect()
+    }
+
+    /// Get the number of macros
+    pub fn len(&self) -> usize {
+        self.macros.len()
+    }
+
+    /// Check if there are no macros
+    pub fn is_empty(&self) -> bool {
+        self.macros.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AnimationType, MouseButton};
+
+    fn screens() -> Vec<ScreenBounds> {
+        vec![
+            ScreenBounds {
+                id: 0,
+                x: 0,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: true,
+                scale_factor: 1.0,
+            },
+            ScreenBounds {
+                id: 1,
+                x: 1920,
+                y: 0,
+                width: 1280,
+                height: 1024,
+                is_primary: false,
+                scale_factor: 1.0,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_record_and_resolve() {
+        let start = Instant::now();
+        let mut recorder = MacroRecorder::start(
+            'a',
+            MacroPositions::Screen,
+            Position::new(10, 10),
+            screens(),
+        );
+        let target = Position::new(2020, 50);
+        recorder.record_at(&Action::MoveCursor(target, AnimationType::Linear), start);
+        recorder.record_at(&Action::NoAction, start + Duration::from_millis(100));
+        recorder.record_at(
+            &Action::Click(MouseButton::Left),
+            start + Duration::from_millis(300),
+        );
+
+        let (register, recording) = recorder.finish();
+        assert_eq!(register, 'a');
+        assert_eq!(recording.steps.len(), 2);
+        assert_eq!(recording.steps[0].delay_ms, 0);
+        assert_eq!(recording.steps[1].delay_ms, 300);
+        assert_eq!(recording.duration(), Duration::from_millis(300));
+        assert_eq!(
+            recording.steps[0].action,
+            Action::MoveCursor(Position::new(100, 50), AnimationType::Linear)
+        );
+
+        // Played back from the first screen, the move lands on the first screen
+        let steps = recording.resolve(Position::new(500, 500), &screens());
+        assert_eq!(
+            steps[0],
+            (
+                Duration::ZERO,
+                Action::MoveCursor(Position::with_screen(100, 50, 0), AnimationType::Linear)
+            )
+        );
+        assert_eq!(
+            steps[1],
+            (Duration::from_millis(300), Action::Click(MouseButton::Left))
+        );
+    }
+
+    #[test]
+    fn test_cursor_relative_positions() {
+        let mut recorder = MacroRecorder::start(
+            'b',
+            MacroPositions::Cursor,
+            Position::new(100, 100),
+            Vec::new(),
+        );
+        recorder.record(&Action::MoveCursor(
+            Position::new(150, 80),
+            AnimationType::Linear,
+        ));
+        let (_, recording) = recorder.finish();
+
+        let steps = recording.resolve(Position::new(400, 400), &[]);
+        assert_eq!(
+            steps[0].1,
+            Action::MoveCursor(Position::new(450, 380), AnimationType::Linear)
+        );
+    }
+
+    #[test]
+    fn test_macro_keys() {
+        let mut keys = MacroKeys::new(MacroConfig {
+            record_key: Some('q'),
+            ..MacroConfig::default()
+        });
+        let basic = InteractionMode::Basic;
+
+        assert_eq!(keys.process_key('i', &basic), None);
+        assert_eq!(keys.process_key('q', &basic), Some(Action::NoAction));
+        assert_eq!(
+            keys.process_key('a', &basic),
+            Some(Action::RecordMacro('a'))
+        );
+        assert!(keys.is_recording());
+        assert_eq!(
+            keys.process_key('q', &basic),
+            Some(Action::StopMacroRecording)
+        );
+        assert!(!keys.is_recording());
+
+        assert_eq!(keys.process_key('@', &basic), Some(Action::NoAction));
+        assert_eq!(
+            keys.process_key('a', &basic),
+            Some(Action::PlayMacro('a', 1))
+        );
+
+        // A count between the play key and the register repeats the macro
+        for key in ['@', '1', '2'] {
+            assert_eq!(keys.process_key(key, &basic), Some(Action::NoAction));
+        }
+        assert_eq!(
+            keys.process_key('b', &basic),
+            Some(Action::PlayMacro('b', 12))
+        );
+
+        // The keys only apply in the configured modes
+        assert_eq!(keys.process_key('q', &InteractionMode::Grid), None);
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = MacroConfig::default();
+        let path = config.file_for(&dir.path().join("config.toml"));
+        assert_eq!(path, dir.path().join("macros.toml"));
+
+        let mut store = MacroStore::load(&path).unwrap();
+        assert!(store.is_empty());
+
+        let recording = Macro {
+            positions: MacroPositions::Screen,
+            steps: vec![
+                MacroStep {
+                    delay_ms: 0,
+                    action: Action::MoveCursor(Position::new(120, 40), AnimationType::Smooth),
+                },
+                MacroStep {
+                    delay_ms: 250,
+                    action: Action::Click(MouseButton::Left),
+                },
+            ],
+        };
+        store.set('a', recording.clone()).unwrap();
+        assert!(store.set('A', Macro::default()).is_err());
+        store.save().unwrap();
+
+        let loaded = MacroStore::load(&path).unwrap();
+        assert_eq!(loaded.registers(), vec!['a']);
+        assert_eq!(loaded.get('a'), Some(&recording));
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(MacroConfig::default().validate().is_ok());
+
+        let config = MacroConfig {
+            record_key: Some('q'),
+            play_key: Some('q'),
+            ..MacroConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = MacroConfig {
+            record_key: Some('1'),
+            ..MacroConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
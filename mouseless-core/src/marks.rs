@@ -0,0 +1,164 @@
+//! Marks module
+//!
+//! This module provides functionality for:
+//! - Named cursor positions to jump back to
+//! - Loading marks from the `[marks]` configuration table
+//! - Keeping marks on their screens when the screens change
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::{
+    error::{ConfigError, ConfigResult},
+    models::Position,
+    screen_events::ScreenChangeEvent,
+};
+
+/// Named cursor positions, listed in name order
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Marks {
+    marks: BTreeMap<String, Position>,
+}
+
+impl Marks {
+    /// Create an empty set of marks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a mark, replacing any mark with the same name
+    pub fn set(&mut self, name: &str, position: Position) -> ConfigResult<()> {
+        Self::validate_name(name)?;
+        self.marks.insert(name.to_string(), position);
+        Ok(())
+    }
+
+    /// Get the position of a mark
+    pub fn get(&self, name: &str) -> Option<Position> {
+        self.marks.get(name).copied()
+    }
+
+    /// Remove a mark, returning its position
+    pub fn remove(&mut self, name: &str) -> Option<Position> {
+        self.marks.remove(name)
+    }
+
+    /// Iterate over the marks in name order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Position)> {
+        self.marks
+            .iter()
+            .map(|(name, position)| (name.as_str(), *position))
+    }
+
+    /// Get the number of marks
+    pub fn len(&self) -> usize {
+        self.marks.len()
+    }
+
+    /// Check if there are no marks
+    pub fn is_empty(&self) -> bool {
+        self.marks.is_empty()
+    }
+
+    /// Move every mark along with its screen after the screens changed
+    pub fn apply_screen_change(&mut self, event: &ScreenChangeEvent) {
+        for position in self.marks.values_mut() {
+            *position = event.remap_position(*position);
+        }
+    }
+
+    /// Validate the mark names
+    pub fn validate(&self) -> ConfigResult<()> {
+        self.marks
+            .keys()
+            .try_for_each(|name| Self::validate_name(name))
+    }
+
+    /// Mark names are used on command lines, so they must be non-empty words
+    fn validate_name(name: &str) -> ConfigResult<()> {
+        if name.is_empty() || name.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(ConfigError::InvalidValue {
+                field: "marks".to_string(),
+                value: format!("{:?}", name),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screen_provider::ScreenInfo;
+
+    fn screen(id: u32, name: &str, x: i32, width: u32, height: u32) -> ScreenInfo {
+        ScreenInfo {
+            id,
+            name: name.to_string(),
+            x,
+            y: 0,
+            width,
+            height,
+            is_primary: id == 0,
+            scale_factor: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_set_and_get_marks() {
+        let mut marks = Marks::new();
+        marks.set("editor", Position::new(100, 200)).unwrap();
+        marks.set("browser", Position::new(2000, 300)).unwrap();
+        marks.set("editor", Position::new(120, 220)).unwrap();
+
+        assert_eq!(marks.len(), 2);
+        assert_eq!(marks.get("editor"), Some(Position::new(120, 220)));
+        assert_eq!(marks.get("terminal"), None);
+
+        let names: Vec<&str> = marks.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["browser", "editor"]);
+
+        assert_eq!(marks.remove("browser"), Some(Position::new(2000, 300)));
+        assert_eq!(marks.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_mark_names() {
+        let mut marks = Marks::new();
+        assert!(marks.set("", Position::new(0, 0)).is_err());
+        assert!(marks.set("two words", Position::new(0, 0)).is_err());
+        assert!(marks.is_empty());
+    }
+
+    #[test]
+    fn test_marks_follow_screen_changes() {
+        let laptop = screen(0, "eDP-1", 0, 1920, 1080);
+        let external = screen(1, "HDMI-1", 1920, 2560, 1440);
+
+        let mut marks = Marks::new();
+        marks
+            .set("editor", Position::with_screen(480, 270, 0))
+            .unwrap();
+        marks
+            .set("browser", Position::with_screen(1920 + 1280, 720, 1))
+            .unwrap();
+
+        // Unplugging the external screen moves its marks onto the laptop screen
+        let event = ScreenChangeEvent::between(
+            vec![laptop.clone(), external],
+            vec![screen(0, "eDP-1", 0, 3840, 2160)],
+        )
+        .unwrap();
+        marks.apply_screen_change(&event);
+
+        assert_eq!(
+            marks.get("editor"),
+            Some(Position::with_screen(960, 540, 0))
+        );
+        assert_eq!(
+            marks.get("browser"),
+            Some(Position::with_screen(1920, 1080, 0))
+        );
+    }
+}
@@ -1,23 +1,31 @@
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 use tokio::sync::broadcast;
 use tracing::{info, warn};
 
 use crate::{
+    animation_scheduler::{SharedClock, SystemClock},
     area_mode::AreaMode,
     basic_mode::BasicMode,
-    error::Result,
+    config::MovementConfig,
+    error::{MouselessError, Result},
     grid_mode::GridMode,
-    models::{Action, InteractionMode, KeyInput},
-    traits::{KeyBindings, ModeController},
+    macros::{MacroConfig, MacroKeys},
+    mode_registry::{ModeRegistry, SharedMode},
+    models::{Action, AnimationType, InteractionMode, KeyInput, MovementSpeed},
+    screen::ScreenManager,
+    screen_events::ScreenChangeEvent,
+    scripting::ScriptBinding,
+    traits::{KeyBindings, Mode, ModeController, ModeOverlay},
 };
 
 /// Maximum number of modes to keep in history
 const MAX_MODE_HISTORY: usize = 10;
 
 /// Event types for mode changes
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ModeEvent {
     ModeActivated(InteractionMode),
     ModeDeactivated(InteractionMode),
@@ -32,11 +40,14 @@ pub enum ModeEvent {
 }
 
 /// Mode manager that handles state transitions and mode switching
+///
+/// Clones share the same state and event channel.
+#[derive(Clone)]
 pub struct ModeManager {
     /// Current active mode (None means inactive)
     current_mode: Arc<Mutex<Option<InteractionMode>>>,
 
-    /// History of previous modes for undo functionality
+    /// History of recently used modes
     mode_history: Arc<Mutex<VecDeque<InteractionMode>>>,
 
     /// Event broadcaster for mode change notifications
@@ -45,15 +56,15 @@ pub struct ModeManager {
     /// Key bindings configuration
     key_bindings: Arc<Mutex<KeyBindings>>,
 
-    /// Current movement speed multiplier
-    movement_speed: Arc<Mutex<f32>>,
-
-    /// Whether speed toggle is in fast mode
-    fast_mode: Arc<Mutex<bool>>,
-
     /// Hold state for click-and-hold functionality
     hold_state: Arc<Mutex<bool>>,
 
+    /// Keys that run scripts, checked before the current mode sees a key
+    script_bindings: Arc<Mutex<Vec<ScriptBinding>>>,
+
+    /// Record and play key sequences, checked before script bindings
+    macro_keys: Arc<Mutex<MacroKeys>>,
+
     /// Basic mode implementation
     basic_mode: Arc<Mutex<BasicMode>>,
 
@@ -62,6 +73,12 @@ pub struct ModeManager {
 
     /// Area mode implementation
     area_mode: Arc<Mutex<AreaMode>>,
+
+    /// Every mode by name, including the built-in ones above
+    registry: Arc<Mutex<ModeRegistry>>,
+
+    /// Clock the modes time key sequences with
+    clock: Arc<Mutex<SharedClock>>,
 }
 
 impl ModeManager {
@@ -69,20 +86,122 @@ impl ModeManager {
     pub fn new(key_bindings: KeyBindings) -> Self {
         let (event_sender, _) = broadcast::channel(100);
 
+        let basic_mode = Arc::new(Mutex::new(BasicMode::new()));
+        let grid_mode = Arc::new(Mutex::new(GridMode::new()));
+        let area_mode = Arc::new(Mutex::new(AreaMode::new()));
+
+        // The built-in modes register like any other mode; the typed handles
+        // above share their state for configuration
+        let mut registry = ModeRegistry::new();
+        let built_in: [SharedMode; 3] = [basic_mode.clone(), grid_mode.clone(), area_mode.clone()];
+        for mode in built_in {
+            registry
+                .register_shared(mode)
+                .expect("built-in mode names are unique");
+        }
+
         Self {
             current_mode: Arc::new(Mutex::new(None)),
             mode_history: Arc::new(Mutex::new(VecDeque::with_capacity(MAX_MODE_HISTORY))),
             event_sender,
             key_bindings: Arc::new(Mutex::new(key_bindings)),
-            movement_speed: Arc::new(Mutex::new(1.0)),
-            fast_mode: Arc::new(Mutex::new(false)),
             hold_state: Arc::new(Mutex::new(false)),
-            basic_mode: Arc::new(Mutex::new(BasicMode::new())),
-            grid_mode: Arc::new(Mutex::new(GridMode::new())),
-            area_mode: Arc::new(Mutex::new(AreaMode::new())),
+            script_bindings: Arc::new(Mutex::new(Vec::new())),
+            macro_keys: Arc::new(Mutex::new(MacroKeys::new(MacroConfig::default()))),
+            basic_mode,
+            grid_mode,
+            area_mode,
+            registry: Arc::new(Mutex::new(registry)),
+            clock: Arc::new(Mutex::new(Arc::new(SystemClock::new()) as SharedClock)),
+        }
+    }
+
+    /// Register a custom mode, activated with `InteractionMode::Custom(name)`
+    pub fn register_mode<M: Mode + 'static>(&self, mut mode: M) -> Result<()> {
+        if let Ok(clock) = self.clock.lock() {
+            mode.set_clock(clock.clone());
+        }
+        self.registry
+            .lock()
+            .map_err(|_| MouselessError::ModeError("Mode registry lock poisoned".to_string()))?
+            .register(mode)
+            .map(|_| ())
+    }
+
+    /// Set the clock every registered mode times key sequences with
+    pub fn set_clock(&self, clock: SharedClock) {
+        let modes = self
+            .registry
+            .lock()
+            .map(|registry| {
+                registry
+                    .names()
+                    .iter()
+                    .filter_map(|name| registry.get(name))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        for mode in modes {
+            if let Ok(mut mode) = mode.lock() {
+                mode.set_clock(clock.clone());
+            }
+        }
+
+        if let Ok(mut current) = self.clock.lock() {
+            *current = clock;
+        }
+    }
+
+    /// Names of the registered modes
+    pub fn registered_modes(&self) -> Vec<String> {
+        self.registry
+            .lock()
+            .map(|registry| registry.names())
+            .unwrap_or_default()
+    }
+
+    /// Get the registered mode behind an interaction mode
+    fn registered_mode(&self, mode: &InteractionMode) -> Option<SharedMode> {
+        self.registry
+            .lock()
+            .ok()
+            .and_then(|registry| registry.get_mode(mode))
+    }
+
+    /// Get the mode that handles input: the current mode, or basic mode
+    /// when inactive or when the current mode has no implementation yet
+    fn input_mode(&self) -> SharedMode {
+        self.get_current_mode()
+            .and_then(|mode| self.registered_mode(&mode))
+            .unwrap_or_else(|| self.basic_mode.clone())
+    }
+
+    /// Describe what the current mode wants drawn over the screen
+    pub fn current_overlay(&self) -> ModeOverlay {
+        match self.get_current_mode() {
+            Some(mode) => self
+                .registered_mode(&mode)
+                .and_then(|mode| mode.lock().ok().map(|mode| mode.overlay()))
+                .unwrap_or_default(),
+            None => ModeOverlay::None,
         }
     }
 
+    /// Advance the timers of the current mode, returning any action they produce
+    pub fn tick(&self) -> Result<Action> {
+        let Some(mode) = self
+            .get_current_mode()
+            .and_then(|mode| self.registered_mode(&mode))
+        else {
+            return Ok(Action::NoAction);
+        };
+        let action = match mode.lock() {
+            Ok(mut mode) => mode.tick(),
+            Err(_) => Ok(Action::NoAction),
+        };
+        action
+    }
+
     /// Subscribe to mode change events
     pub fn subscribe_to_events(&self) -> broadcast::Receiver<ModeEvent> {
         self.event_sender.subscribe()
@@ -104,6 +223,46 @@ impl ModeManager {
             .unwrap_or_default()
     }
 
+    /// Set the keys that run scripts
+    pub fn set_script_bindings(&self, bindings: Vec<ScriptBinding>) {
+        if let Ok(mut script_bindings) = self.script_bindings.lock() {
+            *script_bindings = bindings;
+        }
+    }
+
+    /// Get the script a key runs in a mode, if any
+    fn script_for_key(&self, key: char, mode: &InteractionMode) -> Option<String> {
+        self.script_bindings.lock().ok().and_then(|bindings| {
+            bindings
+                .iter()
+                .find(|binding| binding.key == key && binding.applies_to(mode))
+                .map(|binding| binding.script.clone())
+        })
+    }
+
+    /// Set the macro record and play keys
+    pub fn set_macro_config(&self, config: MacroConfig) {
+        if let Ok(mut macro_keys) = self.macro_keys.lock() {
+            macro_keys.set_config(config);
+        }
+    }
+
+    /// Check if a macro recording was started with the record key and not stopped yet
+    pub fn is_recording_macro(&self) -> bool {
+        self.macro_keys
+            .lock()
+            .map(|macro_keys| macro_keys.is_recording())
+            .unwrap_or(false)
+    }
+
+    /// Get the macro action a key is part of in a mode, if any
+    fn macro_action_for_key(&self, key: char, mode: &InteractionMode) -> Option<Action> {
+        self.macro_keys
+            .lock()
+            .ok()
+            .and_then(|mut macro_keys| macro_keys.process_key(key, mode))
+    }
+
     /// Add mode to history
     fn add_to_history(&self, mode: InteractionMode) {
         if let Ok(mut history) = self.mode_history.lock() {
@@ -127,39 +286,18 @@ impl ModeManager {
         }
     }
 
-    /// Process basic movement input using BasicMode implementation
-    fn process_basic_input(&self, input: KeyInput) -> Result<Action> {
+    /// Handle the release of a key, which ends a held scroll in basic mode
+    pub fn handle_key_release(&self, input: KeyInput) -> Result<Action> {
         let bindings = self.get_key_bindings();
-
-        if let Ok(mut basic_mode) = self.basic_mode.lock() {
-            let action = basic_mode.process_input(input, &bindings)?;
-
-            // Handle speed toggle action by updating our internal state
-            if action == Action::ToggleSpeed {
-                self.toggle_speed();
-            }
-
-            Ok(action)
-        } else {
-            warn!("Failed to acquire basic mode lock");
-            Ok(Action::NoAction)
-        }
-    }
-
-    /// Toggle speed mode
-    pub fn toggle_speed(&self) {
-        if let Ok(mut fast_mode) = self.fast_mode.lock() {
-            *fast_mode = !*fast_mode;
-            info!(
-                "Speed mode toggled to: {}",
-                if *fast_mode { "fast" } else { "normal" }
-            );
-
-            // Sync with basic mode
-            if let Ok(mut basic_mode) = self.basic_mode.lock() {
-                basic_mode.toggle_fast_mode();
+        let mode = self.input_mode();
+        let action = match mode.lock() {
+            Ok(mut mode) => mode.process_key_release(input, &bindings),
+            Err(_) => {
+                warn!("Failed to acquire mode lock");
+                Ok(Action::NoAction)
             }
-        }
+        };
+        action
     }
 
     /// Check if currently in hold state
@@ -179,24 +317,27 @@ impl ModeManager {
         }
     }
 
-    /// Set movement speed
-    pub fn set_movement_speed(&self, speed: f32) {
-        if let Ok(mut movement_speed) = self.movement_speed.lock() {
-            *movement_speed = speed;
-
-            // Sync with basic mode
-            if let Ok(mut basic_mode) = self.basic_mode.lock() {
-                basic_mode.set_movement_speed(speed);
-            }
+    /// Set the movement speed tier
+    pub fn set_speed(&self, speed: MovementSpeed) {
+        if let Ok(mut basic_mode) = self.basic_mode.lock() {
+            basic_mode.set_speed(speed);
         }
     }
 
-    /// Get current movement speed
-    pub fn get_movement_speed(&self) -> f32 {
-        self.movement_speed
+    /// Get the current movement speed tier
+    pub fn get_speed(&self) -> MovementSpeed {
+        self.basic_mode
             .lock()
-            .map(|speed| *speed)
-            .unwrap_or(1.0)
+            .map(|basic_mode| basic_mode.get_speed())
+            .unwrap_or(MovementSpeed::Normal)
+    }
+
+    /// Apply movement settings: step sizes, speed tiers and default animation
+    pub fn set_movement_config(&self, movement: MovementConfig) {
+        self.set_default_animation(movement.default_animation);
+        if let Ok(mut basic_mode) = self.basic_mode.lock() {
+            basic_mode.set_movement_config(movement);
+        }
     }
 
     /// Set grid manager for grid mode
@@ -224,6 +365,25 @@ impl ModeManager {
         }
     }
 
+    /// Set keyboard layout used by grid and area mode
+    pub fn set_keyboard_layout(&self, keyboard_layout: crate::KeyboardLayout) -> Result<()> {
+        if let Ok(mut grid_mode) = self.grid_mode.lock() {
+            grid_mode.set_keyboard_layout(keyboard_layout.clone())?;
+        }
+        if let Ok(mut area_mode) = self.area_mode.lock() {
+            area_mode.set_keyboard_layout(keyboard_layout);
+        }
+        Ok(())
+    }
+
+    /// Set the area layout for area mode
+    pub fn set_area_layout(&self, layout: crate::AreaLayout) -> Result<()> {
+        if let Ok(mut area_mode) = self.area_mode.lock() {
+            area_mode.set_layout(layout)?;
+        }
+        Ok(())
+    }
+
     /// Set screen dimensions for area mode
     pub fn set_area_screen_dimensions(&self, width: f64, height: f64) {
         if let Ok(mut area_mode) = self.area_mode.lock() {
@@ -231,6 +391,84 @@ impl ModeManager {
         }
     }
 
+    /// Set the screens for grid and area mode
+    pub fn set_screens(&self, screens: Vec<crate::models::ScreenBounds>) -> Result<()> {
+        Self::update_mode_screens(&self.grid_mode, &self.area_mode, screens)
+    }
+
+    /// Apply a change of the connected screens to grid and area mode
+    pub fn apply_screen_change(&self, event: &ScreenChangeEvent) -> Result<()> {
+        self.set_screens(event.bounds())
+    }
+
+    /// Keep grid and area mode in sync with the connected screens
+    ///
+    /// Spawns a task on the current tokio runtime that applies every screen
+    /// change published by [`ScreenManager`].
+    pub fn follow_screen_changes(&self) -> tokio::task::JoinHandle<()> {
+        let mut events = ScreenManager::subscribe();
+        let grid_mode = self.grid_mode.clone();
+        let area_mode = self.area_mode.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let screens = match events.recv().await {
+                    Ok(event) => event.bounds(),
+                    // Missed events only matter for the latest layout
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        match ScreenManager::detect_screens() {
+                            Ok(screens) => screens,
+                            Err(_) => continue,
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+
+                if let Err(e) = Self::update_mode_screens(&grid_mode, &area_mode, screens) {
+                    warn!("Failed to apply screen change: {}", e);
+                }
+            }
+        })
+    }
+
+    fn update_mode_screens(
+        grid_mode: &Mutex<GridMode>,
+        area_mode: &Mutex<AreaMode>,
+        screens: Vec<crate::models::ScreenBounds>,
+    ) -> Result<()> {
+        if let Ok(mut grid_mode) = grid_mode.lock() {
+            grid_mode.update_screens(screens.clone())?;
+        }
+        if let Ok(mut area_mode) = area_mode.lock() {
+            area_mode.set_screens(screens);
+        }
+        Ok(())
+    }
+
+    /// Set how grid and area mode lay out their targets over several screens
+    pub fn set_multi_screen_layout(&self, multi_screen: crate::MultiScreenLayout) -> Result<()> {
+        if let Ok(mut grid_mode) = self.grid_mode.lock() {
+            grid_mode.update_multi_screen_layout(multi_screen)?;
+        }
+        if let Ok(mut area_mode) = self.area_mode.lock() {
+            area_mode.set_multi_screen_layout(multi_screen);
+        }
+        Ok(())
+    }
+
+    /// Set the animation the modes use for cursor movement
+    pub fn set_default_animation(&self, animation_type: AnimationType) {
+        if let Ok(mut basic_mode) = self.basic_mode.lock() {
+            basic_mode.set_animation_type(animation_type);
+        }
+        if let Ok(mut grid_mode) = self.grid_mode.lock() {
+            grid_mode.set_animation_type(animation_type);
+        }
+        if let Ok(mut area_mode) = self.area_mode.lock() {
+            area_mode.set_animation_type(animation_type);
+        }
+    }
+
     /// Get current areas for area mode
     pub fn get_current_areas(&self) -> Vec<crate::models::Area> {
         if let Ok(area_mode) = self.area_mode.lock() {
@@ -244,6 +482,15 @@ impl ModeManager {
 #[async_trait]
 impl ModeController for ModeManager {
     async fn activate_mode(&mut self, mode: InteractionMode) -> Result<()> {
+        // A custom mode named like a built-in one is the built-in mode
+        let mode = InteractionMode::from_name(mode.name()).unwrap_or(mode);
+        let registered = self.registered_mode(&mode);
+        if registered.is_none() {
+            if let InteractionMode::Custom(name) = &mode {
+                return Err(MouselessError::ModeError(format!("Unknown mode: {}", name)));
+            }
+        }
+
         let previous_mode = {
             let mut current = self.current_mode.lock().unwrap();
             let prev = current.clone();
@@ -252,35 +499,17 @@ impl ModeController for ModeManager {
         };
 
         // Deactivate previous mode
-        if let Some(prev_mode) = &previous_mode {
-            match prev_mode {
-                InteractionMode::Grid => {
-                    if let Ok(mut grid_mode) = self.grid_mode.lock() {
-                        grid_mode.deactivate();
-                    }
-                }
-                InteractionMode::Area => {
-                    if let Ok(mut area_mode) = self.area_mode.lock() {
-                        area_mode.deactivate();
-                    }
-                }
-                _ => {} // Other modes don't need special deactivation yet
+        if let Some(prev_mode) = previous_mode.as_ref().and_then(|m| self.registered_mode(m)) {
+            if let Ok(mut prev_mode) = prev_mode.lock() {
+                prev_mode.deactivate();
             }
         }
 
         // Activate new mode
-        match &mode {
-            InteractionMode::Grid => {
-                if let Ok(mut grid_mode) = self.grid_mode.lock() {
-                    grid_mode.activate();
-                }
-            }
-            InteractionMode::Area => {
-                if let Ok(mut area_mode) = self.area_mode.lock() {
-                    area_mode.activate();
-                }
+        if let Some(new_mode) = registered {
+            if let Ok(mut new_mode) = new_mode.lock() {
+                new_mode.activate();
             }
-            _ => {} // Other modes don't need special activation yet
         }
 
         // Add previous mode to history if it existed
@@ -306,19 +535,13 @@ impl ModeController for ModeManager {
         };
 
         if let Some(mode) = current_mode {
-            // Deactivate the specific mode
-            match &mode {
-                InteractionMode::Grid => {
-                    if let Ok(mut grid_mode) = self.grid_mode.lock() {
-                        grid_mode.deactivate();
-                    }
-                }
-                InteractionMode::Area => {
-                    if let Ok(mut area_mode) = self.area_mode.lock() {
-                        area_mode.deactivate();
-                    }
+            if let Ok(mut macro_keys) = self.macro_keys.lock() {
+                macro_keys.cancel();
+            }
+            if let Some(registered) = self.registered_mode(&mode) {
+                if let Ok(mut registered) = registered.lock() {
+                    registered.deactivate();
                 }
-                _ => {} // Other modes don't need special deactivation yet
             }
 
             self.send_event(ModeEvent::ModeDeactivated(mode.clone()));
@@ -334,39 +557,43 @@ impl ModeController for ModeManager {
 
     async fn handle_input(&self, input: KeyInput) -> Result<Action> {
         let current_mode = self.get_current_mode();
+        let bindings = self.get_key_bindings();
 
-        let action = match current_mode {
-            Some(InteractionMode::Basic) | None => {
-                // Basic mode or no mode active - handle basic input
-                self.process_basic_input(input.clone())?
-            }
-            Some(InteractionMode::Grid) => {
-                // Grid mode - use grid-specific input handling
-                if let Ok(mut grid_mode) = self.grid_mode.lock() {
-                    let bindings = self.get_key_bindings();
-                    grid_mode.process_input(input.clone(), &bindings)?
-                } else {
-                    warn!("Failed to acquire grid mode lock, falling back to basic input");
-                    self.process_basic_input(input.clone())?
-                }
-            }
-            Some(InteractionMode::Area) => {
-                // Area mode - use area-specific input handling
-                if let Ok(mut area_mode) = self.area_mode.lock() {
-                    let bindings = self.get_key_bindings();
-                    area_mode.process_input(input.clone(), &bindings)?
-                } else {
-                    warn!("Failed to acquire area mode lock, falling back to basic input");
-                    self.process_basic_input(input.clone())?
-                }
+        // Prediction mode has no registered implementation yet and falls back to basic input
+        //TODO: Create PredictionMode struct and register it with the mode registry
+        //TODO: Integrate with AI prediction system
+        //TODO: Handle prediction target selection and navigation
+        let undo_action = current_mode.as_ref().and_then(|_| {
+            if bindings.undo == Some(input.key) {
+                Some(Action::Undo)
+            } else if bindings.redo == Some(input.key) {
+                Some(Action::Redo)
+            } else {
+                None
             }
-            Some(InteractionMode::Prediction) => {
-                // Prediction mode - for now, fall back to basic input
-                //TODO: Implement prediction-specific input handling
-                //TODO: Create PredictionMode struct and implement input processing
-                //TODO: Integrate with AI prediction system
-                //TODO: Handle prediction target selection and navigation
-                self.process_basic_input(input.clone())?
+        });
+        let host_action = undo_action.or_else(|| {
+            current_mode
+                .as_ref()
+                .and_then(|mode| self.macro_action_for_key(input.key, mode))
+        });
+        let script = current_mode
+            .as_ref()
+            .filter(|_| host_action.is_none())
+            .and_then(|mode| self.script_for_key(input.key, mode));
+        let action = match (host_action, script) {
+            (Some(action), _) => action,
+            (None, Some(script)) => Action::RunScript(script),
+            (None, None) => {
+                let mode = self.input_mode();
+                let action = match mode.lock() {
+                    Ok(mut mode) => mode.process_input(input, &bindings)?,
+                    Err(_) => {
+                        warn!("Failed to acquire mode lock");
+                        Action::NoAction
+                    }
+                };
+                action
             }
         };
 
@@ -391,12 +618,22 @@ impl ModeController for ModeManager {
             .map(|history| history.iter().cloned().collect())
             .unwrap_or_default()
     }
+
+    fn toggle_speed(&self) -> MovementSpeed {
+        match self.basic_mode.lock() {
+            Ok(mut basic_mode) => {
+                basic_mode.toggle_fast_mode();
+                basic_mode.get_speed()
+            }
+            Err(_) => MovementSpeed::Normal,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{AnimationType, MouseButton, Position};
+    use crate::models::{AnimationType, MouseButton};
     use std::time::SystemTime;
 
     fn create_test_input(key: char) -> KeyInput {
@@ -442,6 +679,123 @@ mod tests {
         assert_eq!(manager.get_current_mode(), None);
     }
 
+    #[tokio::test]
+    async fn test_script_bindings() {
+        let mut manager = ModeManager::new(KeyBindings::default());
+        manager.set_script_bindings(vec![ScriptBinding {
+            key: 'i',
+            script: "corner".to_string(),
+            modes: vec!["grid".to_string()],
+        }]);
+
+        // Bindings only apply in their modes
+        manager.activate_mode(InteractionMode::Basic).await.unwrap();
+        let action = manager.handle_input(create_test_input('i')).await.unwrap();
+        assert!(matches!(action, Action::MoveRelative(..)));
+
+        manager.activate_mode(InteractionMode::Grid).await.unwrap();
+        let action = manager.handle_input(create_test_input('i')).await.unwrap();
+        assert_eq!(action, Action::RunScript("corner".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_macro_keys() {
+        let mut manager = ModeManager::new(KeyBindings::default());
+        manager.set_macro_config(MacroConfig {
+            record_key: Some('q'),
+            ..MacroConfig::default()
+        });
+        manager.activate_mode(InteractionMode::Basic).await.unwrap();
+
+        for key in ['q', 'a'] {
+            manager.handle_input(create_test_input(key)).await.unwrap();
+        }
+        assert!(manager.is_recording_macro());
+
+        // Movement keys still reach the mode while recording
+        let action = manager.handle_input(create_test_input('i')).await.unwrap();
+        assert!(matches!(action, Action::MoveRelative(..)));
+
+        let action = manager.handle_input(create_test_input('q')).await.unwrap();
+        assert_eq!(action, Action::StopMacroRecording);
+
+        for key in ['@', '3'] {
+            manager.handle_input(create_test_input(key)).await.unwrap();
+        }
+        let action = manager.handle_input(create_test_input('a')).await.unwrap();
+        assert_eq!(action, Action::PlayMacro('a', 3));
+    }
+
+    /// Moves the cursor by one pixel per key and counts its ticks
+    struct RulerMode {
+        active: bool,
+        ticks: Arc<Mutex<u32>>,
+    }
+
+    impl Mode for RulerMode {
+        fn name(&self) -> &str {
+            "ruler"
+        }
+
+        fn activate(&mut self) {
+            self.active = true;
+        }
+
+        fn deactivate(&mut self) {
+            self.active = false;
+        }
+
+        fn process_input(&mut self, _input: KeyInput, _bindings: &KeyBindings) -> Result<Action> {
+            Ok(Action::MoveRelative(1, 0, AnimationType::Instant))
+        }
+
+        fn tick(&mut self) -> Result<Action> {
+            *self.ticks.lock().unwrap() += 1;
+            Ok(Action::NoAction)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_mode() {
+        let mut manager = ModeManager::new(KeyBindings::default());
+        let ticks = Arc::new(Mutex::new(0));
+        manager
+            .register_mode(RulerMode {
+                active: false,
+                ticks: ticks.clone(),
+            })
+            .unwrap();
+        assert_eq!(
+            manager.registered_modes(),
+            vec!["area", "basic", "grid", "ruler"]
+        );
+
+        let ruler = InteractionMode::Custom("ruler".to_string());
+        manager.activate_mode(ruler.clone()).await.unwrap();
+        assert_eq!(manager.get_current_mode(), Some(ruler));
+
+        let action = manager.handle_input(create_test_input('i')).await.unwrap();
+        assert_eq!(action, Action::MoveRelative(1, 0, AnimationType::Instant));
+        manager.tick().unwrap();
+        assert_eq!(*ticks.lock().unwrap(), 1);
+        assert!(matches!(manager.current_overlay(), ModeOverlay::None));
+
+        // Unregistered custom modes cannot be activated
+        let unknown = InteractionMode::Custom("telepathy".to_string());
+        assert!(manager.activate_mode(unknown).await.is_err());
+
+        // Built-in names resolve to the built-in modes
+        manager
+            .activate_mode(InteractionMode::Custom("Grid".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(manager.get_current_mode(), Some(InteractionMode::Grid));
+        assert_eq!(
+            manager.get_mode_history(),
+            vec![InteractionMode::Custom("ruler".to_string())]
+        );
+    }
+
     #[tokio::test]
     async fn test_basic_input_processing() {
         let manager = ModeManager::new(KeyBindings::default());
@@ -449,11 +803,11 @@ mod tests {
         // Test movement keys
         let action = manager.handle_input(create_test_input('i')).await.unwrap();
         match action {
-            Action::MoveCursor(pos, _) => {
-                assert_eq!(pos.x, 0);
-                assert!(pos.y < 0); // Moving up
+            Action::MoveRelative(dx, dy, _) => {
+                assert_eq!(dx, 0);
+                assert!(dy < 0); // Moving up
             }
-            _ => panic!("Expected MoveCursor action"),
+            _ => panic!("Expected MoveRelative action"),
         }
 
         // Test click keys
@@ -463,6 +817,14 @@ mod tests {
         // Test exit key
         let action = manager.handle_input(create_test_input(' ')).await.unwrap();
         assert_eq!(action, Action::Exit);
+
+        // Movement follows the configured default animation
+        manager.set_default_animation(AnimationType::Elastic);
+        let action = manager.handle_input(create_test_input('l')).await.unwrap();
+        assert!(matches!(
+            action,
+            Action::MoveRelative(_, _, AnimationType::Elastic)
+        ));
     }
 
     #[tokio::test]
@@ -483,13 +845,36 @@ mod tests {
         let manager = ModeManager::new(KeyBindings::default());
 
         // Initially should be in normal speed
-        assert!(!*manager.fast_mode.lock().unwrap());
+        assert_eq!(manager.get_speed(), MovementSpeed::Normal);
 
         manager.toggle_speed();
-        assert!(*manager.fast_mode.lock().unwrap());
+        assert_eq!(manager.get_speed(), MovementSpeed::Fast);
 
         manager.toggle_speed();
-        assert!(!*manager.fast_mode.lock().unwrap());
+        assert_eq!(manager.get_speed(), MovementSpeed::Normal);
+    }
+
+    #[tokio::test]
+    async fn test_speed_follows_movement_config() {
+        let manager = ModeManager::new(KeyBindings::default());
+        manager.set_movement_config(MovementConfig {
+            default_speed: MovementSpeed::Slow,
+            step_size: 30,
+            ..MovementConfig::default()
+        });
+        assert_eq!(manager.get_speed(), MovementSpeed::Slow);
+
+        // The speed key goes through basic mode exactly once, which reports the new tier
+        let action = manager.handle_input(create_test_input('f')).await.unwrap();
+        assert_eq!(action, Action::SetSpeed(MovementSpeed::Fast));
+        assert_eq!(manager.get_speed(), MovementSpeed::Fast);
+
+        let action = manager.handle_input(create_test_input('i')).await.unwrap();
+        assert_eq!(action, Action::MoveRelative(0, -90, AnimationType::Smooth));
+
+        manager.set_speed(MovementSpeed::Precision);
+        let action = manager.handle_input(create_test_input('i')).await.unwrap();
+        assert_eq!(action, Action::MoveRelative(0, -3, AnimationType::Smooth));
     }
 
     #[test]
@@ -536,4 +921,36 @@ mod tests {
         let event = receiver.try_recv().unwrap();
         assert_eq!(event, ModeEvent::ModeActivated(InteractionMode::Basic));
     }
+
+    #[test]
+    fn test_apply_screen_change() {
+        use crate::screen_provider::ScreenInfo;
+
+        let manager = ModeManager::new(KeyBindings::default());
+        let laptop = ScreenInfo {
+            id: 0,
+            name: "eDP-1".to_string(),
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            is_primary: true,
+            scale_factor: 1.0,
+        };
+        let external = ScreenInfo {
+            id: 1,
+            name: "HDMI-1".to_string(),
+            x: 1920,
+            is_primary: false,
+            ..laptop.clone()
+        };
+
+        let event =
+            ScreenChangeEvent::between(vec![laptop.clone()], vec![laptop, external]).unwrap();
+        manager.apply_screen_change(&event).unwrap();
+
+        let areas = manager.area_mode.lock().unwrap().get_all_areas();
+        assert_eq!(areas.len(), 18);
+        assert!(areas.iter().any(|area| area.bounds.x >= 1920));
+    }
 }
@@ -0,0 +1,129 @@
+//! Mode registry module
+//!
+//! This module provides functionality for:
+//! - Registering interaction modes by name
+//! - Looking up the mode behind an `InteractionMode`
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    error::{MouselessError, Result},
+    models::InteractionMode,
+    traits::Mode,
+};
+
+/// A registered mode, shared between the registry and its owner
+pub type SharedMode = Arc<Mutex<dyn Mode>>;
+
+/// Interaction modes keyed by name, listed in name order
+#[derive(Default)]
+pub struct ModeRegistry {
+    modes: BTreeMap<String, SharedMode>,
+}
+
+impl ModeRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a mode under its name, returning the shared handle
+    pub fn register<M: Mode + 'static>(&mut self, mode: M) -> Result<SharedMode> {
+        let mode: SharedMode = Arc::new(Mutex::new(mode));
+        self.register_shared(mode.clone())?;
+        Ok(mode)
+    }
+
+    /// Register a mode that the caller keeps a handle to
+    pub fn register_shared(&mut self, mode: SharedMode) -> Result<()> {
+        let name = mode
+            .lock()
+            .map_err(|_| MouselessError::ModeError("Mode lock poisoned".to_string()))?
+            .name()
+            .to_string();
+
+        if !InteractionMode::is_valid_name(&name) {
+            return Err(MouselessError::ModeError(format!(
+                "Invalid mode name: {:?}",
+                name
+            )));
+        }
+        if self.modes.contains_key(&name) {
+            return Err(MouselessError::ModeError(format!(
+                "Mode already registered: {}",
+                name
+            )));
+        }
+
+        self.modes.insert(name, mode);
+        Ok(())
+    }
+
+    /// Get the mode registered under a name
+    pub fn get(&self, name: &str) -> Option<SharedMode> {
+        self.modes.get(name).cloned()
+    }
+
+    /// Get the mode behind an interaction mode
+    pub fn get_mode(&self, mode: &InteractionMode) -> Option<SharedMode> {
+        self.get(mode.name())
+    }
+
+    /// Check if a mode is registered under a name
+    pub fn contains(&self, name: &str) -> bool {
+        self.modes.contains_key(name)
+    }
+
+    /// Names of the registered modes, in name order
+    pub fn names(&self) -> Vec<String> {
+        self.modes.keys().cloned().collect()
+    }
+
+    /// Get the number of registered modes
+    pub fn len(&self) -> usize {
+        self.modes.len()
+    }
+
+    /// Check if no modes are registered
+    pub fn is_empty(&self) -> bool {
+        self.modes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Action, KeyInput};
+    use crate::traits::KeyBindings;
+
+    struct RulerMode;
+
+    impl Mode for RulerMode {
+        fn name(&self) -> &str {
+            "ruler"
+        }
+
+        fn process_input(&mut self, _input: KeyInput, _bindings: &KeyBindings) -> Result<Action> {
+            Ok(Action::NoAction)
+        }
+    }
+
+    #[test]
+    fn test_register_modes() {
+        let mut registry = ModeRegistry::new();
+        registry.register(RulerMode).unwrap();
+        registry.register(crate::GridMode::new()).unwrap();
+
+        assert_eq!(registry.names(), vec!["grid", "ruler"]);
+        assert!(registry.contains("ruler"));
+        assert!(registry.get_mode(&InteractionMode::Grid).is_some());
+        assert!(registry
+            .get_mode(&InteractionMode::Custom("ruler".to_string()))
+            .is_some());
+        assert!(registry.get("selection").is_none());
+
+        assert!(registry.register(RulerMode).is_err());
+        assert_eq!(registry.len(), 2);
+    }
+}
@@ -1,6 +1,20 @@
 use serde::{Deserialize, Serialize};
 
-/// Represents a position on the screen
+/// Coordinate space of a position
+///
+/// Screens are laid out in logical pixels, the space [`Position`],
+/// [`ScreenBounds`], grid cells and areas use. A screen with a scale factor
+/// of 2 has two physical (device) pixels per logical pixel along each axis.
+/// In physical space screens are placed next to each other at their physical
+/// size, so a screen's physical origin differs from its logical one once a
+/// screen before it is scaled (see [`crate::ScreenManager::physical_origins`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoordinateSpace {
+    Logical,
+    Physical,
+}
+
+/// Represents a position on the screen in logical pixels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Position {
     pub x: i32,
@@ -26,8 +40,22 @@ impl Position {
     }
 }
 
-/// Screen boundary information
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Position in physical pixels on a specific screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhysicalPosition {
+    pub x: i32,
+    pub y: i32,
+    pub screen_id: u32,
+}
+
+impl PhysicalPosition {
+    pub fn new(x: i32, y: i32, screen_id: u32) -> Self {
+        Self { x, y, screen_id }
+    }
+}
+
+/// Screen boundary information in logical pixels
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScreenBounds {
     pub id: u32,
     pub x: i32,
@@ -35,6 +63,8 @@ pub struct ScreenBounds {
     pub width: u32,
     pub height: u32,
     pub is_primary: bool,
+    /// Physical pixels per logical pixel
+    pub scale_factor: f64,
 }
 
 impl ScreenBounds {
@@ -52,6 +82,70 @@ impl ScreenBounds {
             && position.y >= self.y
             && position.y < self.y + self.height as i32
     }
+
+    /// Clamp a position into this screen and tag it with the screen id
+    pub fn clamp(&self, position: Position) -> Position {
+        Position::with_screen(
+            position
+                .x
+                .clamp(self.x, self.x + self.width.saturating_sub(1) as i32),
+            position
+                .y
+                .clamp(self.y, self.y + self.height.saturating_sub(1) as i32),
+            self.id,
+        )
+    }
+
+    /// Get the size of this screen in physical pixels
+    pub fn physical_size(&self) -> (u32, u32) {
+        (
+            (self.width as f64 * self.scale_factor).round() as u32,
+            (self.height as f64 * self.scale_factor).round() as u32,
+        )
+    }
+
+    /// Check whether a physical position lies on this screen
+    ///
+    /// `origin` is the screen's physical origin.
+    pub fn contains_physical(&self, origin: (i32, i32), position: PhysicalPosition) -> bool {
+        let (width, height) = self.physical_size();
+        position.x >= origin.0
+            && position.x < origin.0 + width as i32
+            && position.y >= origin.1
+            && position.y < origin.1 + height as i32
+    }
+
+    /// Convert a logical position on this screen to physical pixels
+    ///
+    /// `origin` is the screen's physical origin.
+    pub fn to_physical(&self, origin: (i32, i32), position: Position) -> PhysicalPosition {
+        PhysicalPosition::new(
+            origin.0 + ((position.x - self.x) as f64 * self.scale_factor).round() as i32,
+            origin.1 + ((position.y - self.y) as f64 * self.scale_factor).round() as i32,
+            self.id,
+        )
+    }
+
+    /// Convert a physical position on this screen to logical pixels
+    ///
+    /// `origin` is the screen's physical origin.
+    pub fn to_logical(&self, origin: (i32, i32), position: PhysicalPosition) -> Position {
+        Position::with_screen(
+            self.x + ((position.x - origin.0) as f64 / self.scale_factor).round() as i32,
+            self.y + ((position.y - origin.1) as f64 / self.scale_factor).round() as i32,
+            self.id,
+        )
+    }
+}
+
+/// How grid and area mode lay out their targets over several screens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MultiScreenLayout {
+    /// Every screen gets its own layout, selected with a screen number prefix key
+    #[default]
+    PerScreen,
+    /// One layout spans the bounding box of all screens
+    SpanDesktop,
 }
 
 /// Mouse button types
@@ -78,11 +172,87 @@ pub enum AnimationType {
     Linear,
     Smooth,
     Bounce,
+    Elastic,
+}
+
+/// Shape of the path the cursor follows towards its target
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PathStyle {
+    /// Straight line
+    #[default]
+    Straight,
+    /// Cubic Bézier curve
+    ///
+    /// Control points are given relative to the straight line: the first
+    /// coordinate is the fraction of the way along it, the second the sideways
+    /// offset as a fraction of its length.
+    Bezier { c1: (f32, f32), c2: (f32, f32) },
+    /// Circular arc bulging sideways by a fraction of the distance
+    Arc { bulge: f32 },
+    /// Straight line with a minimum-jerk speed profile, timed by Fitts' law
+    MinimumJerk,
+}
+
+/// Easing curve defined like CSS `cubic-bezier(x1, y1, x2, y2)`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CubicBezierEasing {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
 }
 
-/// Movement speed settings
+impl CubicBezierEasing {
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+}
+
+/// Path and easing used for cursor animations
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct PathConfig {
+    #[serde(default)]
+    pub style: PathStyle,
+    /// Easing curve overriding the one of the animation type
+    #[serde(default)]
+    pub easing: Option<CubicBezierEasing>,
+}
+
+/// Cursor path settings for each interaction mode
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModePathConfig {
+    #[serde(default)]
+    pub basic: PathConfig,
+    #[serde(default)]
+    pub grid: PathConfig,
+    #[serde(default)]
+    pub area: PathConfig,
+    #[serde(default)]
+    pub prediction: PathConfig,
+}
+
+impl ModePathConfig {
+    /// Get the path settings of a mode
+    pub fn for_mode(&self, mode: &InteractionMode) -> &PathConfig {
+        match mode {
+            InteractionMode::Basic => &self.basic,
+            InteractionMode::Grid => &self.grid,
+            InteractionMode::Area => &self.area,
+            InteractionMode::Prediction => &self.prediction,
+            // Custom modes move the cursor like basic mode
+            InteractionMode::Custom(_) => &self.basic,
+        }
+    }
+}
+
+/// Movement speed tiers
+///
+/// Step sizes and multipliers of each tier come from `MovementConfig`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MovementSpeed {
+    /// Pixel-precise adjustments
+    Precision,
     Slow,
     Normal,
     Fast,
@@ -90,6 +260,7 @@ pub enum MovementSpeed {
 
 /// Grid configuration for grid mode
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GridConfig {
     pub rows: u32,
     pub columns: u32,
@@ -98,6 +269,24 @@ pub struct GridConfig {
     pub cell_padding: u32,
     pub border_width: u32,
     pub opacity: f32,
+    /// Keys used to build cell labels; two-key labels from the keyboard layout when unset
+    #[serde(default)]
+    pub label_alphabet: Option<String>,
+    /// Which cells get the shortest labels
+    #[serde(default)]
+    pub label_priority: LabelPriority,
+}
+
+/// Order in which grid cells receive labels, shortest labels first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LabelPriority {
+    /// Left to right, top to bottom
+    #[default]
+    RowMajor,
+    /// Closest to the middle of the screen first
+    Center,
+    /// Most frequently selected cells first
+    Usage,
 }
 
 impl Default for GridConfig {
@@ -110,6 +299,8 @@ impl Default for GridConfig {
             cell_padding: 2,
             border_width: 1,
             opacity: 0.8,
+            label_alphabet: None,
+            label_priority: LabelPriority::RowMajor,
         }
     }
 }
@@ -189,25 +380,108 @@ pub enum KeyModifier {
     Cmd,
 }
 
+/// Target screen of a screen switch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScreenTarget {
+    /// Screen by its 1-based number
+    Number(u8),
+    /// Following screen in numbering order, wrapping around
+    Next,
+    /// Preceding screen in numbering order, wrapping around
+    Previous,
+    /// Nearest screen in a direction, by screen geometry
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
 /// Actions that can be performed
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Action {
+    /// Move the cursor to an absolute position
     MoveCursor(Position, AnimationType),
+    /// Move the cursor by a pixel offset from its current position
+    MoveRelative(i32, i32, AnimationType),
+    /// Jump to another screen, keeping the relative cursor position
+    SwitchScreen(ScreenTarget, AnimationType),
     Click(MouseButton),
     Scroll(ScrollDirection, i32),
+    /// A held scroll key was released
+    ScrollRelease(ScrollDirection),
+    /// Scroll by a page of the screen
+    ScrollPage(ScrollDirection),
     ActivateMode(InteractionMode),
     DeactivateMode,
     ToggleSpeed,
+    /// Switch to a movement speed tier
+    SetSpeed(MovementSpeed),
     HighlightArea(char),
+    /// Run a named script; the host expands it into the actions it queues
+    RunScript(String),
+    /// Start recording the actions that run into a macro register
+    RecordMacro(char),
+    /// Stop the macro recording
+    StopMacroRecording,
+    /// Play the macro in a register a number of times; the host runs its steps
+    PlayMacro(char, u32),
+    /// Restore the cursor position and mode from before the last absolute move or mode switch
+    Undo,
+    /// Re-apply what the last undo reverted
+    Redo,
     Exit,
     NoAction,
 }
 
+/// What the caller should do after an action was executed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionOutcome {
+    /// Keep processing input
+    Continue,
+    /// The user asked to leave mouseless
+    Exit,
+}
+
 /// Interaction modes
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InteractionMode {
     Basic,
     Grid,
     Area,
     Prediction,
+    /// A mode registered by name with the mode manager
+    Custom(String),
+}
+
+impl InteractionMode {
+    /// Name the mode is registered under, also used on command lines and over D-Bus
+    pub fn name(&self) -> &str {
+        match self {
+            InteractionMode::Basic => "basic",
+            InteractionMode::Grid => "grid",
+            InteractionMode::Area => "area",
+            InteractionMode::Prediction => "prediction",
+            InteractionMode::Custom(name) => name,
+        }
+    }
+
+    /// Look up a mode by name
+    ///
+    /// Built-in names are matched ignoring case; any other word names a
+    /// custom mode.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "basic" => Some(InteractionMode::Basic),
+            "grid" => Some(InteractionMode::Grid),
+            "area" => Some(InteractionMode::Area),
+            "prediction" => Some(InteractionMode::Prediction),
+            _ if Self::is_valid_name(name) => Some(InteractionMode::Custom(name.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Mode names must be non-empty words
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty() && !name.chars().any(|c| c.is_whitespace() || c.is_control())
+    }
 }
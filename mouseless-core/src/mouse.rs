@@ -1,20 +1,40 @@
 use crate::{
-    animation::{AnimationInterpolator, AnimationMetrics},
+    animation::AnimationTiming,
+    animation_scheduler::{AnimationScheduler, SystemClock},
+    config::MovementConfig,
     error::{MouseError, MouseResult},
-    models::{AnimationType, MouseButton, MovementSpeed, Position, ScreenBounds, ScrollDirection},
+    models::{
+        AnimationType, CoordinateSpace, MouseButton, MovementSpeed, PathConfig, PhysicalPosition,
+        Position, ScreenBounds, ScreenTarget, ScrollDirection,
+    },
     screen::ScreenManager,
+    screen_events::ScreenChangeEvent,
     traits::MouseOperations,
 };
 use enigo::{Enigo, Mouse, Settings};
-use std::thread;
-use std::time::Instant;
-use tracing;
+use std::sync::Arc;
+
+/// Coordinate space of the platform pointer API driven by enigo
+#[cfg(target_os = "macos")]
+const POINTER_SPACE: CoordinateSpace = CoordinateSpace::Logical;
+#[cfg(not(target_os = "macos"))]
+const POINTER_SPACE: CoordinateSpace = CoordinateSpace::Physical;
 
 /// Mouse controller implementation using enigo
+///
+/// All positions taken and returned are logical; they are converted to the
+/// pointer's coordinate space only when talking to enigo.
+///
+/// Animated moves block the calling thread until they land. Hosts that must
+/// stay responsive, or retarget a move in flight, should go through
+/// `MouseService`, which runs the animation on its worker thread.
 pub struct MouseController {
     enigo: Enigo,
     current_position: Position,
     movement_speed: MovementSpeed,
+    movement: MovementConfig,
+    path_config: PathConfig,
+    timing: AnimationTiming,
     screen_info: Vec<ScreenBounds>,
 }
 
@@ -30,12 +50,15 @@ impl MouseController {
         let screens = ScreenManager::detect_screens()?;
 
         // Initialize current position
-        let current_pos = Self::get_cursor_position_from_enigo(&enigo)?;
+        let current_pos = Self::get_cursor_position_from_enigo(&enigo, &screens)?;
 
         let controller = Self {
             enigo,
             current_position: current_pos,
             movement_speed: MovementSpeed::Normal,
+            movement: MovementConfig::default(),
+            path_config: PathConfig::default(),
+            timing: AnimationTiming::default(),
             screen_info: screens,
         };
 
@@ -44,97 +67,163 @@ impl MouseController {
 
     /// Refresh screen information
     pub fn refresh_screens(&mut self) -> MouseResult<()> {
-        let screens = ScreenManager::detect_screens()?;
-        self.screen_info = screens;
+        ScreenManager::refresh()?;
+        self.screen_info = ScreenManager::detect_screens()?;
         Ok(())
     }
 
-    /// Move cursor to the center of a specific screen
-    pub fn move_to_screen(&mut self, screen_number: u8) -> MouseResult<()> {
-        let screen_id = ScreenManager::map_screen_number_to_id(screen_number)?;
-        let center = ScreenManager::get_screen_center(screen_id)?;
-        self.move_to(center, AnimationType::Smooth)
+    /// Apply a change of the connected screens to the clamp data
+    pub fn apply_screen_change(&mut self, event: &ScreenChangeEvent) {
+        self.screen_info = event.bounds();
+        self.current_position = event.remap_position(self.current_position);
+    }
+
+    /// Move cursor to a specific screen, keeping its relative position;
+    /// blocks until the move lands
+    pub fn move_to_screen(
+        &mut self,
+        screen_number: u8,
+        animation: AnimationType,
+    ) -> MouseResult<()> {
+        self.switch_screen(ScreenTarget::Number(screen_number), animation)
+    }
+
+    /// Move cursor to another screen, keeping its relative position; blocks
+    /// until the move lands
+    pub fn switch_screen(
+        &mut self,
+        target: ScreenTarget,
+        animation: AnimationType,
+    ) -> MouseResult<()> {
+        let current = self.get_current_position()?;
+        let destination = Self::screen_switch_destination(&self.screen_info, current, target)?;
+        self.move_to(destination, animation)
+    }
+
+    /// Calculate where a screen switch from `current` lands
+    pub fn screen_switch_destination(
+        screens: &[ScreenBounds],
+        current: Position,
+        target: ScreenTarget,
+    ) -> MouseResult<Position> {
+        let placed = ScreenManager::place_on_screens(screens, current);
+        let from = screens
+            .iter()
+            .find(|s| Some(s.id) == placed.screen_id)
+            .ok_or_else(|| MouseError::ScreenDetectionFailed {
+                reason: "No screens available".to_string(),
+            })?;
+
+        let to = ScreenManager::resolve_target(screens, from.id, target).ok_or_else(|| {
+            MouseError::ScreenDetectionFailed {
+                reason: format!("No screen {:?} of screen {}", target, from.id),
+            }
+        })?;
+
+        Ok(ScreenManager::relative_position_on(from, to, placed))
     }
 
-    /// Move cursor by relative offset from current position
-    pub fn move_relative(&mut self, dx: i32, dy: i32) -> MouseResult<()> {
+    /// Move cursor by a number of movement steps from current position;
+    /// blocks until the move lands
+    pub fn move_relative(&mut self, dx: i32, dy: i32, animation: AnimationType) -> MouseResult<()> {
         let current = self.get_current_position()?;
-        let speed = self.get_movement_speed();
-        let pixel_distance = Self::speed_to_pixels(speed);
+        let pixel_distance = self.movement.step_pixels(self.movement_speed);
 
         let new_position = Position::new(
             current.x + (dx * pixel_distance),
             current.y + (dy * pixel_distance),
         );
 
-        self.move_to(new_position, AnimationType::Smooth)
+        self.move_to(new_position, animation)
     }
 
     /// Get current cursor position from the system
-    fn get_cursor_position_from_enigo(enigo: &Enigo) -> MouseResult<Position> {
+    fn get_cursor_position_from_enigo(
+        enigo: &Enigo,
+        screens: &[ScreenBounds],
+    ) -> MouseResult<Position> {
         let (x, y) = enigo
             .location()
             .map_err(|e| MouseError::ScreenDetectionFailed {
                 reason: format!("Failed to get cursor position: {}", e),
             })?;
 
-        Ok(Position::new(x, y))
+        Ok(Self::position_from_pointer(screens, x, y, POINTER_SPACE))
+    }
+
+    /// Convert a logical position to pointer coordinates in `space`
+    pub fn pointer_coordinates(
+        screens: &[ScreenBounds],
+        position: Position,
+        space: CoordinateSpace,
+    ) -> (i32, i32) {
+        match space {
+            CoordinateSpace::Logical => (position.x, position.y),
+            CoordinateSpace::Physical => ScreenManager::to_physical(screens, position)
+                .map_or((position.x, position.y), |physical| {
+                    (physical.x, physical.y)
+                }),
+        }
+    }
+
+    /// Convert pointer coordinates in `space` to a logical position
+    pub fn position_from_pointer(
+        screens: &[ScreenBounds],
+        x: i32,
+        y: i32,
+        space: CoordinateSpace,
+    ) -> Position {
+        match space {
+            CoordinateSpace::Logical => Position::new(x, y),
+            CoordinateSpace::Physical => ScreenManager::find_screen_for_physical(screens, x, y)
+                .and_then(|screen| {
+                    ScreenManager::to_logical(screens, PhysicalPosition::new(x, y, screen.id))
+                })
+                .unwrap_or_else(|| Position::new(x, y)),
+        }
     }
 
     /// Update current position from system
     fn update_current_position(&mut self) -> MouseResult<()> {
-        let pos = Self::get_cursor_position_from_enigo(&self.enigo)?;
+        let pos = Self::get_cursor_position_from_enigo(&self.enigo, &self.screen_info)?;
         self.current_position = pos;
         Ok(())
     }
 
-    /// Convert movement speed to pixel distance
-    fn speed_to_pixels(speed: MovementSpeed) -> i32 {
-        match speed {
-            MovementSpeed::Slow => 5,
-            MovementSpeed::Normal => 15,
-            MovementSpeed::Fast => 30,
-        }
-    }
-
     /// Validate position is within screen bounds
     fn validate_position(&self, position: Position) -> MouseResult<Position> {
-        let screens = &self.screen_info;
-
-        // If no screen_id specified, use the primary screen
-        let target_screen = if let Some(screen_id) = position.screen_id {
-            screens.iter().find(|s| s.id == screen_id)
-        } else {
-            screens.iter().find(|s| s.is_primary)
-        };
+        Self::constrain_to_screens(&self.screen_info, position)
+    }
 
-        let screen = target_screen.ok_or_else(|| MouseError::ScreenDetectionFailed {
+    /// Clamp a position onto the screens
+    ///
+    /// Positions with a screen id are clamped to that screen. Others land on
+    /// the screen containing them, or the nearest one, so relative moves flow
+    /// across screen edges.
+    pub fn constrain_to_screens(
+        screens: &[ScreenBounds],
+        position: Position,
+    ) -> MouseResult<Position> {
+        match position.screen_id {
+            Some(screen_id) => screens
+                .iter()
+                .find(|s| s.id == screen_id)
+                .map(|screen| screen.clamp(position)),
+            None if !screens.is_empty() => Some(ScreenManager::place_on_screens(screens, position)),
+            None => None,
+        }
+        .ok_or_else(|| MouseError::ScreenDetectionFailed {
             reason: format!("Screen not found for position: {:?}", position),
-        })?;
-
-        // Clamp position to screen bounds
-        let clamped_x = position
-            .x
-            .max(screen.x)
-            .min(screen.x + screen.width as i32 - 1);
-        let clamped_y = position
-            .y
-            .max(screen.y)
-            .min(screen.y + screen.height as i32 - 1);
-
-        Ok(Position {
-            x: clamped_x,
-            y: clamped_y,
-            screen_id: Some(screen.id),
         })
     }
 
     /// Perform instant movement without animation
     fn move_instant(&mut self, position: Position) -> MouseResult<()> {
         let validated_pos = self.validate_position(position)?;
+        let (x, y) = Self::pointer_coordinates(&self.screen_info, validated_pos, POINTER_SPACE);
 
         self.enigo
-            .move_mouse(validated_pos.x, validated_pos.y, enigo::Coordinate::Abs)
+            .move_mouse(x, y, enigo::Coordinate::Abs)
             .map_err(|e| MouseError::MovementFailed {
                 x: validated_pos.x,
                 y: validated_pos.y,
@@ -147,7 +236,10 @@ impl MouseController {
         Ok(())
     }
 
-    /// Perform animated movement with enhanced easing and performance tracking
+    /// Perform animated movement, blocking until the animation completes
+    ///
+    /// The animation is stepped on the calling thread with its own scheduler,
+    /// so it cannot be retargeted; `MouseService` animates without blocking.
     fn move_animated(
         &mut self,
         target: Position,
@@ -161,66 +253,67 @@ impl MouseController {
             return self.move_instant(validated_target);
         }
 
-        // Create animation interpolator
-        let interpolator = AnimationInterpolator::new(
+        let mut scheduler =
+            AnimationScheduler::new(Arc::new(SystemClock::new())).with_timing(self.timing.clone());
+        scheduler.animate_along(
             current,
             validated_target,
             self.movement_speed,
             animation_type,
+            &self.path_config,
         );
+        scheduler.run_to_completion(|position| self.move_instant(position))
+    }
 
-        let config = interpolator.config();
-        let step_delay = std::time::Duration::from_millis(config.step_delay_ms());
+    /// Set the movement configuration and switch to its default speed
+    pub fn set_movement_config(&mut self, movement: MovementConfig) {
+        self.movement_speed = movement.default_speed;
+        self.movement = movement;
+    }
 
-        // Track performance metrics
-        let mut metrics = AnimationMetrics::new();
+    /// Get the movement configuration
+    pub fn movement_config(&self) -> &MovementConfig {
+        &self.movement
+    }
 
-        // Execute animation steps
-        for step in 0..config.steps {
-            let step_start = Instant::now();
+    /// Set the path shape and easing of animated moves
+    pub fn set_path_config(&mut self, path_config: PathConfig) {
+        self.path_config = path_config;
+    }
 
-            if let Some(position) = interpolator.next_position(step) {
-                self.move_instant(position)?;
+    /// Get the path shape and easing of animated moves
+    pub fn path_config(&self) -> &PathConfig {
+        &self.path_config
+    }
 
-                // Record step timing for performance monitoring
-                let step_duration = step_start.elapsed().as_millis() as u64;
-                metrics.record_step(step_duration);
+    /// Set how animated moves are timed by distance
+    pub fn set_animation_timing(&mut self, timing: AnimationTiming) {
+        self.timing = timing;
+    }
 
-                // Sleep for remaining time to maintain consistent timing
-                if step < config.steps - 1 && step_delay > std::time::Duration::ZERO {
-                    let elapsed = step_start.elapsed();
-                    if elapsed < step_delay {
-                        thread::sleep(step_delay - elapsed);
-                    }
-                }
-            }
-        }
+    /// Get how animated moves are timed by distance
+    pub fn animation_timing(&self) -> &AnimationTiming {
+        &self.timing
+    }
 
-        // Log performance metrics if they don't meet requirements
-        if !metrics.meets_performance_requirement() {
-            tracing::warn!(
-                "Animation performance below requirement: avg={:.2}ms, max={}ms",
-                metrics.average_step_time_ms,
-                metrics.max_step_time_ms
-            );
-        } else {
-            tracing::debug!(
-                "Animation completed successfully: avg={:.2}ms, steps={}",
-                metrics.average_step_time_ms,
-                metrics.steps_completed
-            );
-        }
+    /// Move the cursor to a position immediately, without animation
+    ///
+    /// Used as the per-frame step of externally scheduled animations.
+    pub fn warp(&mut self, position: Position) -> MouseResult<()> {
+        self.move_instant(position)
+    }
 
-        Ok(())
+    /// Press and hold a mouse button, e.g. to start a drag
+    pub fn press(&mut self, button: MouseButton) -> MouseResult<()> {
+        self.button(button, enigo::Direction::Press)
     }
-}
 
-impl MouseOperations for MouseController {
-    fn move_to(&mut self, position: Position, animation: AnimationType) -> MouseResult<()> {
-        self.move_animated(position, animation)
+    /// Release a held mouse button
+    pub fn release(&mut self, button: MouseButton) -> MouseResult<()> {
+        self.button(button, enigo::Direction::Release)
     }
 
-    fn click(&mut self, button: MouseButton) -> MouseResult<()> {
+    fn button(&mut self, button: MouseButton, direction: enigo::Direction) -> MouseResult<()> {
         let enigo_button = match button {
             MouseButton::Left => enigo::Button::Left,
             MouseButton::Right => enigo::Button::Right,
@@ -228,13 +321,21 @@ impl MouseOperations for MouseController {
         };
 
         self.enigo
-            .button(enigo_button, enigo::Direction::Click)
+            .button(enigo_button, direction)
             .map_err(|e| MouseError::ClickFailed {
                 button: format!("{:?}", button),
-                reason: format!("Enigo click failed: {}", e),
-            })?;
+                reason: format!("Enigo {:?} failed: {}", direction, e),
+            })
+    }
+}
 
-        Ok(())
+impl MouseOperations for MouseController {
+    fn move_to(&mut self, position: Position, animation: AnimationType) -> MouseResult<()> {
+        self.move_animated(position, animation)
+    }
+
+    fn click(&mut self, button: MouseButton) -> MouseResult<()> {
+        self.button(button, enigo::Direction::Click)
     }
 
     fn scroll(&mut self, direction: ScrollDirection, amount: i32) -> MouseResult<()> {
@@ -278,7 +379,7 @@ impl MouseOperations for MouseController {
 
     fn get_current_position(&self) -> MouseResult<Position> {
         // Try to get the real-time position first
-        match Self::get_cursor_position_from_enigo(&self.enigo) {
+        match Self::get_cursor_position_from_enigo(&self.enigo, &self.screen_info) {
             Ok(pos) => Ok(pos),
             Err(_) => {
                 // Fall back to cached position if real-time detection fails
@@ -318,10 +419,110 @@ mod tests {
     }
 
     #[test]
-    fn test_speed_to_pixels() {
-        assert_eq!(MouseController::speed_to_pixels(MovementSpeed::Slow), 5);
-        assert_eq!(MouseController::speed_to_pixels(MovementSpeed::Normal), 15);
-        assert_eq!(MouseController::speed_to_pixels(MovementSpeed::Fast), 30);
+    fn test_pointer_coordinates_on_mixed_dpi_screens() {
+        let screens = vec![
+            ScreenBounds {
+                id: 0,
+                x: 0,
+                y: 0,
+                width: 1440,
+                height: 900,
+                is_primary: true,
+                scale_factor: 2.0,
+            },
+            ScreenBounds {
+                id: 1,
+                x: 1440,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: false,
+                scale_factor: 1.0,
+            },
+        ];
+
+        let center = Position::with_screen(720, 450, 0);
+        assert_eq!(
+            MouseController::pointer_coordinates(&screens, center, CoordinateSpace::Physical),
+            (1440, 900)
+        );
+        assert_eq!(
+            MouseController::pointer_coordinates(&screens, center, CoordinateSpace::Logical),
+            (720, 450)
+        );
+        assert_eq!(
+            MouseController::position_from_pointer(&screens, 1440, 900, CoordinateSpace::Physical),
+            center
+        );
+
+        // The second screen starts where the first ends physically, at x = 2880
+        let external = Position::with_screen(2000, 100, 1);
+        assert_eq!(
+            MouseController::pointer_coordinates(&screens, external, CoordinateSpace::Physical),
+            (3440, 100)
+        );
+        assert_eq!(
+            MouseController::position_from_pointer(&screens, 3440, 100, CoordinateSpace::Physical),
+            external
+        );
+    }
+
+    #[test]
+    fn test_cross_screen_movement() {
+        let screens = vec![
+            ScreenBounds {
+                id: 0,
+                x: 0,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: true,
+                scale_factor: 1.0,
+            },
+            ScreenBounds {
+                id: 1,
+                x: 1920,
+                y: 0,
+                width: 1280,
+                height: 1024,
+                is_primary: false,
+                scale_factor: 1.0,
+            },
+        ];
+
+        // Relative moves past the right edge continue on the next screen
+        let moved = MouseController::constrain_to_screens(&screens, Position::new(1930, 500));
+        assert_eq!(moved.unwrap(), Position::with_screen(1930, 500, 1));
+
+        // Moving below the shorter screen stops at its bottom edge
+        let moved = MouseController::constrain_to_screens(&screens, Position::new(2000, 1050));
+        assert_eq!(moved.unwrap(), Position::with_screen(2000, 1023, 1));
+
+        // Positions for a specific screen stay on it
+        let moved =
+            MouseController::constrain_to_screens(&screens, Position::with_screen(1930, 500, 0));
+        assert_eq!(moved.unwrap(), Position::with_screen(1919, 500, 0));
+        assert!(MouseController::constrain_to_screens(&[], Position::new(0, 0)).is_err());
+
+        // Screen jumps keep the relative position
+        let destination = MouseController::screen_switch_destination(
+            &screens,
+            Position::new(960, 540),
+            ScreenTarget::Right,
+        );
+        assert_eq!(destination.unwrap(), Position::with_screen(2560, 512, 1));
+        let destination = MouseController::screen_switch_destination(
+            &screens,
+            Position::new(2560, 512),
+            ScreenTarget::Next,
+        );
+        assert_eq!(destination.unwrap(), Position::with_screen(960, 540, 0));
+        assert!(MouseController::screen_switch_destination(
+            &screens,
+            Position::new(960, 540),
+            ScreenTarget::Up,
+        )
+        .is_err());
     }
 
     #[test]
@@ -461,7 +662,7 @@ mod tests {
         controller.set_movement_speed(MovementSpeed::Fast);
 
         let start_time = Instant::now();
-        let result = controller.move_relative(50, 50);
+        let result = controller.move_relative(50, 50, AnimationType::Smooth);
         let duration = start_time.elapsed();
 
         assert!(result.is_ok());
@@ -474,7 +675,7 @@ mod tests {
         let mut controller = MouseController::new().unwrap();
 
         let start_time = Instant::now();
-        let _result = controller.move_to_screen(1);
+        let _result = controller.move_to_screen(1, AnimationType::Smooth);
         let duration = start_time.elapsed();
 
         // Screen switching should not take too long regardless of success/failure
@@ -1,125 +1,726 @@
-use crate::{AnimationType, MouseController, MouseOperations, Position};
-use std::sync::mpsc;
+use crate::{
+    error::{MouseError, MouseResult},
+    focus::FocusTracker,
+    page_viewport, Action, AnimationEvent, AnimationScheduler, AnimationTiming, AnimationType,
+    MouseButton, MouseController, MouseOperations, MovementSpeed, PathConfig, Position,
+    ScreenBounds, ScreenChangeEvent, ScreenManager, ScreenTarget, ScrollConfig, ScrollDirection,
+    SmoothScroller, SystemClock,
+};
+use std::sync::{mpsc, Arc};
 use std::thread;
-use tracing::{error, info};
+use tokio::sync::broadcast::{self, error::TryRecvError};
+use tracing::{debug, error, info};
 
 /// Commands that can be sent to the mouse service
 #[derive(Debug)]
 pub enum MouseCommand {
+    /// Move to an absolute position; `None` uses the service's animation type
     MoveTo {
-        x: i32,
-        y: i32,
-        response_tx: mpsc::Sender<Result<(), String>>,
+        position: Position,
+        animation: Option<AnimationType>,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    /// Move by a pixel offset from the current position or animation target;
+    /// `None` uses the service's animation type
+    MoveRelative {
+        dx: i32,
+        dy: i32,
+        animation: Option<AnimationType>,
+        response_tx: mpsc::Sender<MouseResult<Position>>,
+    },
+    /// Jump to another screen; `None` uses the service's animation type
+    SwitchScreen {
+        target: ScreenTarget,
+        animation: Option<AnimationType>,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    Click {
+        button: MouseButton,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    ButtonDown {
+        button: MouseButton,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    ButtonUp {
+        button: MouseButton,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    /// Scroll as a scroll key goes down; the scroll is held until `ScrollRelease`
+    Scroll {
+        direction: ScrollDirection,
+        amount: i32,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    ScrollRelease {
+        direction: ScrollDirection,
+    },
+    /// Scroll by a page of the screen under the cursor
+    ScrollPage {
+        direction: ScrollDirection,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    StopScrolling {
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    SetScrollConfig {
+        config: ScrollConfig,
+    },
+    SetSpeed {
+        speed: MovementSpeed,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    GetSpeed {
+        response_tx: mpsc::Sender<MouseResult<MovementSpeed>>,
+    },
+    GetPosition {
+        response_tx: mpsc::Sender<MouseResult<Position>>,
+    },
+    GetScreens {
+        response_tx: mpsc::Sender<MouseResult<Vec<ScreenBounds>>>,
+    },
+    /// Apply the mouse part of a mode's output
+    ApplyAction {
+        action: Action,
+        response_tx: mpsc::Sender<MouseResult<()>>,
     },
     RefreshScreens {
-        response_tx: mpsc::Sender<Result<(), String>>,
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    CancelAnimation {
+        response_tx: mpsc::Sender<MouseResult<()>>,
+    },
+    SetPathConfig {
+        path_config: PathConfig,
+    },
+    SetAnimation {
+        animation_type: AnimationType,
+        timing: AnimationTiming,
     },
 }
 
+/// Animation settings applied to every move of the service
+#[derive(Debug, Clone)]
+struct AnimationSettings {
+    animation_type: AnimationType,
+    path_config: PathConfig,
+    timing: AnimationTiming,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        Self {
+            animation_type: AnimationType::Smooth,
+            path_config: PathConfig::default(),
+            timing: AnimationTiming::default(),
+        }
+    }
+}
+
+/// State owned by the service thread
+struct MouseWorker {
+    controller: Option<MouseController>,
+    scheduler: AnimationScheduler,
+    scroller: SmoothScroller,
+    focus: FocusTracker,
+    settings: AnimationSettings,
+    screen_events: broadcast::Receiver<ScreenChangeEvent>,
+}
+
+impl MouseWorker {
+    /// Get the controller, creating it on first use
+    fn controller<'a>(
+        controller: &'a mut Option<MouseController>,
+        settings: &AnimationSettings,
+    ) -> MouseResult<&'a mut MouseController> {
+        if controller.is_none() {
+            info!("🖱️ Creating new MouseController instance");
+            let mut new_controller = MouseController::new()?;
+            new_controller.set_path_config(settings.path_config);
+            new_controller.set_animation_timing(settings.timing.clone());
+            *controller = Some(new_controller);
+        }
+
+        controller
+            .as_mut()
+            .ok_or_else(|| MouseError::ServiceUnavailable {
+                reason: "Mouse controller not available".to_string(),
+            })
+    }
+
+    /// Where the cursor is headed: the animation target, or where it is now
+    fn base_position(&mut self) -> MouseResult<Position> {
+        if let Some(target) = self.scheduler.current_target() {
+            return Ok(target);
+        }
+        Self::controller(&mut self.controller, &self.settings)?.get_current_position()
+    }
+
+    /// Start an animation to `target`, or retarget the one in flight
+    fn animate_to(&mut self, target: Position, animation: AnimationType) -> MouseResult<()> {
+        let ctrl = Self::controller(&mut self.controller, &self.settings)?;
+        let current = ctrl.get_current_position()?;
+        self.scheduler.animate_along(
+            current,
+            target,
+            ctrl.get_movement_speed(),
+            animation,
+            ctrl.path_config(),
+        );
+        info!("✅ Mouse animating to ({}, {})", target.x, target.y);
+        Ok(())
+    }
+
+    /// Let the animation in flight land, so buttons act at its target
+    fn finish_animation(&mut self) -> MouseResult<()> {
+        if !self.scheduler.is_animating() {
+            return Ok(());
+        }
+        let ctrl = Self::controller(&mut self.controller, &self.settings)?;
+        self.scheduler
+            .run_to_completion(|position| ctrl.warp(position))
+    }
+
+    /// Move by an offset from where the cursor is headed, across screen edges
+    fn move_relative(
+        &mut self,
+        dx: i32,
+        dy: i32,
+        animation: AnimationType,
+    ) -> MouseResult<Position> {
+        let base = self.base_position()?;
+        let screens =
+            Self::controller(&mut self.controller, &self.settings)?.get_screen_bounds()?;
+        let target =
+            ScreenManager::place_on_screens(&screens, Position::new(base.x + dx, base.y + dy));
+        self.animate_to(target, animation)?;
+        Ok(target)
+    }
+
+    fn switch_screen(&mut self, target: ScreenTarget, animation: AnimationType) -> MouseResult<()> {
+        let base = self.base_position()?;
+        let screens =
+            Self::controller(&mut self.controller, &self.settings)?.get_screen_bounds()?;
+        let destination = MouseController::screen_switch_destination(&screens, base, target)?;
+        self.animate_to(destination, animation)
+    }
+
+    fn click(&mut self, button: MouseButton) -> MouseResult<()> {
+        self.finish_animation()?;
+        Self::controller(&mut self.controller, &self.settings)?.click(button)
+    }
+
+    fn button_down(&mut self, button: MouseButton) -> MouseResult<()> {
+        self.finish_animation()?;
+        Self::controller(&mut self.controller, &self.settings)?.press(button)
+    }
+
+    fn button_up(&mut self, button: MouseButton) -> MouseResult<()> {
+        self.finish_animation()?;
+        Self::controller(&mut self.controller, &self.settings)?.release(button)
+    }
+
+    /// Pick up the focused application's scroll multiplier as scrolling starts
+    fn detect_application(&mut self) {
+        if !self.scroller.is_active() {
+            let application = self.focus.focused_application();
+            debug!("🖱️ Scrolling in {:?}", application);
+            self.scroller.set_application(application);
+        }
+    }
+
+    fn scroll(&mut self, direction: ScrollDirection, amount: i32) -> MouseResult<()> {
+        self.finish_animation()?;
+        Self::controller(&mut self.controller, &self.settings)?;
+        self.detect_application();
+        self.scroller.scroll(direction, amount);
+        Ok(())
+    }
+
+    fn scroll_page(&mut self, direction: ScrollDirection) -> MouseResult<()> {
+        self.finish_animation()?;
+        self.detect_application();
+        let ctrl = Self::controller(&mut self.controller, &self.settings)?;
+        let viewport = page_viewport(
+            &ctrl.get_screen_bounds()?,
+            ctrl.get_current_position()?,
+            direction,
+        );
+        self.scroller.page(direction, viewport);
+        Ok(())
+    }
+
+    fn set_speed(&mut self, speed: MovementSpeed) -> MouseResult<()> {
+        Self::controller(&mut self.controller, &self.settings)?.set_movement_speed(speed);
+        info!("🖱️ Movement speed set to {:?}", speed);
+        Ok(())
+    }
+
+    /// Toggle between fast speed and the configured default speed
+    fn toggle_speed(&mut self) -> MouseResult<()> {
+        let ctrl = Self::controller(&mut self.controller, &self.settings)?;
+        let speed = if ctrl.get_movement_speed() == MovementSpeed::Fast {
+            ctrl.movement_config().default_speed
+        } else {
+            MovementSpeed::Fast
+        };
+        self.set_speed(speed)
+    }
+
+    /// Apply an action; actions without a mouse effect are ignored
+    fn apply_action(&mut self, action: Action) -> MouseResult<()> {
+        match action {
+            Action::MoveCursor(position, animation) => self.animate_to(position, animation),
+            Action::MoveRelative(dx, dy, animation) => {
+                self.move_relative(dx, dy, animation).map(|_| ())
+            }
+            Action::SwitchScreen(target, animation) => self.switch_screen(target, animation),
+            Action::Click(button) => self.click(button),
+            Action::Scroll(direction, amount) => self.scroll(direction, amount),
+            Action::ScrollRelease(direction) => {
+                self.scroller.release(direction);
+                Ok(())
+            }
+            Action::ScrollPage(direction) => self.scroll_page(direction),
+            Action::ToggleSpeed => self.toggle_speed(),
+            Action::SetSpeed(speed) => self.set_speed(speed),
+            other => {
+                debug!("🖱️ Ignoring non-mouse action {:?}", other);
+                Ok(())
+            }
+        }
+    }
+
+    fn handle_command(&mut self, command: MouseCommand) {
+        match command {
+            MouseCommand::MoveTo {
+                position,
+                animation,
+                response_tx,
+            } => {
+                info!(
+                    "🖱️ Moving mouse to position ({}, {})",
+                    position.x, position.y
+                );
+                let animation = animation.unwrap_or(self.settings.animation_type);
+                Self::reply(response_tx, self.animate_to(position, animation));
+            }
+            MouseCommand::MoveRelative {
+                dx,
+                dy,
+                animation,
+                response_tx,
+            } => {
+                let animation = animation.unwrap_or(self.settings.animation_type);
+                Self::reply(response_tx, self.move_relative(dx, dy, animation));
+            }
+            MouseCommand::SwitchScreen {
+                target,
+                animation,
+                response_tx,
+            } => {
+                let animation = animation.unwrap_or(self.settings.animation_type);
+                Self::reply(response_tx, self.switch_screen(target, animation));
+            }
+            MouseCommand::Click {
+                button,
+                response_tx,
+            } => {
+                Self::reply(response_tx, self.click(button));
+            }
+            MouseCommand::ButtonDown {
+                button,
+                response_tx,
+            } => {
+                Self::reply(response_tx, self.button_down(button));
+            }
+            MouseCommand::ButtonUp {
+                button,
+                response_tx,
+            } => {
+                Self::reply(response_tx, self.button_up(button));
+            }
+            MouseCommand::Scroll {
+                direction,
+                amount,
+                response_tx,
+            } => {
+                Self::reply(response_tx, self.scroll(direction, amount));
+            }
+            MouseCommand::SetSpeed { speed, response_tx } => {
+                Self::reply(response_tx, self.set_speed(speed));
+            }
+            MouseCommand::GetSpeed { response_tx } => {
+                let result = Self::controller(&mut self.controller, &self.settings)
+                    .map(|ctrl| ctrl.get_movement_speed());
+                Self::reply(response_tx, result);
+            }
+            MouseCommand::GetPosition { response_tx } => {
+                let result = Self::controller(&mut self.controller, &self.settings)
+                    .and_then(|ctrl| ctrl.get_current_position());
+                Self::reply(response_tx, result);
+            }
+            MouseCommand::GetScreens { response_tx } => {
+                let result = Self::controller(&mut self.controller, &self.settings)
+                    .and_then(|ctrl| ctrl.get_screen_bounds());
+                Self::reply(response_tx, result);
+            }
+            MouseCommand::ApplyAction {
+                action,
+                response_tx,
+            } => {
+                Self::reply(response_tx, self.apply_action(action));
+            }
+            MouseCommand::RefreshScreens { response_tx } => {
+                let result = Self::controller(&mut self.controller, &self.settings)
+                    .and_then(|ctrl| ctrl.refresh_screens());
+                if result.is_ok() {
+                    info!("🖱️ Mouse controller screen information refreshed");
+                }
+                Self::reply(response_tx, result);
+            }
+            MouseCommand::CancelAnimation { response_tx } => {
+                if let Some(id) = self.scheduler.cancel() {
+                    info!("🖱️ Cancelled mouse animation {}", id);
+                }
+                Self::reply(response_tx, Ok(()));
+            }
+            MouseCommand::ScrollRelease { direction } => {
+                self.scroller.release(direction);
+            }
+            MouseCommand::ScrollPage {
+                direction,
+                response_tx,
+            } => {
+                Self::reply(response_tx, self.scroll_page(direction));
+            }
+            MouseCommand::StopScrolling { response_tx } => {
+                self.scroller.stop();
+                Self::reply(response_tx, Ok(()));
+            }
+            MouseCommand::SetScrollConfig { config } => {
+                self.scroller.set_config(config);
+            }
+            MouseCommand::SetPathConfig { path_config } => {
+                self.settings.path_config = path_config;
+                if let Some(ctrl) = &mut self.controller {
+                    ctrl.set_path_config(path_config);
+                }
+            }
+            MouseCommand::SetAnimation {
+                animation_type,
+                timing,
+            } => {
+                self.settings.animation_type = animation_type;
+                self.settings.timing = timing.clone();
+                self.scheduler.set_timing(timing.clone());
+                if let Some(ctrl) = &mut self.controller {
+                    ctrl.set_animation_timing(timing);
+                }
+            }
+        }
+    }
+
+    /// Apply the screen changes since the last command, so the clamp data never goes stale
+    fn apply_screen_changes(&mut self) {
+        loop {
+            let event = match self.screen_events.try_recv() {
+                Ok(event) => event,
+                // Every event lists all current screens, so skipped ones do not matter
+                Err(TryRecvError::Lagged(_)) => continue,
+                Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+            };
+            // A controller created later reads the new screens itself
+            if let Some(ctrl) = &mut self.controller {
+                ctrl.apply_screen_change(&event);
+                info!(
+                    "🖱️ Mouse controller updated for {} screen(s)",
+                    event.current.len()
+                );
+            }
+        }
+    }
+
+    fn reply<T>(response_tx: mpsc::Sender<MouseResult<T>>, result: MouseResult<T>) {
+        if let Err(e) = &result {
+            error!("❌ Mouse command failed: {}", e);
+        }
+        let _ = response_tx.send(result);
+    }
+
+    /// Check if an animation or a scroll needs frames
+    fn is_busy(&self) -> bool {
+        self.scheduler.is_animating() || self.scroller.is_active()
+    }
+
+    /// Advance the animation and the scroll in flight by one frame
+    fn tick(&mut self) {
+        if let Some(ctrl) = &mut self.controller {
+            if let Err(e) = self.scheduler.tick(|position| ctrl.warp(position)) {
+                error!("❌ Mouse animation failed: {}", e);
+            }
+            if let Err(e) = self.scroller.tick(ctrl) {
+                error!("❌ Mouse scroll failed: {}", e);
+                self.scroller.stop();
+            }
+        }
+    }
+}
+
 /// Thread-safe mouse service that runs MouseController in a dedicated thread
+///
+/// Moves are animated frame by frame on the service thread: a move returns as
+/// soon as its animation is scheduled, and a new move retargets the one in
+/// flight. Clicks, button presses and scrolls first let the animation in
+/// flight land. Scrolls glide and keep going while their key is held, until a
+/// matching scroll release. The thread stops once the last clone of the
+/// service is dropped.
 pub struct MouseService {
     command_tx: mpsc::Sender<MouseCommand>,
+    animation_events: broadcast::Sender<AnimationEvent>,
 }
 
 impl MouseService {
     pub fn new() -> Self {
         let (command_tx, command_rx) = mpsc::channel::<MouseCommand>();
+        let screen_events = ScreenManager::subscribe();
+
+        let clock = Arc::new(SystemClock::new());
+        let scheduler = AnimationScheduler::new(clock.clone());
+        let scroller = SmoothScroller::new(clock, ScrollConfig::default());
+        let animation_events = scheduler.event_sender();
 
         // Spawn a dedicated thread for mouse operations
         thread::spawn(move || {
-            let mut controller: Option<MouseController> = None;
-
-            for command in command_rx {
-                match command {
-                    MouseCommand::MoveTo { x, y, response_tx } => {
-                        info!("🖱️ Moving mouse to position ({}, {})", x, y);
-
-                        // Get or create controller
-                        if controller.is_none() {
-                            info!("🖱️ Creating new MouseController instance");
-                            match MouseController::new() {
-                                Ok(new_controller) => {
-                                    controller = Some(new_controller);
-                                }
-                                Err(e) => {
-                                    let error_msg = format!("Failed to create mouse controller: {}", e);
-                                    error!("❌ {}", error_msg);
-                                    let _ = response_tx.send(Err(error_msg));
-                                    continue;
-                                }
-                            }
-                        }
-
-                        // Use the controller
-                        let result = if let Some(ref mut ctrl) = controller {
-                            let position = Position::new(x, y);
-                            ctrl.move_to(position, AnimationType::Smooth)
-                                .map_err(|e| format!("Failed to move mouse: {}", e))
-                        } else {
-                            Err("Mouse controller not available".to_string())
-                        };
-
-                        match result {
-                            Ok(_) => {
-                                info!("✅ Mouse moved successfully to ({}, {})", x, y);
-                                let _ = response_tx.send(Ok(()));
-                            }
-                            Err(e) => {
-                                error!("❌ Failed to move mouse: {}", e);
-                                let _ = response_tx.send(Err(e));
-                            }
-                        }
+            let mut worker = MouseWorker {
+                controller: None,
+                scheduler,
+                scroller,
+                focus: FocusTracker::new(),
+                settings: AnimationSettings::default(),
+                screen_events,
+            };
+
+            loop {
+                // Wake up for the next frame while busy, otherwise wait for work
+                let command = if worker.is_busy() {
+                    match command_rx.recv_timeout(worker.scheduler.frame_interval()) {
+                        Ok(command) => Some(command),
+                        Err(mpsc::RecvTimeoutError::Timeout) => None,
+                        Err(mpsc::RecvTimeoutError::Disconnected) => break,
                     }
-                    MouseCommand::RefreshScreens { response_tx } => {
-                        let result = if let Some(ref mut ctrl) = controller {
-                            ctrl.refresh_screens()
-                                .map_err(|e| format!("Failed to refresh mouse controller screens: {}", e))
-                        } else {
-                            Err("Mouse controller not available".to_string())
-                        };
-
-                        match result {
-                            Ok(_) => {
-                                info!("🖱️ Mouse controller screen information refreshed");
-                                let _ = response_tx.send(Ok(()));
-                            }
-                            Err(e) => {
-                                error!("❌ Failed to refresh screens: {}", e);
-                                let _ = response_tx.send(Err(e));
-                            }
-                        }
+                } else {
+                    match command_rx.recv() {
+                        Ok(command) => Some(command),
+                        Err(_) => break,
                     }
+                };
+
+                if let Some(command) = command {
+                    worker.apply_screen_changes();
+                    worker.handle_command(command);
                 }
+
+                worker.tick();
             }
         });
 
-        Self { command_tx }
+        Self {
+            command_tx,
+            animation_events,
+        }
     }
 
-    /// Move mouse to specific position
-    pub async fn move_to_position(&self, x: i32, y: i32) -> Result<(), String> {
+    /// Send a command and wait for its response
+    fn request<T>(
+        &self,
+        command: impl FnOnce(mpsc::Sender<MouseResult<T>>) -> MouseCommand,
+    ) -> MouseResult<T> {
         let (response_tx, response_rx) = mpsc::channel();
-        
-        self.command_tx
-            .send(MouseCommand::MoveTo { x, y, response_tx })
-            .map_err(|e| format!("Failed to send mouse command: {}", e))?;
+
+        self.send(command(response_tx))?;
 
         response_rx
             .recv()
-            .map_err(|e| format!("Failed to receive mouse response: {}", e))?
+            .map_err(|e| MouseError::ServiceUnavailable {
+                reason: format!("Failed to receive mouse response: {}", e),
+            })?
     }
 
-    /// Refresh screen information for the mouse controller
-    pub async fn refresh_screens(&self) -> Result<(), String> {
-        let (response_tx, response_rx) = mpsc::channel();
-        
+    fn send(&self, command: MouseCommand) -> MouseResult<()> {
         self.command_tx
-            .send(MouseCommand::RefreshScreens { response_tx })
-            .map_err(|e| format!("Failed to send refresh command: {}", e))?;
+            .send(command)
+            .map_err(|e| MouseError::ServiceUnavailable {
+                reason: format!("Failed to send mouse command: {}", e),
+            })
+    }
 
-        response_rx
-            .recv()
-            .map_err(|e| format!("Failed to receive refresh response: {}", e))?
+    /// Move mouse to specific position with the service's animation type
+    pub async fn move_to_position(&self, x: i32, y: i32) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::MoveTo {
+            position: Position::new(x, y),
+            animation: None,
+            response_tx,
+        })
+    }
+
+    /// Move mouse to specific position with the given animation type
+    pub async fn move_to(&self, position: Position, animation: AnimationType) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::MoveTo {
+            position,
+            animation: Some(animation),
+            response_tx,
+        })
+    }
+
+    /// Move mouse by a pixel offset and return its target; `None` uses the
+    /// service's animation type
+    pub async fn move_relative(
+        &self,
+        dx: i32,
+        dy: i32,
+        animation: Option<AnimationType>,
+    ) -> MouseResult<Position> {
+        self.request(|response_tx| MouseCommand::MoveRelative {
+            dx,
+            dy,
+            animation,
+            response_tx,
+        })
+    }
+
+    /// Move mouse to another screen, keeping its relative position; `None`
+    /// uses the service's animation type
+    pub async fn switch_screen(
+        &self,
+        target: ScreenTarget,
+        animation: Option<AnimationType>,
+    ) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::SwitchScreen {
+            target,
+            animation,
+            response_tx,
+        })
+    }
+
+    /// Click a mouse button
+    pub async fn click(&self, button: MouseButton) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::Click {
+            button,
+            response_tx,
+        })
+    }
+
+    /// Press and hold a mouse button
+    pub async fn button_down(&self, button: MouseButton) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::ButtonDown {
+            button,
+            response_tx,
+        })
+    }
+
+    /// Release a held mouse button
+    pub async fn button_up(&self, button: MouseButton) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::ButtonUp {
+            button,
+            response_tx,
+        })
+    }
+
+    /// Scroll in a direction
+    pub async fn scroll(&self, direction: ScrollDirection, amount: i32) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::Scroll {
+            direction,
+            amount,
+            response_tx,
+        })
+    }
+
+    /// End the hold of a scroll started by `scroll`, leaving it to its inertia
+    pub fn scroll_release(&self, direction: ScrollDirection) -> MouseResult<()> {
+        self.send(MouseCommand::ScrollRelease { direction })
+    }
+
+    /// Scroll by a page of the screen under the cursor
+    pub async fn scroll_page(&self, direction: ScrollDirection) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::ScrollPage {
+            direction,
+            response_tx,
+        })
+    }
+
+    /// Stop all scrolling at once
+    pub async fn stop_scrolling(&self) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::StopScrolling { response_tx })
+    }
+
+    /// Set smooth scrolling, natural scrolling and per-application multipliers
+    ///
+    /// Multipliers apply to the application focused when a scroll starts.
+    pub fn set_scroll_config(&self, config: ScrollConfig) -> MouseResult<()> {
+        self.send(MouseCommand::SetScrollConfig { config })
+    }
+
+    /// Set the movement speed tier
+    pub async fn set_speed(&self, speed: MovementSpeed) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::SetSpeed { speed, response_tx })
+    }
+
+    /// Get the movement speed tier
+    pub async fn get_speed(&self) -> MouseResult<MovementSpeed> {
+        self.request(|response_tx| MouseCommand::GetSpeed { response_tx })
+    }
+
+    /// Get the current cursor position
+    pub async fn get_position(&self) -> MouseResult<Position> {
+        self.request(|response_tx| MouseCommand::GetPosition { response_tx })
+    }
+
+    /// Get the screens known to the mouse controller
+    pub async fn get_screens(&self) -> MouseResult<Vec<ScreenBounds>> {
+        self.request(|response_tx| MouseCommand::GetScreens { response_tx })
+    }
+
+    /// Apply the mouse part of an action, e.g. the output of a mode
+    ///
+    /// Actions without a mouse effect, like mode changes, are ignored.
+    pub async fn apply_action(&self, action: Action) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::ApplyAction {
+            action,
+            response_tx,
+        })
+    }
+
+    /// Refresh screen information for the mouse controller
+    pub async fn refresh_screens(&self) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::RefreshScreens { response_tx })
+    }
+
+    /// Stop the cursor animation in flight, leaving the cursor where it is
+    pub async fn cancel_animation(&self) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::CancelAnimation { response_tx })
+    }
+
+    /// Set the path shape and easing of cursor animations, e.g. for the active mode
+    pub fn set_path_config(&self, path_config: PathConfig) -> MouseResult<()> {
+        self.send(MouseCommand::SetPathConfig { path_config })
+    }
+
+    /// Set the animation type and distance-based timing of cursor moves
+    pub fn set_animation(
+        &self,
+        animation_type: AnimationType,
+        timing: AnimationTiming,
+    ) -> MouseResult<()> {
+        self.send(MouseCommand::SetAnimation {
+            animation_type,
+            timing,
+        })
+    }
+
+    /// Subscribe to cursor animation progress events
+    pub fn subscribe_to_animation_events(&self) -> broadcast::Receiver<AnimationEvent> {
+        self.animation_events.subscribe()
     }
 }
 
@@ -127,12 +728,90 @@ impl Clone for MouseService {
     fn clone(&self) -> Self {
         Self {
             command_tx: self.command_tx.clone(),
+            animation_events: self.animation_events.clone(),
         }
     }
 }
 
+/// Blocking access to the service, e.g. for the `ActionExecutor`
+impl MouseOperations for MouseService {
+    fn move_to(&mut self, position: Position, animation: AnimationType) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::MoveTo {
+            position,
+            animation: Some(animation),
+            response_tx,
+        })
+    }
+
+    fn move_relative(
+        &mut self,
+        dx: i32,
+        dy: i32,
+        animation: AnimationType,
+    ) -> MouseResult<Position> {
+        self.request(|response_tx| MouseCommand::MoveRelative {
+            dx,
+            dy,
+            animation: Some(animation),
+            response_tx,
+        })
+    }
+
+    fn click(&mut self, button: MouseButton) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::Click {
+            button,
+            response_tx,
+        })
+    }
+
+    fn scroll(&mut self, direction: ScrollDirection, amount: i32) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::Scroll {
+            direction,
+            amount,
+            response_tx,
+        })
+    }
+
+    fn release_scroll(&mut self, direction: ScrollDirection) -> MouseResult<()> {
+        self.scroll_release(direction)
+    }
+
+    fn glides_scrolls(&self) -> bool {
+        true
+    }
+
+    fn scroll_page(&mut self, direction: ScrollDirection) -> MouseResult<()> {
+        self.request(|response_tx| MouseCommand::ScrollPage {
+            direction,
+            response_tx,
+        })
+    }
+
+    fn get_current_position(&self) -> MouseResult<Position> {
+        self.request(|response_tx| MouseCommand::GetPosition { response_tx })
+    }
+
+    fn get_screen_bounds(&self) -> MouseResult<Vec<ScreenBounds>> {
+        self.request(|response_tx| MouseCommand::GetScreens { response_tx })
+    }
+
+    fn set_movement_speed(&mut self, speed: MovementSpeed) {
+        if let Err(e) = self.request(|response_tx| MouseCommand::SetSpeed { speed, response_tx }) {
+            error!("❌ Failed to set movement speed: {}", e);
+        }
+    }
+
+    fn get_movement_speed(&self) -> MovementSpeed {
+        self.request(|response_tx| MouseCommand::GetSpeed { response_tx })
+            .unwrap_or_else(|e| {
+                error!("❌ Failed to get movement speed: {}", e);
+                MovementSpeed::Normal
+            })
+    }
+}
+
 impl Default for MouseService {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,286 @@
+//! Recording mouse module
+//!
+//! This module provides functionality for:
+//! - Recording every operation sent to a `MouseOperations` backend
+//! - An in-memory mouse backend for tests and session replay
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::MouseResult,
+    models::{AnimationType, MouseButton, MovementSpeed, Position, ScreenBounds, ScrollDirection},
+    traits::MouseOperations,
+};
+
+/// An operation sent to a mouse backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum MouseOp {
+    MoveTo {
+        position: Position,
+        animation: AnimationType,
+    },
+    MoveRelative {
+        dx: i32,
+        dy: i32,
+        animation: AnimationType,
+    },
+    Click {
+        button: MouseButton,
+    },
+    Scroll {
+        direction: ScrollDirection,
+        amount: i32,
+    },
+    ScrollPixels {
+        direction: ScrollDirection,
+        pixels: i32,
+    },
+    ReleaseScroll {
+        direction: ScrollDirection,
+    },
+    ScrollPage {
+        direction: ScrollDirection,
+    },
+    SetSpeed {
+        speed: MovementSpeed,
+    },
+}
+
+/// Wraps a mouse backend and records the operations sent to it
+///
+/// Recording is off until enabled, so a long-running host does not collect
+/// operations nobody reads.
+#[derive(Debug)]
+pub struct RecordingMouse<M> {
+    inner: M,
+    recording: bool,
+    ops: Vec<MouseOp>,
+}
+
+impl<M: MouseOperations> RecordingMouse<M> {
+    /// Wrap a mouse backend
+    pub fn new(inner: M) -> Self {
+        Self {
+            inner,
+            recording: false,
+            ops: Vec::new(),
+        }
+    }
+
+    /// Get the wrapped backend
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Get the wrapped backend mutably
+    pub fn inner_mut(&mut self) -> &mut M {
+        &mut self.inner
+    }
+
+    /// Turn recording on or off; turning it off drops unread operations
+    pub fn set_recording(&mut self, recording: bool) {
+        self.recording = recording;
+        if !recording {
+            self.ops.clear();
+        }
+    }
+
+    /// Check if operations are being recorded
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Take the operations recorded since the last call
+    pub fn take_ops(&mut self) -> Vec<MouseOp> {
+        std::mem::take(&mut self.ops)
+    }
+
+    fn record(&mut self, op: MouseOp) {
+        if self.recording {
+            self.ops.push(op);
+        }
+    }
+}
+
+impl<M: MouseOperations> MouseOperations for RecordingMouse<M> {
+    fn move_to(&mut self, position: Position, animation: AnimationType) -> MouseResult<()> {
+        self.record(MouseOp::MoveTo {
+            position,
+            animation,
+        });
+        self.inner.move_to(position, animation)
+    }
+
+    fn move_relative(
+        &mut self,
+        dx: i32,
+        dy: i32,
+        animation: AnimationType,
+    ) -> MouseResult<Position> {
+        self.record(MouseOp::MoveRelative { dx, dy, animation });
+        self.inner.move_relative(dx, dy, animation)
+    }
+
+    fn click(&mut self, button: MouseButton) -> MouseResult<()> {
+        self.record(MouseOp::Click { button });
+        self.inner.click(button)
+    }
+
+    fn scroll(&mut self, direction: ScrollDirection, amount: i32) -> MouseResult<()> {
+        self.record(MouseOp::Scroll { direction, amount });
+        self.inner.scroll(direction, amount)
+    }
+
+    fn supports_pixel_scroll(&self) -> bool {
+        self.inner.supports_pixel_scroll()
+    }
+
+    fn scroll_pixels(&mut self, direction: ScrollDirection, pixels: i32) -> MouseResult<()> {
+        self.record(MouseOp::ScrollPixels { direction, pixels });
+        self.inner.scroll_pixels(direction, pixels)
+    }
+
+    fn release_scroll(&mut self, direction: ScrollDirection) -> MouseResult<()> {
+        self.record(MouseOp::ReleaseScroll { direction });
+        self.inner.release_scroll(direction)
+    }
+
+    fn glides_scrolls(&self) -> bool {
+        self.inner.glides_scrolls()
+    }
+
+    fn scroll_page(&mut self, direction: ScrollDirection) -> MouseResult<()> {
+        self.record(MouseOp::ScrollPage { direction });
+        self.inner.scroll_page(direction)
+    }
+
+    fn get_current_position(&self) -> MouseResult<Position> {
+        self.inner.get_current_position()
+    }
+
+    fn get_screen_bounds(&self) -> MouseResult<Vec<ScreenBounds>> {
+        self.inner.get_screen_bounds()
+    }
+
+    fn set_movement_speed(&mut self, speed: MovementSpeed) {
+        self.record(MouseOp::SetSpeed { speed });
+        self.inner.set_movement_speed(speed)
+    }
+
+    fn get_movement_speed(&self) -> MovementSpeed {
+        self.inner.get_movement_speed()
+    }
+}
+
+/// A mouse that only exists in memory; moves are instant
+///
+/// Like the `MouseService` it takes scrolls as they come, so a replayed
+/// session sends the same scroll operations as the recorded one.
+#[derive(Debug, Clone)]
+pub struct VirtualMouse {
+    position: Position,
+    screens: Vec<ScreenBounds>,
+    speed: MovementSpeed,
+}
+
+impl VirtualMouse {
+    /// Create a mouse at a position on a set of screens
+    pub fn new(position: Position, screens: Vec<ScreenBounds>) -> Self {
+        Self {
+            position,
+            screens,
+            speed: MovementSpeed::Normal,
+        }
+    }
+
+    /// Put the cursor at a position without recording a move
+    pub fn set_position(&mut self, position: Position) {
+        self.position = position;
+    }
+
+    /// Replace the screens
+    pub fn set_screens(&mut self, screens: Vec<ScreenBounds>) {
+        self.screens = screens;
+    }
+}
+
+impl Default for VirtualMouse {
+    fn default() -> Self {
+        Self::new(Position::new(0, 0), Vec::new())
+    }
+}
+
+impl MouseOperations for VirtualMouse {
+    fn move_to(&mut self, position: Position, _animation: AnimationType) -> MouseResult<()> {
+        self.position = position;
+        Ok(())
+    }
+
+    fn click(&mut self, _button: MouseButton) -> MouseResult<()> {
+        Ok(())
+    }
+
+    fn scroll(&mut self, _direction: ScrollDirection, _amount: i32) -> MouseResult<()> {
+        Ok(())
+    }
+
+    fn glides_scrolls(&self) -> bool {
+        true
+    }
+
+    fn scroll_page(&mut self, _direction: ScrollDirection) -> MouseResult<()> {
+        Ok(())
+    }
+
+    fn get_current_position(&self) -> MouseResult<Position> {
+        Ok(self.position)
+    }
+
+    fn get_screen_bounds(&self) -> MouseResult<Vec<ScreenBounds>> {
+        Ok(self.screens.clone())
+    }
+
+    fn set_movement_speed(&mut self, speed: MovementSpeed) {
+        self.speed = speed;
+    }
+
+    fn get_movement_speed(&self) -> MovementSpeed {
+        self.speed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_only_when_enabled() {
+        let mut mouse = RecordingMouse::new(VirtualMouse::default());
+        mouse
+            .move_to(Position::new(10, 20), AnimationType::Instant)
+            .unwrap();
+        assert!(mouse.take_ops().is_empty());
+
+        mouse.set_recording(true);
+        mouse
+            .move_to(Position::new(30, 40), AnimationType::Linear)
+            .unwrap();
+        mouse.click(MouseButton::Left).unwrap();
+
+        assert_eq!(
+            mouse.take_ops(),
+            vec![
+                MouseOp::MoveTo {
+                    position: Position::new(30, 40),
+                    animation: AnimationType::Linear,
+                },
+                MouseOp::Click {
+                    button: MouseButton::Left
+                },
+            ]
+        );
+        assert!(mouse.take_ops().is_empty());
+        assert_eq!(mouse.get_current_position().unwrap(), Position::new(30, 40));
+    }
+}
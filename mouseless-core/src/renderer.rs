@@ -0,0 +1,556 @@
+//! Renderer module
+//!
+//! This module provides functionality for:
+//! - Running the core without an overlay window
+//! - Logging the UI feedback a headless front end cannot show
+//! - Drawing overlays as text, scaled from the screen bounds, for terminals and snapshot tests
+
+use std::io::Write;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use tracing::debug;
+
+use crate::{
+    error::{UIError, UIResult},
+    grid::GridCell,
+    models::{
+        AnimationType, Area, GridConfig, InteractionMode, Position, PredictionTarget, ScreenBounds,
+    },
+    screen::ScreenManager,
+    traits::{ModeOverlay, Theme, UIRenderer},
+};
+
+/// Clears a terminal and moves the cursor to its top left corner
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// A renderer that draws nothing, for headless front ends like the daemon
+///
+/// Every call succeeds and is logged at debug level.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullRenderer;
+
+impl NullRenderer {
+    /// Create a new null renderer
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl UIRenderer for NullRenderer {
+    async fn show_grid_overlay(
+        &self,
+        grid_config: GridConfig,
+        cells: Vec<GridCell>,
+    ) -> UIResult<()> {
+        debug!(
+            "Grid overlay: {}x{}, {} cells",
+            grid_config.rows,
+            grid_config.columns,
+            cells.len()
+        );
+        Ok(())
+    }
+
+    async fn show_area_overlay(&self, areas: Vec<Area>) -> UIResult<()> {
+        debug!("Area overlay: {} areas", areas.len());
+        Ok(())
+    }
+
+    async fn show_prediction_targets(&self, targets: Vec<PredictionTarget>) -> UIResult<()> {
+        debug!("Prediction targets: {}", targets.len());
+        Ok(())
+    }
+
+    async fn animate_cursor_movement(
+        &self,
+        from: Position,
+        to: Position,
+        animation: AnimationType,
+    ) -> UIResult<()> {
+        debug!(
+            "Cursor movement: ({}, {}) -> ({}, {}) {:?}",
+            from.x, from.y, to.x, to.y, animation
+        );
+        Ok(())
+    }
+
+    async fn hide_all_overlays(&self) -> UIResult<()> {
+        debug!("Overlays hidden");
+        Ok(())
+    }
+
+    async fn show_mode_indicator(&self, mode: InteractionMode) -> UIResult<()> {
+        debug!("Mode indicator: {:?}", mode);
+        Ok(())
+    }
+
+    async fn hide_mode_indicator(&self) -> UIResult<()> {
+        debug!("Mode indicator hidden");
+        Ok(())
+    }
+
+    async fn update_theme(&self, theme: Theme) -> UIResult<()> {
+        debug!("Theme: {}", theme.name);
+        Ok(())
+    }
+
+    async fn highlight_area(&self, key: char) -> UIResult<()> {
+        debug!("Area highlighted: {}", key);
+        Ok(())
+    }
+}
+
+/// A renderer that draws overlays as text
+///
+/// The desktop is scaled onto a canvas of `columns` by `rows` characters:
+/// screens are boxes, grid cells and areas are boxes with their labels in
+/// the middle, prediction targets are `[key]`, the cursor is `*`, and the
+/// mode indicator is a line below. `render` returns the current frame; with
+/// an output, every changed frame is also written to it.
+pub struct TextRenderer {
+    state: Mutex<TextOverlay>,
+    output: Option<Mutex<TextOutput>>,
+}
+
+struct TextOutput {
+    writer: Box<dyn Write + Send>,
+    clear_screen: bool,
+    last_frame: String,
+}
+
+#[derive(Default)]
+struct TextOverlay {
+    columns: usize,
+    rows: usize,
+    screens: Vec<ScreenBounds>,
+    overlay: ModeOverlay,
+    highlighted: Option<char>,
+    mode: Option<InteractionMode>,
+    cursor: Option<Position>,
+}
+
+impl TextRenderer {
+    /// Create a renderer with a canvas of `columns` by `rows` characters
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            state: Mutex::new(TextOverlay {
+                columns: columns.max(2),
+                rows: rows.max(2),
+                ..Default::default()
+            }),
+            output: None,
+        }
+    }
+
+    /// Write every changed frame to an output, clearing it first if it is a terminal
+    pub fn with_output<W: Write + Send + 'static>(mut self, writer: W, clear_screen: bool) -> Self {
+        self.output = Some(Mutex::new(TextOutput {
+            writer: Box::new(writer),
+            clear_screen,
+            last_frame: String::new(),
+        }));
+        self
+    }
+
+    /// Set the screens the canvas is scaled from
+    pub fn set_screens(&self, screens: Vec<ScreenBounds>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.screens = screens;
+        }
+    }
+
+    /// Draw the current frame
+    pub fn render(&self) -> String {
+        self.state
+            .lock()
+            .map(|state| state.render())
+            .unwrap_or_default()
+    }
+
+    /// Change the state and write the frame if it changed
+    fn update(&self, change: impl FnOnce(&mut TextOverlay)) -> UIResult<()> {
+        if let Ok(mut state) = self.state.lock() {
+            change(&mut state);
+        }
+        let Some(output) = &self.output else {
+            return Ok(());
+        };
+
+        let frame = self.render();
+        let mut output = output.lock().map_err(|_| UIError::RenderingFailed {
+            component: "text overlay".to_string(),
+        })?;
+        if frame == output.last_frame {
+            return Ok(());
+        }
+
+        let clear = if output.clear_screen {
+            CLEAR_SCREEN
+        } else {
+            ""
+        };
+        writeln!(output.writer, "{}{}", clear, frame)
+            .and_then(|_| output.writer.flush())
+            .map_err(|e| UIError::RenderingFailed {
+                component: format!("text overlay: {}", e),
+            })?;
+        output.last_frame = frame;
+        Ok(())
+    }
+}
+
+impl TextOverlay {
+    fn render(&self) -> String {
+        let mut lines = Vec::new();
+
+        if let Ok(desktop) = ScreenManager::desktop_bounds_of(&self.screens) {
+            let mut canvas = Canvas::new(self.columns, self.rows, desktop);
+            for screen in &self.screens {
+                canvas.draw_box(screen.x, screen.y, screen.width, screen.height);
+            }
+
+            match &self.overlay {
+                ModeOverlay::Grid(config, cells) => Self::draw_grid(&mut canvas, config, cells),
+                ModeOverlay::Areas(areas) => {
+                    for area in areas {
+                        let bounds = &area.bounds;
+                        canvas.draw_box(bounds.x, bounds.y, bounds.width, bounds.height);
+                    }
+                    for area in areas {
+                        let label = if area.label.is_empty() {
+                            area.key.to_uppercase().to_string()
+                        } else {
+                            area.label.clone()
+                        };
+                        if self.highlighted == Some(area.key) {
+                            canvas.draw_label(area.center, &format!("[{}]", label));
+                        } else {
+                            canvas.draw_label(area.center, &label);
+                        }
+                    }
+                }
+                ModeOverlay::Targets(targets) => {
+                    for target in targets {
+                        canvas.draw_label(target.position, &format!("[{}]", target.shortcut_key));
+                    }
+                }
+                ModeOverlay::None => {}
+            }
+
+            if let Some(cursor) = self.cursor {
+                canvas.draw_label(cursor, "*");
+            }
+            lines.extend(canvas.lines());
+        }
+
+        if let Some(mode) = &self.mode {
+            lines.push(format!("-- {} --", mode.name().to_uppercase()));
+        }
+        lines.join("\n")
+    }
+
+    fn draw_grid(canvas: &mut Canvas, config: &GridConfig, cells: &[GridCell]) {
+        for cell in cells {
+            let bounds = &cell.bounds;
+            canvas.draw_box(bounds.x, bounds.y, bounds.width, bounds.height);
+        }
+        if config.show_labels {
+            for cell in cells {
+                canvas.draw_label(cell.center_position, &cell.key_combination);
+            }
+        }
+    }
+}
+
+/// Characters the desktop is scaled onto
+struct Canvas {
+    cells: Vec<Vec<char>>,
+    desktop: (i32, i32, u32, u32),
+}
+
+impl Canvas {
+    fn new(columns: usize, rows: usize, desktop: (i32, i32, u32, u32)) -> Self {
+        Self {
+            cells: vec![vec![' '; columns]; rows],
+            desktop,
+        }
+    }
+
+    fn columns(&self) -> usize {
+        self.cells[0].len()
+    }
+
+    fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Scale a desktop coordinate onto `cells` characters; the far edge maps to the last one
+    fn scale(value: i32, origin: i32, size: u32, cells: usize) -> usize {
+        let fraction = (value - origin) as f64 / size.max(1) as f64;
+        let cell = (fraction * (cells - 1) as f64).round();
+        cell.clamp(0.0, (cells - 1) as f64) as usize
+    }
+
+    fn column(&self, x: i32) -> usize {
+        Self::scale(x, self.desktop.0, self.desktop.2, self.columns())
+    }
+
+    fn row(&self, y: i32) -> usize {
+        Self::scale(y, self.desktop.1, self.desktop.3, self.rows())
+    }
+
+    /// Draw the outline of a rectangle; boxes that touch share their edges
+    fn draw_box(&mut self, x: i32, y: i32, width: u32, height: u32) {
+        let (left, right) = (self.column(x), self.column(x + width as i32));
+        let (top, bottom) = (self.row(y), self.row(y + height as i32));
+
+        for column in left..=right {
+            self.draw_edge(top, column, '-');
+            self.draw_edge(bottom, column, '-');
+        }
+        for row in top..=bottom {
+            self.draw_edge(row, left, '|');
+            self.draw_edge(row, right, '|');
+        }
+        for (row, column) in [(top, left), (top, right), (bottom, left), (bottom, right)] {
+            self.cells[row][column] = '+';
+        }
+    }
+
+    /// Draw a line character, turning crossings into corners
+    fn draw_edge(&mut self, row: usize, column: usize, line: char) {
+        let cell = &mut self.cells[row][column];
+        *cell = match *cell {
+            ' ' => line,
+            existing if existing == line => line,
+            _ => '+',
+        };
+    }
+
+    /// Draw text centered on a desktop position, kept inside the canvas
+    fn draw_label(&mut self, position: Position, text: &str) {
+        let row = self.row(position.y);
+        let width = text.chars().count().min(self.columns());
+        let start = self
+            .column(position.x)
+            .saturating_sub(width / 2)
+            .min(self.columns() - width);
+        for (offset, c) in text.chars().take(width).enumerate() {
+            self.cells[row][start + offset] = c;
+        }
+    }
+
+    fn lines(&self) -> Vec<String> {
+        self.cells
+            .iter()
+            .map(|row| row.iter().collect::<String>().trim_end().to_string())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl UIRenderer for TextRenderer {
+    async fn show_grid_overlay(
+        &self,
+        grid_config: GridConfig,
+        cells: Vec<GridCell>,
+    ) -> UIResult<()> {
+        self.update(|state| {
+            state.overlay = ModeOverlay::Grid(grid_config, cells);
+            state.highlighted = None;
+        })
+    }
+
+    async fn show_area_overlay(&self, areas: Vec<Area>) -> UIResult<()> {
+        self.update(|state| {
+            // Redrawing the same areas keeps the highlight
+            state.highlighted = state
+                .highlighted
+                .filter(|key| areas.iter().any(|area| area.key == *key));
+            state.overlay = ModeOverlay::Areas(areas);
+        })
+    }
+
+    async fn show_prediction_targets(&self, targets: Vec<PredictionTarget>) -> UIResult<()> {
+        self.update(|state| {
+            state.overlay = ModeOverlay::Targets(targets);
+            state.highlighted = None;
+        })
+    }
+
+    async fn animate_cursor_movement(
+        &self,
+        _from: Position,
+        to: Position,
+        _animation: AnimationType,
+    ) -> UIResult<()> {
+        self.update(|state| state.cursor = Some(to))
+    }
+
+    async fn hide_all_overlays(&self) -> UIResult<()> {
+        self.update(|state| {
+            state.overlay = ModeOverlay::None;
+            state.highlighted = None;
+        })
+    }
+
+    async fn show_mode_indicator(&self, mode: InteractionMode) -> UIResult<()> {
+        self.update(|state| state.mode = Some(mode))
+    }
+
+    async fn hide_mode_indicator(&self) -> UIResult<()> {
+        self.update(|state| state.mode = None)
+    }
+
+    async fn update_theme(&self, theme: Theme) -> UIResult<()> {
+        debug!("Text overlay ignores theme {}", theme.name);
+        Ok(())
+    }
+
+    async fn highlight_area(&self, key: char) -> UIResult<()> {
+        self.update(|state| state.highlighted = Some(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid::GridManager, keyboard_layout::KeyboardLayout, models::MultiScreenLayout};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_null_renderer_accepts_everything() {
+        let renderer = NullRenderer::new();
+
+        assert!(renderer
+            .show_mode_indicator(InteractionMode::Basic)
+            .await
+            .is_ok());
+        assert!(renderer
+            .animate_cursor_movement(
+                Position::new(0, 0),
+                Position::new(100, 100),
+                AnimationType::Smooth
+            )
+            .await
+            .is_ok());
+        assert!(renderer.show_area_overlay(Vec::new()).await.is_ok());
+        assert!(renderer.hide_all_overlays().await.is_ok());
+        assert!(renderer.hide_mode_indicator().await.is_ok());
+        assert!(renderer.update_theme(Theme::default()).await.is_ok());
+    }
+
+    fn screen(id: u32, x: i32, width: u32, height: u32) -> ScreenBounds {
+        ScreenBounds {
+            id,
+            x,
+            y: 0,
+            width,
+            height,
+            is_primary: id == 1,
+            scale_factor: 1.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_text_renderer_draws_grid() {
+        let renderer = TextRenderer::new(31, 7);
+        let screens = vec![screen(1, 0, 1920, 1080)];
+        renderer.set_screens(screens.clone());
+        let config = GridConfig {
+            rows: 2,
+            columns: 3,
+            ..GridConfig::default()
+        };
+        let grid = GridManager::for_screens(
+            config.clone(),
+            screens,
+            MultiScreenLayout::default(),
+            KeyboardLayout::qwerty(),
+        )
+        .unwrap();
+        // Labels are drawn as the grid assigned them, not recomputed
+        let mut cells = grid.get_cells().to_vec();
+        cells[5].key_combination = "zz".to_string();
+        renderer.show_grid_overlay(config, cells).await.unwrap();
+        renderer
+            .show_mode_indicator(InteractionMode::Grid)
+            .await
+            .unwrap();
+        renderer
+            .animate_cursor_movement(
+                Position::new(0, 0),
+                Position::new(1700, 900),
+                AnimationType::Instant,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            renderer.render(),
+            [
+                "+---------+---------+---------+",
+                "|         |         |         |",
+                "|   aq    |   aw    |   ae    |",
+                "+---------+---------+---------+",
+                "|         |         |         |",
+                "|   ar    |   at    |   zz *  |",
+                "+---------+---------+---------+",
+                "-- GRID --",
+            ]
+            .join("\n")
+        );
+    }
+
+    /// An output that can still be read after the renderer took it
+    #[derive(Clone, Default)]
+    struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_text_renderer_draws_areas_across_screens() {
+        let output = SharedOutput::default();
+        let renderer = TextRenderer::new(41, 5).with_output(output.clone(), false);
+        let screens = vec![screen(1, 0, 1920, 1080), screen(2, 1920, 1280, 1080)];
+        renderer.set_screens(screens.clone());
+        let areas = screens
+            .iter()
+            .zip(['a', 's'])
+            .map(|(screen, key)| Area {
+                key,
+                bounds: screen.clone(),
+                center: screen.center(),
+                label: key.to_uppercase().to_string(),
+            })
+            .collect();
+        renderer.show_area_overlay(areas).await.unwrap();
+        renderer.highlight_area('s').await.unwrap();
+
+        let frame = [
+            "+-----------------------+---------------+",
+            "|                       |               |",
+            "|           A           |      [S]      |",
+            "|                       |               |",
+            "+-----------------------+---------------+",
+        ]
+        .join("\n");
+        assert_eq!(renderer.render(), frame);
+
+        // Only changed frames are written
+        renderer.highlight_area('s').await.unwrap();
+        let written = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(written.matches("[S]").count(), 1);
+        assert!(written.ends_with(&format!("{}\n", frame)));
+    }
+}
@@ -1,26 +1,156 @@
+use std::sync::{Arc, OnceLock, RwLock};
+use tokio::sync::broadcast;
+
 use crate::{
     error::{MouseError, MouseResult},
-    models::{Position, ScreenBounds},
+    models::{PhysicalPosition, Position, ScreenBounds, ScreenTarget},
+    screen_events::ScreenChangeEvent,
+    screen_provider::{ScreenInfo, ScreenProvider, StaticScreenProvider},
 };
 
+/// Environment variable naming a static screen layout file
+pub const SCREEN_LAYOUT_ENV: &str = "MOUSELESS_SCREEN_LAYOUT";
+
+struct ScreenState {
+    provider: Option<Arc<dyn ScreenProvider>>,
+    screens: Option<Vec<ScreenInfo>>,
+    event_sender: broadcast::Sender<ScreenChangeEvent>,
+}
+
+impl ScreenState {
+    fn new() -> Self {
+        let (event_sender, _) = broadcast::channel(16);
+        Self {
+            provider: None,
+            screens: None,
+            event_sender,
+        }
+    }
+}
+
+fn screen_state() -> &'static RwLock<ScreenState> {
+    static STATE: OnceLock<RwLock<ScreenState>> = OnceLock::new();
+    STATE.get_or_init(|| RwLock::new(ScreenState::new()))
+}
+
 /// Screen detection and management utilities
+///
+/// Screens are enumerated once through the active [`ScreenProvider`] and
+/// cached. [`ScreenManager::refresh`] re-enumerates them and publishes a
+/// [`ScreenChangeEvent`] to subscribers when the layout changed.
 pub struct ScreenManager;
 
 impl ScreenManager {
-    /// Detect all connected screens and their bounds
-    pub fn detect_screens() -> MouseResult<Vec<ScreenBounds>> {
-        #[cfg(target_os = "macos")]
-        {
-            Self::detect_screens_macos()
+    /// Replace the screen provider and re-enumerate the screens
+    pub fn set_provider(provider: Arc<dyn ScreenProvider>) -> MouseResult<Vec<ScreenInfo>> {
+        Self::set_provider_of(screen_state(), provider)
+    }
+
+    fn set_provider_of(
+        state: &RwLock<ScreenState>,
+        provider: Arc<dyn ScreenProvider>,
+    ) -> MouseResult<Vec<ScreenInfo>> {
+        if let Ok(mut state) = state.write() {
+            state.provider = Some(provider);
         }
+        Self::refresh_state(state)
+    }
 
-        #[cfg(not(target_os = "macos"))]
-        {
-            // Fallback for other platforms
-            Self::detect_screens_fallback()
+    /// Subscribe to changes of the connected screens
+    pub fn subscribe() -> broadcast::Receiver<ScreenChangeEvent> {
+        Self::subscribe_to(screen_state())
+    }
+
+    fn subscribe_to(state: &RwLock<ScreenState>) -> broadcast::Receiver<ScreenChangeEvent> {
+        match state.read() {
+            Ok(state) => state.event_sender.subscribe(),
+            Err(poisoned) => poisoned.into_inner().event_sender.subscribe(),
         }
     }
 
+    /// Re-enumerate the screens through the active provider
+    ///
+    /// When enumeration fails the previously detected screens are kept, or a
+    /// single 1920x1080 screen is assumed if there are none.
+    pub fn refresh() -> MouseResult<Vec<ScreenInfo>> {
+        Self::refresh_state(screen_state())
+    }
+
+    /// Re-enumerate the screens of `state`; the global state backs the
+    /// public API, tests use their own
+    fn refresh_state(state: &RwLock<ScreenState>) -> MouseResult<Vec<ScreenInfo>> {
+        let (provider, previous) = match state.read() {
+            Ok(state) => (state.provider.clone(), state.screens.clone()),
+            Err(_) => (None, None),
+        };
+        let provider = provider.unwrap_or_else(Self::default_provider);
+
+        let screens = match provider.screens() {
+            Ok(screens) => screens,
+            Err(e) => match previous.clone() {
+                Some(screens) => {
+                    tracing::warn!("Screen detection through {} failed: {}", provider.name(), e);
+                    screens
+                }
+                None => {
+                    tracing::warn!(
+                        "Screen detection through {} failed, assuming a single screen: {}",
+                        provider.name(),
+                        e
+                    );
+                    StaticScreenProvider::fallback().screens()?
+                }
+            },
+        };
+
+        tracing::debug!(
+            "Detected {} screen(s) through {}",
+            screens.len(),
+            provider.name()
+        );
+
+        if let Ok(mut state) = state.write() {
+            state.provider = Some(provider);
+            let previous = state.screens.replace(screens.clone());
+
+            if let Some(event) =
+                previous.and_then(|previous| ScreenChangeEvent::between(previous, screens.clone()))
+            {
+                tracing::info!(
+                    "Screen layout changed: {} added, {} removed, {} reconfigured",
+                    event.added().len(),
+                    event.removed().len(),
+                    event.reconfigured().len()
+                );
+                let _ = state.event_sender.send(event);
+            }
+        }
+
+        Ok(screens)
+    }
+
+    /// Get detailed information about all connected screens
+    pub fn screen_info() -> MouseResult<Vec<ScreenInfo>> {
+        Self::screen_info_of(screen_state())
+    }
+
+    fn screen_info_of(state: &RwLock<ScreenState>) -> MouseResult<Vec<ScreenInfo>> {
+        let cached = state.read().ok().and_then(|state| state.screens.clone());
+
+        match cached {
+            Some(screens) => Ok(screens),
+            None => Self::refresh_state(state),
+        }
+    }
+
+    /// Detect all connected screens and their bounds
+    pub fn detect_screens() -> MouseResult<Vec<ScreenBounds>> {
+        Ok(Self::screen_info()?
+            .iter()
+            .map(ScreenInfo::bounds)
+            .collect())
+    }
+
     /// Get the primary screen
     pub fn get_primary_screen() -> MouseResult<ScreenBounds> {
         let screens = Self::detect_screens()?;
@@ -70,66 +200,48 @@ impl ScreenManager {
             })
     }
 
-    #[cfg(target_os = "macos")]
-    fn detect_screens_macos() -> MouseResult<Vec<ScreenBounds>> {
-        // For now, implement a basic version
-        // In a full implementation, this would use CoreGraphics APIs:
-        // - CGDisplayBounds() to get screen bounds
-        // - CGGetActiveDisplayList() to enumerate displays
-        // - CGDisplayIsMain() to identify primary display
-
-        // This is a simplified implementation that assumes common screen setups
-        let mut screens = Vec::new();
+    /// Get screen by connector name, such as `eDP-1`
+    pub fn get_screen_by_name(name: &str) -> MouseResult<ScreenInfo> {
+        Self::screen_info()?
+            .into_iter()
+            .find(|s| s.name == name)
+            .ok_or_else(|| MouseError::ScreenDetectionFailed {
+                reason: format!("Screen named '{}' not found", name),
+            })
+    }
 
-        // Primary screen (usually the built-in display)
-        //TODO: Implement actual macOS screen detection using CoreGraphics APIs
-        //TODO: Use CGDisplayBounds() to get real screen dimensions
-        //TODO: Use CGGetActiveDisplayList() to enumerate all displays
-        //TODO: Use CGDisplayIsMain() to identify primary display
-        screens.push(ScreenBounds {
-            id: 0,
-            x: 0,
-            y: 0,
-            width: 1920,  //TODO: Get actual screen width from system
-            height: 1080, //TODO: Get actual screen height from system
-            is_primary: true,
-        });
-
-        // Check for common multi-monitor setups
-        //TODO: Replace this placeholder with real multi-monitor detection
-        //TODO: Query system for actual connected displays and their positions
-        if std::env::var("MOUSELESS_MULTI_MONITOR").is_ok() {
-            // Add a second monitor to the right
-            screens.push(ScreenBounds {
-                id: 1,
-                x: 1920, //TODO: Get actual position from system
-                y: 0,    //TODO: Get actual position from system
-                width: 1920,  //TODO: Get actual width from system
-                height: 1080, //TODO: Get actual height from system
-                is_primary: false,
-            });
+    /// Pick the provider for this platform
+    ///
+    /// A layout file named by `MOUSELESS_SCREEN_LAYOUT` takes precedence.
+    fn default_provider() -> Arc<dyn ScreenProvider> {
+        if let Some(path) = std::env::var_os(SCREEN_LAYOUT_ENV) {
+            match StaticScreenProvider::from_file(&path) {
+                Ok(provider) => return Arc::new(provider),
+                Err(e) => tracing::warn!("Ignoring {}: {}", SCREEN_LAYOUT_ENV, e),
+            }
         }
 
-        Ok(screens)
-    }
+        #[cfg(target_os = "linux")]
+        {
+            Arc::new(crate::screen_provider::LinuxScreenProvider)
+        }
 
-    #[cfg(not(target_os = "macos"))]
-    fn detect_screens_fallback() -> MouseResult<Vec<ScreenBounds>> {
-        // Basic fallback implementation for non-macOS platforms
-        Ok(vec![ScreenBounds {
-            id: 0,
-            x: 0,
-            y: 0,
-            width: 1920,
-            height: 1080,
-            is_primary: true,
-        }])
+        #[cfg(not(target_os = "linux"))]
+        {
+            //TODO: Implement a CoreGraphics provider for macOS using
+            //TODO: CGGetActiveDisplayList(), CGDisplayBounds() and CGDisplayIsMain()
+            Arc::new(StaticScreenProvider::fallback())
+        }
     }
 
     /// Calculate the total desktop bounds across all screens
     pub fn get_desktop_bounds() -> MouseResult<(i32, i32, u32, u32)> {
         let screens = Self::detect_screens()?;
+        Self::desktop_bounds_of(&screens)
+    }
 
+    /// Calculate the bounding box of a set of screens
+    pub fn desktop_bounds_of(screens: &[ScreenBounds]) -> MouseResult<(i32, i32, u32, u32)> {
         if screens.is_empty() {
             return Err(MouseError::ScreenDetectionFailed {
                 reason: "No screens detected".to_string(),
@@ -152,6 +264,241 @@ impl ScreenManager {
         Ok((min_x, min_y, (max_x - min_x) as u32, (max_y - min_y) as u32))
     }
 
+    /// Place a global position on the screen containing it
+    ///
+    /// Positions in gaps between screens are clamped onto the nearest screen,
+    /// so the result always carries a `screen_id`.
+    pub fn place_on_screens(screens: &[ScreenBounds], position: Position) -> Position {
+        if let Some(screen) = screens.iter().find(|s| s.contains(position)) {
+            return Position::with_screen(position.x, position.y, screen.id);
+        }
+
+        screens
+            .iter()
+            .map(|screen| screen.clamp(position))
+            .min_by_key(|clamped| {
+                let dx = (clamped.x - position.x) as i64;
+                let dy = (clamped.y - position.y) as i64;
+                dx * dx + dy * dy
+            })
+            .unwrap_or(position)
+    }
+
+    /// Convert a logical position to physical pixels on the screen showing it
+    ///
+    /// Positions without a screen id, or off every screen, are first placed
+    /// on the nearest screen.
+    pub fn to_physical(screens: &[ScreenBounds], position: Position) -> Option<PhysicalPosition> {
+        let index = position
+            .screen_id
+            .and_then(|id| screens.iter().position(|s| s.id == id))
+            .or_else(|| {
+                let placed = Self::place_on_screens(screens, position);
+                screens.iter().position(|s| Some(s.id) == placed.screen_id)
+            })?;
+        let screen = &screens[index];
+        let origin = Self::physical_origins(screens)[index];
+
+        Some(screen.to_physical(origin, screen.clamp(position)))
+    }
+
+    /// Convert a physical position to logical pixels
+    pub fn to_logical(screens: &[ScreenBounds], position: PhysicalPosition) -> Option<Position> {
+        let index = screens.iter().position(|s| s.id == position.screen_id)?;
+        let origin = Self::physical_origins(screens)[index];
+        Some(screens[index].to_logical(origin, position))
+    }
+
+    /// Find the screen showing a global physical pointer position
+    pub fn find_screen_for_physical(
+        screens: &[ScreenBounds],
+        x: i32,
+        y: i32,
+    ) -> Option<&ScreenBounds> {
+        Self::physical_origins(screens)
+            .into_iter()
+            .zip(screens)
+            .find(|(origin, s)| s.contains_physical(*origin, PhysicalPosition::new(x, y, s.id)))
+            .map(|(_, s)| s)
+    }
+
+    /// Place the screens in physical pixel space
+    ///
+    /// Returns the physical origin of every screen, in order. The screen at
+    /// the logical origin (or the primary screen) keeps its logical origin;
+    /// every other screen is placed off its nearest already placed neighbour
+    /// at that neighbour's physical size, so neighbouring screens do not
+    /// overlap in physical space. Gaps between screens are kept in logical pixels.
+    pub fn physical_origins(screens: &[ScreenBounds]) -> Vec<(i32, i32)> {
+        let root = screens
+            .iter()
+            .position(|s| s.x == 0 && s.y == 0)
+            .or_else(|| screens.iter().position(|s| s.is_primary))
+            .unwrap_or(0);
+
+        let mut origins: Vec<Option<(i32, i32)>> = vec![None; screens.len()];
+        if let Some(screen) = screens.get(root) {
+            origins[root] = Some((screen.x, screen.y));
+        }
+
+        for _ in 1..screens.len() {
+            let placed = &origins;
+            let next = (0..screens.len())
+                .filter(|&from| placed[from].is_some())
+                .flat_map(|from| {
+                    (0..screens.len())
+                        .filter(move |&to| placed[to].is_none())
+                        .map(move |to| (from, to))
+                })
+                .min_by_key(|&(from, to)| Self::logical_gap(&screens[from], &screens[to]));
+
+            let Some((from, to)) = next else { break };
+            let (from_screen, to_screen) = (&screens[from], &screens[to]);
+            let origin = origins[from].unwrap_or((from_screen.x, from_screen.y));
+            let (from_width, from_height) = from_screen.physical_size();
+            let (to_width, to_height) = to_screen.physical_size();
+
+            origins[to] = Some((
+                Self::physical_start(
+                    (from_screen.x, from_screen.width, from_width, origin.0),
+                    (to_screen.x, to_screen.width, to_width),
+                    from_screen.scale_factor,
+                ),
+                Self::physical_start(
+                    (from_screen.y, from_screen.height, from_height, origin.1),
+                    (to_screen.y, to_screen.height, to_height),
+                    from_screen.scale_factor,
+                ),
+            ));
+        }
+
+        origins
+            .into_iter()
+            .zip(screens)
+            .map(|(origin, s)| origin.unwrap_or((s.x, s.y)))
+            .collect()
+    }
+
+    /// Distance between two screens in logical pixels, zero when they touch
+    fn logical_gap(a: &ScreenBounds, b: &ScreenBounds) -> i64 {
+        let gap = |a_start: i32, a_len: u32, b_start: i32, b_len: u32| {
+            let (a_start, b_start) = (a_start as i64, b_start as i64);
+            (b_start - (a_start + a_len as i64))
+                .max(a_start - (b_start + b_len as i64))
+                .max(0)
+        };
+
+        gap(a.x, a.width, b.x, b.width) + gap(a.y, a.height, b.y, b.height)
+    }
+
+    /// Physical start of a screen along one axis, placed off a neighbour
+    ///
+    /// `from` is the neighbour's logical start, logical length, physical
+    /// length and physical start; `to` the screen's logical start, logical
+    /// length and physical length.
+    fn physical_start(from: (i32, u32, u32, i32), to: (i32, u32, u32), scale_factor: f64) -> i32 {
+        let (from_start, from_len, from_physical_len, from_physical_start) = from;
+        let (to_start, to_len, to_physical_len) = to;
+        let from_end = from_start + from_len as i32;
+
+        if to_start >= from_end {
+            // After the neighbour, keeping any gap
+            from_physical_start + from_physical_len as i32 + (to_start - from_end)
+        } else if to_start + to_len as i32 <= from_start {
+            // Before the neighbour, keeping any gap
+            from_physical_start - (from_start - (to_start + to_len as i32)) - to_physical_len as i32
+        } else {
+            // Overlapping along this axis: offset within the neighbour
+            from_physical_start + ((to_start - from_start) as f64 * scale_factor).round() as i32
+        }
+    }
+
+    /// Resolve the screen a switch from the screen `from_id` lands on
+    ///
+    /// Next and previous follow the screen numbering and wrap around.
+    /// Directions pick the nearest screen beyond the corresponding edge,
+    /// preferring screens that overlap along the other axis.
+    pub fn resolve_target(
+        screens: &[ScreenBounds],
+        from_id: u32,
+        target: ScreenTarget,
+    ) -> Option<&ScreenBounds> {
+        let index = screens.iter().position(|s| s.id == from_id)?;
+        let from = &screens[index];
+        let count = screens.len();
+
+        match target {
+            ScreenTarget::Number(number) => screens.get((number as usize).checked_sub(1)?),
+            ScreenTarget::Next => screens.get((index + 1) % count),
+            ScreenTarget::Previous => screens.get((index + count - 1) % count),
+            ScreenTarget::Left | ScreenTarget::Right | ScreenTarget::Up | ScreenTarget::Down => {
+                screens
+                    .iter()
+                    .filter_map(|to| {
+                        Self::direction_score(from, to, target).map(|score| (score, to))
+                    })
+                    .min_by_key(|(score, _)| *score)
+                    .map(|(_, to)| to)
+            }
+        }
+    }
+
+    /// Rank a screen as the neighbour of `from` in a direction, lower is closer
+    fn direction_score(
+        from: &ScreenBounds,
+        to: &ScreenBounds,
+        direction: ScreenTarget,
+    ) -> Option<(bool, i64, i64)> {
+        let (from_start, from_end) = (from.x as i64, from.x as i64 + from.width as i64);
+        let (from_top, from_bottom) = (from.y as i64, from.y as i64 + from.height as i64);
+        let (to_start, to_end) = (to.x as i64, to.x as i64 + to.width as i64);
+        let (to_top, to_bottom) = (to.y as i64, to.y as i64 + to.height as i64);
+        let from_center = from.center();
+        let to_center = to.center();
+
+        // Gap along the direction, and the perpendicular spans and centers
+        let (gap, overlap, offset) = match direction {
+            ScreenTarget::Left if to_center.x < from_center.x && to_end <= from_start + 1 => (
+                from_start - to_end,
+                from_bottom.min(to_bottom) - from_top.max(to_top),
+                (to_center.y - from_center.y) as i64,
+            ),
+            ScreenTarget::Right if to_center.x > from_center.x && to_start >= from_end - 1 => (
+                to_start - from_end,
+                from_bottom.min(to_bottom) - from_top.max(to_top),
+                (to_center.y - from_center.y) as i64,
+            ),
+            ScreenTarget::Up if to_center.y < from_center.y && to_bottom <= from_top + 1 => (
+                from_top - to_bottom,
+                from_end.min(to_end) - from_start.max(to_start),
+                (to_center.x - from_center.x) as i64,
+            ),
+            ScreenTarget::Down if to_center.y > from_center.y && to_top >= from_bottom - 1 => (
+                to_top - from_bottom,
+                from_end.min(to_end) - from_start.max(to_start),
+                (to_center.x - from_center.x) as i64,
+            ),
+            _ => return None,
+        };
+
+        Some((overlap <= 0, gap.max(0), offset.abs()))
+    }
+
+    /// Move a position to the same relative place on another screen
+    pub fn relative_position_on(
+        from: &ScreenBounds,
+        to: &ScreenBounds,
+        position: Position,
+    ) -> Position {
+        let relative_x = (position.x - from.x) as f64 / from.width.max(1) as f64;
+        let relative_y = (position.y - from.y) as f64 / from.height.max(1) as f64;
+
+        to.clamp(Position::new(
+            to.x + (relative_x * to.width as f64).round() as i32,
+            to.y + (relative_y * to.height as f64).round() as i32,
+        ))
+    }
+
     /// Map a screen number (1, 2, 3) to screen ID for user convenience
     pub fn map_screen_number_to_id(screen_number: u8) -> MouseResult<u32> {
         let screens = Self::detect_screens()?;
@@ -232,6 +579,253 @@ mod tests {
         assert!(screen.contains(pos));
     }
 
+    #[test]
+    fn test_place_on_screens() {
+        // Second screen left of and below the primary screen
+        let screens = vec![
+            ScreenBounds {
+                id: 0,
+                x: 0,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: true,
+                scale_factor: 1.0,
+            },
+            ScreenBounds {
+                id: 1,
+                x: -1280,
+                y: 300,
+                width: 1280,
+                height: 1024,
+                is_primary: false,
+                scale_factor: 1.0,
+            },
+        ];
+
+        let bounds = ScreenManager::desktop_bounds_of(&screens).unwrap();
+        assert_eq!(bounds, (-1280, 0, 3200, 1324));
+
+        let placed = ScreenManager::place_on_screens(&screens, Position::new(-100, 400));
+        assert_eq!(placed, Position::with_screen(-100, 400, 1));
+
+        // Gap above the second screen is clamped onto it
+        let placed = ScreenManager::place_on_screens(&screens, Position::new(-600, 100));
+        assert_eq!(placed, Position::with_screen(-600, 300, 1));
+
+        assert!(ScreenManager::desktop_bounds_of(&[]).is_err());
+    }
+
+    #[test]
+    fn test_mixed_dpi_conversions() {
+        // 2x laptop screen with a 1x external screen to its right
+        let screens = vec![
+            ScreenBounds {
+                id: 0,
+                x: 0,
+                y: 0,
+                width: 1440,
+                height: 900,
+                is_primary: true,
+                scale_factor: 2.0,
+            },
+            ScreenBounds {
+                id: 1,
+                x: 1440,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: false,
+                scale_factor: 1.0,
+            },
+        ];
+
+        assert_eq!(screens[0].physical_size(), (2880, 1800));
+
+        // The external screen starts after the laptop screen's physical width
+        assert_eq!(
+            ScreenManager::physical_origins(&screens),
+            vec![(0, 0), (2880, 0)]
+        );
+
+        // Offsets on the laptop screen double, those on the external screen do not
+        let physical = ScreenManager::to_physical(&screens, Position::new(720, 450)).unwrap();
+        assert_eq!(physical, PhysicalPosition::new(1440, 900, 0));
+        let physical = ScreenManager::to_physical(&screens, Position::new(2400, 540)).unwrap();
+        assert_eq!(physical, PhysicalPosition::new(3840, 540, 1));
+
+        assert_eq!(
+            ScreenManager::to_logical(&screens, PhysicalPosition::new(1440, 900, 0)),
+            Some(Position::with_screen(720, 450, 0))
+        );
+        assert_eq!(
+            ScreenManager::to_logical(&screens, PhysicalPosition::new(3840, 540, 1)),
+            Some(Position::with_screen(2400, 540, 1))
+        );
+
+        // Positions off screen are clamped before conversion
+        let physical = ScreenManager::to_physical(&screens, Position::with_screen(5000, 10, 0));
+        assert_eq!(physical, Some(PhysicalPosition::new(2878, 20, 0)));
+
+        // Fractional scaling rounds to the nearest pixel and round-trips
+        let fractional = ScreenBounds {
+            scale_factor: 1.25,
+            ..screens[1].clone()
+        };
+        assert_eq!(fractional.physical_size(), (2400, 1350));
+        let physical = fractional.to_physical((2880, 0), Position::new(1441, 3));
+        assert_eq!(physical, PhysicalPosition::new(2881, 4, 1));
+        assert_eq!(
+            fractional.to_logical((2880, 0), physical),
+            Position::with_screen(1441, 3, 1)
+        );
+
+        // Physical ranges of neighbouring screens do not overlap
+        let screen = ScreenManager::find_screen_for_physical(&screens, 2000, 1500).unwrap();
+        assert_eq!(screen.id, 0);
+        let screen = ScreenManager::find_screen_for_physical(&screens, 2000, 100).unwrap();
+        assert_eq!(screen.id, 0);
+        let screen = ScreenManager::find_screen_for_physical(&screens, 2900, 100).unwrap();
+        assert_eq!(screen.id, 1);
+    }
+
+    #[test]
+    fn test_physical_round_trip_across_scale_boundary() {
+        // 1x screen left of a 2x primary screen, a 1.5x screen below it
+        let screen = |id, x, y, width, height, scale_factor| ScreenBounds {
+            id,
+            x,
+            y,
+            width,
+            height,
+            is_primary: id == 0,
+            scale_factor,
+        };
+        let screens = vec![
+            screen(0, 0, 0, 1440, 900, 2.0),
+            screen(1, -1920, 0, 1920, 1080, 1.0),
+            screen(2, 0, 900, 1280, 720, 1.5),
+        ];
+
+        assert_eq!(
+            ScreenManager::physical_origins(&screens),
+            vec![(0, 0), (-1920, 0), (0, 1800)]
+        );
+
+        let through_pointer = |position: Position| {
+            let physical = ScreenManager::to_physical(&screens, position).unwrap();
+            let screen =
+                ScreenManager::find_screen_for_physical(&screens, physical.x, physical.y).unwrap();
+            assert_eq!(Some(screen.id), position.screen_id);
+            ScreenManager::to_logical(
+                &screens,
+                PhysicalPosition::new(physical.x, physical.y, screen.id),
+            )
+            .unwrap()
+        };
+
+        for position in [
+            Position::with_screen(1439, 450, 0),
+            Position::with_screen(0, 0, 0),
+            Position::with_screen(-1, 450, 1),
+            Position::with_screen(-1920, 1079, 1),
+            Position::with_screen(0, 900, 2),
+            Position::with_screen(1279, 1619, 2),
+            Position::with_screen(700, 899, 0),
+        ] {
+            assert_eq!(through_pointer(position), position);
+        }
+    }
+
+    #[test]
+    fn test_directional_screen_navigation() {
+        let screen = |id, x, y, width, height| ScreenBounds {
+            id,
+            x,
+            y,
+            width,
+            height,
+            is_primary: id == 0,
+            scale_factor: 1.0,
+        };
+        // Primary in the middle, a portrait screen to the left, one screen
+        // to the right and one above the primary screen
+        let screens = vec![
+            screen(0, 0, 0, 1920, 1080),
+            screen(1, -1080, -400, 1080, 1920),
+            screen(2, 1920, 200, 2560, 1440),
+            screen(3, 320, -1080, 1280, 1080),
+        ];
+        let target =
+            |from, target| ScreenManager::resolve_target(&screens, from, target).map(|s| s.id);
+
+        assert_eq!(target(0, ScreenTarget::Left), Some(1));
+        assert_eq!(target(0, ScreenTarget::Right), Some(2));
+        assert_eq!(target(0, ScreenTarget::Up), Some(3));
+        assert_eq!(target(0, ScreenTarget::Down), None);
+        assert_eq!(target(2, ScreenTarget::Left), Some(0));
+        assert_eq!(target(3, ScreenTarget::Down), Some(0));
+        assert_eq!(target(1, ScreenTarget::Left), None);
+
+        assert_eq!(target(0, ScreenTarget::Next), Some(1));
+        assert_eq!(target(3, ScreenTarget::Next), Some(0));
+        assert_eq!(target(0, ScreenTarget::Previous), Some(3));
+        assert_eq!(target(0, ScreenTarget::Number(3)), Some(2));
+        assert_eq!(target(0, ScreenTarget::Number(0)), None);
+        assert_eq!(target(0, ScreenTarget::Number(5)), None);
+        assert_eq!(target(9, ScreenTarget::Next), None);
+
+        // Jumps keep the relative position
+        let moved =
+            ScreenManager::relative_position_on(&screens[0], &screens[2], Position::new(480, 810));
+        assert_eq!(moved, Position::with_screen(2560, 1280, 2));
+    }
+
+    #[test]
+    fn test_screen_change_events() {
+        let laptop = ScreenInfo {
+            id: 0,
+            name: "eDP-1".to_string(),
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            is_primary: true,
+            scale_factor: 1.0,
+        };
+        let external = ScreenInfo {
+            name: "HDMI-1".to_string(),
+            x: 1920,
+            is_primary: false,
+            ..laptop.clone()
+        };
+
+        let single = Arc::new(StaticScreenProvider::new(vec![laptop.clone()]).unwrap());
+        let dual = Arc::new(StaticScreenProvider::new(vec![laptop, external]).unwrap());
+
+        // A state of its own, so tests reading the global screens are unaffected
+        let state = RwLock::new(ScreenState::new());
+        ScreenManager::set_provider_of(&state, single.clone()).unwrap();
+        let mut events = ScreenManager::subscribe_to(&state);
+
+        // Re-enumerating an unchanged layout publishes nothing
+        ScreenManager::refresh_state(&state).unwrap();
+        assert!(events.try_recv().is_err());
+
+        ScreenManager::set_provider_of(&state, dual).unwrap();
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.added()[0].name, "HDMI-1");
+        let screens = ScreenManager::screen_info_of(&state).unwrap();
+        assert_eq!(screens.len(), 2);
+        assert_eq!(screens[1].name, "HDMI-1");
+        assert_eq!(screens[1].id, 1);
+
+        ScreenManager::set_provider_of(&state, single).unwrap();
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.removed()[0].name, "HDMI-1");
+        assert_eq!(ScreenManager::screen_info_of(&state).unwrap().len(), 1);
+    }
+
     #[test]
     fn test_screen_center() {
         let screens = ScreenManager::detect_screens().unwrap();
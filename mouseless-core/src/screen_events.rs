@@ -0,0 +1,225 @@
+//! Screen change module
+//!
+//! This module provides functionality for:
+//! - Describing display hotplug, resolution and rotation changes
+//! - Remapping stored positions when their screen changes or disappears
+//! - Watching the screen provider for changes in the background
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::{
+    models::{Position, ScreenBounds},
+    screen::ScreenManager,
+    screen_provider::ScreenInfo,
+};
+
+/// A change of the connected screens
+///
+/// Screens are matched across the change by connector name, since ids are
+/// reassigned on every enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenChangeEvent {
+    pub previous: Vec<ScreenInfo>,
+    pub current: Vec<ScreenInfo>,
+}
+
+impl ScreenChangeEvent {
+    /// Describe the change between two enumerations, if anything changed
+    pub fn between(previous: Vec<ScreenInfo>, current: Vec<ScreenInfo>) -> Option<Self> {
+        if previous == current || current.is_empty() {
+            return None;
+        }
+
+        Some(Self { previous, current })
+    }
+
+    /// Screens that were connected by this change
+    pub fn added(&self) -> Vec<&ScreenInfo> {
+        self.current
+            .iter()
+            .filter(|screen| Self::find_by_name(&self.previous, &screen.name).is_none())
+            .collect()
+    }
+
+    /// Screens that were disconnected by this change
+    pub fn removed(&self) -> Vec<&ScreenInfo> {
+        self.previous
+            .iter()
+            .filter(|screen| Self::find_by_name(&self.current, &screen.name).is_none())
+            .collect()
+    }
+
+    /// Screens that stayed connected but were moved, resized, rotated or rescaled
+    pub fn reconfigured(&self) -> Vec<&ScreenInfo> {
+        self.current
+            .iter()
+            .filter(|screen| {
+                Self::find_by_name(&self.previous, &screen.name).is_some_and(|old| {
+                    (old.x, old.y, old.width, old.height, old.scale_factor)
+                        != (
+                            screen.x,
+                            screen.y,
+                            screen.width,
+                            screen.height,
+                            screen.scale_factor,
+                        )
+                })
+            })
+            .collect()
+    }
+
+    /// Get the bounds of the screens after the change
+    pub fn bounds(&self) -> Vec<ScreenBounds> {
+        self.current.iter().map(ScreenInfo::bounds).collect()
+    }
+
+    /// Remap a position recorded before the change
+    ///
+    /// A position on a screen that is still connected keeps its relative
+    /// place on that screen, so resolution changes and rotations scale it.
+    /// A position on a disconnected screen moves to the same relative place
+    /// on the primary screen.
+    pub fn remap_position(&self, position: Position) -> Position {
+        let old_screen = position
+            .screen_id
+            .and_then(|id| self.previous.iter().find(|s| s.id == id))
+            .or_else(|| self.previous.iter().find(|s| s.bounds().contains(position)));
+
+        let Some(old_screen) = old_screen else {
+            return ScreenManager::place_on_screens(&self.bounds(), position);
+        };
+
+        let new_screen = Self::find_by_name(&self.current, &old_screen.name)
+            .or_else(|| self.current.iter().find(|s| s.is_primary))
+            .unwrap_or(&self.current[0]);
+
+        ScreenManager::relative_position_on(&old_screen.bounds(), &new_screen.bounds(), position)
+    }
+
+    fn find_by_name<'a>(screens: &'a [ScreenInfo], name: &str) -> Option<&'a ScreenInfo> {
+        screens.iter().find(|s| s.name == name)
+    }
+}
+
+/// Background watcher that re-enumerates the screens periodically
+///
+/// Changes are published through [`ScreenManager::subscribe`]. The watcher
+/// stops when dropped.
+pub struct ScreenWatcher {
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl ScreenWatcher {
+    /// Default interval between two enumerations
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+
+    /// Start watching the screens
+    pub fn start(interval: Duration) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = stop.clone();
+
+        let handle = thread::spawn(move || {
+            while !stop_flag.load(Ordering::Relaxed) {
+                if let Err(e) = ScreenManager::refresh() {
+                    tracing::warn!("Failed to refresh screens: {}", e);
+                }
+                thread::park_timeout(interval);
+            }
+        });
+
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Stop watching and wait for the watcher thread to finish
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for ScreenWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(id: u32, name: &str, x: i32, width: u32, height: u32) -> ScreenInfo {
+        ScreenInfo {
+            id,
+            name: name.to_string(),
+            x,
+            y: 0,
+            width,
+            height,
+            is_primary: id == 0,
+            scale_factor: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_change_detection() {
+        let laptop = screen(0, "eDP-1", 0, 1920, 1080);
+        let external = screen(1, "HDMI-1", 1920, 2560, 1440);
+
+        assert!(ScreenChangeEvent::between(vec![laptop.clone()], vec![laptop.clone()]).is_none());
+
+        let event =
+            ScreenChangeEvent::between(vec![laptop.clone()], vec![laptop.clone(), external])
+                .unwrap();
+        assert_eq!(event.added()[0].name, "HDMI-1");
+        assert!(event.removed().is_empty());
+        assert!(event.reconfigured().is_empty());
+        assert_eq!(event.bounds().len(), 2);
+
+        // Rotating the laptop screen swaps its dimensions
+        let rotated = screen(0, "eDP-1", 0, 1080, 1920);
+        let event = ScreenChangeEvent::between(vec![laptop], vec![rotated]).unwrap();
+        assert_eq!(event.reconfigured()[0].name, "eDP-1");
+    }
+
+    #[test]
+    fn test_remap_positions() {
+        let laptop = screen(0, "eDP-1", 0, 1920, 1080);
+        let external = screen(1, "HDMI-1", 1920, 2560, 1440);
+
+        // Unplugging the external screen moves its positions onto the laptop screen
+        let event =
+            ScreenChangeEvent::between(vec![laptop.clone(), external], vec![laptop.clone()])
+                .unwrap();
+        assert_eq!(event.removed()[0].name, "HDMI-1");
+        assert_eq!(
+            event.remap_position(Position::with_screen(1920 + 1280, 720, 1)),
+            Position::with_screen(960, 540, 0)
+        );
+        assert_eq!(
+            event.remap_position(Position::new(100, 200)),
+            Position::with_screen(100, 200, 0)
+        );
+
+        // A resolution change keeps the relative position
+        let scaled = screen(0, "eDP-1", 0, 3840, 2160);
+        let event = ScreenChangeEvent::between(vec![laptop], vec![scaled]).unwrap();
+        assert_eq!(
+            event.remap_position(Position::with_screen(960, 540, 0)),
+            Position::with_screen(1920, 1080, 0)
+        );
+        assert_eq!(
+            event.remap_position(Position::with_screen(1919, 1079, 0)),
+            Position::with_screen(3838, 2158, 0)
+        );
+    }
+}
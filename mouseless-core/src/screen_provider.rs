@@ -0,0 +1,660 @@
+//! Screen provider module
+//!
+//! This module provides functionality for:
+//! - Describing connected screens with connector name and scale factor
+//! - Enumerating outputs on Linux through X11 RandR or Wayland
+//! - Reading a static screen layout file for tests and headless runs
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::{
+    error::{MouseError, MouseResult},
+    models::ScreenBounds,
+};
+
+/// Detailed information about a connected screen
+///
+/// Positions and sizes are in the global desktop coordinate space.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScreenInfo {
+    /// Screen id, assigned by the provider in enumeration order
+    #[serde(default)]
+    pub id: u32,
+    /// Stable connector name, such as `eDP-1` or `DP-2`
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub is_primary: bool,
+    #[serde(default = "default_scale_factor")]
+    pub scale_factor: f64,
+}
+
+fn default_scale_factor() -> f64 {
+    1.0
+}
+
+impl ScreenInfo {
+    /// Get the bounds of this screen
+    pub fn bounds(&self) -> ScreenBounds {
+        ScreenBounds {
+            id: self.id,
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+            is_primary: self.is_primary,
+            scale_factor: self.scale_factor,
+        }
+    }
+}
+
+/// Source of the connected screens
+pub trait ScreenProvider: Send + Sync {
+    /// Short name of the provider for logging
+    fn name(&self) -> &str;
+
+    /// Enumerate the connected screens
+    fn screens(&self) -> MouseResult<Vec<ScreenInfo>>;
+}
+
+/// Put screens into a canonical order and assign ids
+///
+/// The primary screen comes first, followed by the others from left to
+/// right and top to bottom. Without a primary screen the first one becomes
+/// primary.
+pub fn normalize_screens(mut screens: Vec<ScreenInfo>) -> MouseResult<Vec<ScreenInfo>> {
+    if screens.is_empty() {
+        return Err(MouseError::ScreenDetectionFailed {
+            reason: "No screens detected".to_string(),
+        });
+    }
+
+    for screen in &screens {
+        if screen.width == 0 || screen.height == 0 || screen.scale_factor <= 0.0 {
+            return Err(MouseError::ScreenDetectionFailed {
+                reason: format!(
+                    "Invalid screen '{}': {}x{} at scale {}",
+                    screen.name, screen.width, screen.height, screen.scale_factor
+                ),
+            });
+        }
+    }
+
+    screens.sort_by_key(|s| (!s.is_primary, s.x, s.y));
+
+    let mut seen_primary = false;
+    for (index, screen) in screens.iter_mut().enumerate() {
+        screen.id = index as u32;
+        screen.is_primary = screen.is_primary && !seen_primary;
+        seen_primary |= screen.is_primary;
+    }
+    if !seen_primary {
+        screens[0].is_primary = true;
+    }
+
+    Ok(screens)
+}
+
+/// Layout description file read by [`StaticScreenProvider`]
+///
+/// ```toml
+/// [[screens]]
+/// name = "eDP-1"
+/// x = 0
+/// y = 0
+/// width = 2560
+/// height = 1600
+/// is_primary = true
+/// scale_factor = 2.0
+///
+/// [[screens]]
+/// name = "DP-1"
+/// x = -1920
+/// y = 0
+/// width = 1920
+/// height = 1080
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScreenLayoutFile {
+    pub screens: Vec<ScreenInfo>,
+}
+
+/// Screen provider returning a fixed layout
+pub struct StaticScreenProvider {
+    screens: Vec<ScreenInfo>,
+}
+
+impl StaticScreenProvider {
+    /// Create a provider for a fixed set of screens
+    pub fn new(screens: Vec<ScreenInfo>) -> MouseResult<Self> {
+        Ok(Self {
+            screens: normalize_screens(screens)?,
+        })
+    }
+
+    /// Read a layout description file (TOML, or JSON by extension)
+    pub fn from_file<P: AsRef<Path>>(path: P) -> MouseResult<Self> {
+        let path = path.as_ref();
+        let load_failed = |reason: String| MouseError::ScreenDetectionFailed {
+            reason: format!(
+                "Failed to load screen layout {}: {}",
+                path.display(),
+                reason
+            ),
+        };
+
+        let content = fs::read_to_string(path).map_err(|e| load_failed(e.to_string()))?;
+
+        let layout: ScreenLayoutFile = match path.extension().and_then(|s| s.to_str()) {
+            Some("json") => {
+                serde_json::from_str(&content).map_err(|e| load_failed(e.to_string()))?
+            }
+            _ => toml::from_str(&content).map_err(|e| load_failed(e.to_string()))?,
+        };
+
+        Self::new(layout.screens)
+    }
+
+    /// Single 1920x1080 screen, used when no screens can be detected
+    pub fn fallback() -> Self {
+        Self {
+            screens: vec![ScreenInfo {
+                id: 0,
+                name: "default".to_string(),
+                x: 0,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: true,
+                scale_factor: 1.0,
+            }],
+        }
+    }
+}
+
+impl ScreenProvider for StaticScreenProvider {
+    fn name(&self) -> &str {
+        "static"
+    }
+
+    fn screens(&self) -> MouseResult<Vec<ScreenInfo>> {
+        Ok(self.screens.clone())
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use linux::{LinuxScreenProvider, WaylandScreenProvider, X11ScreenProvider};
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::*;
+    use wayland_client::{
+        delegate_noop,
+        protocol::{wl_output, wl_registry},
+        Connection, Dispatch, QueueHandle, WEnum,
+    };
+    use wayland_protocols::xdg::xdg_output::zv1::client::{
+        zxdg_output_manager_v1::ZxdgOutputManagerV1, zxdg_output_v1,
+    };
+    use x11rb::{
+        connection::Connection as _, protocol::randr::ConnectionExt as _,
+        protocol::xproto::ConnectionExt as _,
+    };
+
+    fn detection_failed(backend: &str, error: impl std::fmt::Display) -> MouseError {
+        MouseError::ScreenDetectionFailed {
+            reason: format!("{} output enumeration failed: {}", backend, error),
+        }
+    }
+
+    /// Screen provider using the X11 RandR monitor list
+    ///
+    /// X11 has no per-output scale, so every screen reports a scale factor of 1.
+    pub struct X11ScreenProvider;
+
+    impl ScreenProvider for X11ScreenProvider {
+        fn name(&self) -> &str {
+            "x11"
+        }
+
+        fn screens(&self) -> MouseResult<Vec<ScreenInfo>> {
+            let (conn, screen_num) =
+                x11rb::connect(None).map_err(|e| detection_failed("X11", e))?;
+            let root = conn.setup().roots[screen_num].root;
+
+            let monitors = conn
+                .randr_get_monitors(root, true)
+                .map_err(|e| detection_failed("X11", e))?
+                .reply()
+                .map_err(|e| detection_failed("X11", e))?
+                .monitors;
+
+            let mut screens = Vec::new();
+            for monitor in monitors {
+                let name = conn
+                    .get_atom_name(monitor.name)
+                    .map_err(|e| detection_failed("X11", e))?
+                    .reply()
+                    .map_err(|e| detection_failed("X11", e))?
+                    .name;
+
+                screens.push(ScreenInfo {
+                    id: 0,
+                    name: String::from_utf8_lossy(&name).into_owned(),
+                    x: monitor.x as i32,
+                    y: monitor.y as i32,
+                    width: monitor.width as u32,
+                    height: monitor.height as u32,
+                    is_primary: monitor.primary,
+                    scale_factor: 1.0,
+                });
+            }
+
+            normalize_screens(screens)
+        }
+    }
+
+    /// What the compositor reported about one output
+    #[derive(Debug, Default)]
+    pub(super) struct WaylandOutput {
+        pub(super) name: Option<String>,
+        pub(super) x: i32,
+        pub(super) y: i32,
+        pub(super) mode: Option<(i32, i32)>,
+        pub(super) scale: i32,
+        /// Whether the output transform turns it by 90 or 270 degrees
+        pub(super) rotated: bool,
+        /// Position and size in the global space from `zxdg_output_v1`
+        pub(super) logical_position: Option<(i32, i32)>,
+        pub(super) logical_size: Option<(i32, i32)>,
+    }
+
+    impl WaylandOutput {
+        /// Describe the output in logical desktop coordinates
+        ///
+        /// The xdg-output logical size already accounts for the transform and
+        /// for fractional scales. Without it the current mode is rotated by
+        /// the transform and divided by the integer output scale.
+        pub(super) fn screen_info(self, index: usize) -> Option<ScreenInfo> {
+            let (mut width, mut height) = self.mode?;
+            if self.rotated {
+                (width, height) = (height, width);
+            }
+
+            let (x, y) = self.logical_position.unwrap_or((self.x, self.y));
+            let logical_size = self.logical_size.filter(|&(w, h)| w > 0 && h > 0);
+            let (width, height, scale_factor) = match logical_size {
+                Some((w, h)) => (w, h, width as f64 / w as f64),
+                None => (width / self.scale, height / self.scale, self.scale as f64),
+            };
+
+            Some(ScreenInfo {
+                id: 0,
+                name: self.name.unwrap_or_else(|| format!("wl_output-{}", index)),
+                x,
+                y,
+                width: width as u32,
+                height: height as u32,
+                is_primary: index == 0,
+                scale_factor,
+            })
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct WaylandState {
+        outputs: Vec<WaylandOutput>,
+        wl_outputs: Vec<wl_output::WlOutput>,
+        xdg_output_manager: Option<ZxdgOutputManagerV1>,
+    }
+
+    impl Dispatch<wl_registry::WlRegistry, ()> for WaylandState {
+        fn event(
+            state: &mut Self,
+            registry: &wl_registry::WlRegistry,
+            event: wl_registry::Event,
+            _: &(),
+            _: &Connection,
+            qh: &QueueHandle<Self>,
+        ) {
+            if let wl_registry::Event::Global {
+                name,
+                interface,
+                version,
+            } = event
+            {
+                if interface == "wl_output" {
+                    let index = state.outputs.len();
+                    state.outputs.push(WaylandOutput {
+                        scale: 1,
+                        ..WaylandOutput::default()
+                    });
+                    let output =
+                        registry.bind::<wl_output::WlOutput, _, _>(name, version.min(4), qh, index);
+                    state.wl_outputs.push(output);
+                } else if interface == "zxdg_output_manager_v1" {
+                    let manager =
+                        registry.bind::<ZxdgOutputManagerV1, _, _>(name, version.min(3), qh, ());
+                    state.xdg_output_manager = Some(manager);
+                }
+            }
+        }
+    }
+
+    impl Dispatch<wl_output::WlOutput, usize> for WaylandState {
+        fn event(
+            state: &mut Self,
+            _: &wl_output::WlOutput,
+            event: wl_output::Event,
+            index: &usize,
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            let output = &mut state.outputs[*index];
+            match event {
+                wl_output::Event::Geometry {
+                    x, y, transform, ..
+                } => {
+                    output.x = x;
+                    output.y = y;
+                    output.rotated = matches!(
+                        transform,
+                        WEnum::Value(
+                            wl_output::Transform::_90
+                                | wl_output::Transform::_270
+                                | wl_output::Transform::Flipped90
+                                | wl_output::Transform::Flipped270
+                        )
+                    );
+                }
+                wl_output::Event::Mode {
+                    flags: WEnum::Value(flags),
+                    width,
+                    height,
+                    ..
+                } if flags.contains(wl_output::Mode::Current) => {
+                    output.mode = Some((width, height));
+                }
+                wl_output::Event::Scale { factor } => output.scale = factor.max(1),
+                wl_output::Event::Name { name } => output.name = Some(name),
+                _ => {}
+            }
+        }
+    }
+
+    impl Dispatch<zxdg_output_v1::ZxdgOutputV1, usize> for WaylandState {
+        fn event(
+            state: &mut Self,
+            _: &zxdg_output_v1::ZxdgOutputV1,
+            event: zxdg_output_v1::Event,
+            index: &usize,
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            let output = &mut state.outputs[*index];
+            match event {
+                zxdg_output_v1::Event::LogicalPosition { x, y } => {
+                    output.logical_position = Some((x, y))
+                }
+                zxdg_output_v1::Event::LogicalSize { width, height } => {
+                    output.logical_size = Some((width, height))
+                }
+                // Older wl_output versions have no name event
+                zxdg_output_v1::Event::Name { name } => {
+                    output.name.get_or_insert(name);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    delegate_noop!(WaylandState: ZxdgOutputManagerV1);
+
+    /// Screen provider using the Wayland `wl_output` globals
+    ///
+    /// Wayland has no primary output, so the first advertised output is used.
+    /// Positions and sizes are logical. They come from `zxdg_output_v1` when
+    /// the compositor supports it, which handles fractional scales; otherwise
+    /// from the current mode, rotated by the output transform and divided by
+    /// the output scale.
+    pub struct WaylandScreenProvider;
+
+    impl ScreenProvider for WaylandScreenProvider {
+        fn name(&self) -> &str {
+            "wayland"
+        }
+
+        fn screens(&self) -> MouseResult<Vec<ScreenInfo>> {
+            let conn = Connection::connect_to_env().map_err(|e| detection_failed("Wayland", e))?;
+            let mut queue = conn.new_event_queue();
+            let qh = queue.handle();
+            conn.display().get_registry(&qh, ());
+
+            // First roundtrip announces the globals, the second delivers output events
+            let mut state = WaylandState::default();
+            queue
+                .roundtrip(&mut state)
+                .map_err(|e| detection_failed("Wayland", e))?;
+            if let Some(manager) = &state.xdg_output_manager {
+                for (index, output) in state.wl_outputs.iter().enumerate() {
+                    manager.get_xdg_output(output, &qh, index);
+                }
+            }
+            queue
+                .roundtrip(&mut state)
+                .map_err(|e| detection_failed("Wayland", e))?;
+
+            let screens = state
+                .outputs
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, output)| output.screen_info(index))
+                .collect();
+
+            normalize_screens(screens)
+        }
+    }
+
+    /// Screen provider picking Wayland or X11 from the session environment
+    pub struct LinuxScreenProvider;
+
+    impl ScreenProvider for LinuxScreenProvider {
+        fn name(&self) -> &str {
+            "linux"
+        }
+
+        fn screens(&self) -> MouseResult<Vec<ScreenInfo>> {
+            if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+                match WaylandScreenProvider.screens() {
+                    Ok(screens) => return Ok(screens),
+                    Err(e) => tracing::warn!("{}, trying X11", e),
+                }
+            }
+
+            X11ScreenProvider.screens()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn screen(name: &str, x: i32, is_primary: bool) -> ScreenInfo {
+        ScreenInfo {
+            id: 42,
+            name: name.to_string(),
+            x,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            is_primary,
+            scale_factor: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_normalize_screens() {
+        let screens = normalize_screens(vec![
+            screen("DP-2", 1920, false),
+            screen("DP-1", -1920, false),
+            screen("eDP-1", 0, true),
+        ])
+        .unwrap();
+
+        let names: Vec<&str> = screens.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["eDP-1", "DP-1", "DP-2"]);
+        assert_eq!(
+            screens.iter().map(|s| s.id).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+
+        // Without a primary screen the first one becomes primary
+        let screens =
+            normalize_screens(vec![screen("DP-2", 1920, false), screen("DP-1", 0, false)]).unwrap();
+        assert_eq!(screens[0].name, "DP-1");
+        assert!(screens[0].is_primary);
+
+        assert!(normalize_screens(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_static_provider_from_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let layout_path = temp_dir.path().join("screens.toml");
+
+        fs::write(
+            &layout_path,
+            r#"
+[[screens]]
+name = "DP-1"
+x = -1920
+y = 0
+width = 1920
+height = 1080
+
+[[screens]]
+name = "eDP-1"
+x = 0
+y = 0
+width = 1440
+height = 900
+is_primary = true
+scale_factor = 2.0
+"#,
+        )
+        .unwrap();
+
+        let provider = StaticScreenProvider::from_file(&layout_path).unwrap();
+        let screens = provider.screens().unwrap();
+
+        assert_eq!(screens.len(), 2);
+        assert_eq!(screens[0].name, "eDP-1");
+        assert_eq!(screens[0].scale_factor, 2.0);
+        assert_eq!(screens[1].scale_factor, 1.0);
+        assert_eq!(
+            screens[1].bounds(),
+            ScreenBounds {
+                id: 1,
+                x: -1920,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: false,
+                scale_factor: 1.0,
+            }
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_wayland_rotated_output() {
+        use super::linux::WaylandOutput;
+
+        // A portrait monitor still reports its landscape mode
+        let output = WaylandOutput {
+            name: Some("DP-1".to_string()),
+            x: 1920,
+            mode: Some((1920, 1080)),
+            scale: 1,
+            rotated: true,
+            ..WaylandOutput::default()
+        };
+        let screen = output.screen_info(1).unwrap();
+        assert_eq!((screen.x, screen.width, screen.height), (1920, 1080, 1920));
+        assert!(!screen.is_primary);
+        assert_eq!(screen.scale_factor, 1.0);
+
+        // Without a name the output is named by its index
+        let output = WaylandOutput {
+            mode: Some((2560, 1440)),
+            scale: 2,
+            rotated: true,
+            ..WaylandOutput::default()
+        };
+        let screen = output.screen_info(0).unwrap();
+        assert_eq!(screen.name, "wl_output-0");
+        assert_eq!((screen.width, screen.height), (720, 1280));
+        assert_eq!(screen.scale_factor, 2.0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_wayland_fractional_scale_output() {
+        use super::linux::WaylandOutput;
+
+        // wl_output rounds a 1.5 scale up to 2, xdg-output has the real size
+        let output = WaylandOutput {
+            name: Some("eDP-1".to_string()),
+            mode: Some((2880, 1800)),
+            scale: 2,
+            logical_position: Some((0, 0)),
+            logical_size: Some((1920, 1200)),
+            ..WaylandOutput::default()
+        };
+        let screen = output.screen_info(0).unwrap();
+        assert_eq!((screen.width, screen.height), (1920, 1200));
+        assert_eq!(screen.scale_factor, 1.5);
+
+        // The logical size of a rotated output is already swapped
+        let output = WaylandOutput {
+            name: Some("DP-2".to_string()),
+            x: 0,
+            mode: Some((3840, 2160)),
+            scale: 2,
+            rotated: true,
+            logical_position: Some((1920, 0)),
+            logical_size: Some((1440, 2560)),
+            ..WaylandOutput::default()
+        };
+        let screen = output.screen_info(1).unwrap();
+        assert_eq!((screen.x, screen.y), (1920, 0));
+        assert_eq!((screen.width, screen.height), (1440, 2560));
+        assert_eq!(screen.scale_factor, 1.5);
+
+        // Outputs without a current mode are skipped
+        assert!(WaylandOutput::default().screen_info(2).is_none());
+    }
+
+    #[test]
+    fn test_static_provider_rejects_invalid_layouts() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let missing = temp_dir.path().join("missing.toml");
+        assert!(StaticScreenProvider::from_file(&missing).is_err());
+
+        let json_path = temp_dir.path().join("screens.json");
+        fs::write(
+            &json_path,
+            r#"{"screens": [{"name": "HDMI-1", "x": 0, "y": 0, "width": 0, "height": 1080}]}"#,
+        )
+        .unwrap();
+        assert!(StaticScreenProvider::from_file(&json_path).is_err());
+    }
+}
@@ -0,0 +1,651 @@
+//! Scripting module
+//!
+//! This module provides functionality for:
+//! - Rhai scripts for user-defined actions and macros
+//! - Binding scripts to keys in any mode
+//! - Sandboxing scripts with operation and time limits
+//!
+//! Scripts do not drive the mouse themselves. They read a snapshot of the
+//! cursor, screens, marks and mode, and queue actions that the host runs
+//! through its action executor once the script has finished, so a failing
+//! script has no effect at all.
+
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, AST, INT};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, info};
+
+use crate::{
+    error::{ConfigError, ConfigResult, ScriptError, ScriptResult},
+    marks::Marks,
+    models::{
+        Action, AnimationType, InteractionMode, MouseButton, MovementSpeed, Position, ScreenBounds,
+        ScrollDirection,
+    },
+};
+
+/// Operations between two checks of the time limit
+const TIME_CHECK_INTERVAL: u64 = 256;
+
+/// Most actions one script run may queue
+const MAX_SCRIPT_ACTIONS: usize = 1000;
+
+type FnResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// Scripting configuration
+///
+/// ```toml
+/// [scripting.scripts]
+/// corner = """
+/// let back = cursor();
+/// let s = screen();
+/// move_to(s.x + s.width - 20, s.y + 20);
+/// double_click();
+/// move_to(back);
+/// """
+///
+/// [[scripting.bindings]]
+/// key = "t"
+/// script = "corner"
+/// modes = ["basic", "grid"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScriptConfig {
+    /// Wall-clock time a script may run before it is stopped
+    pub time_limit_ms: u64,
+    /// Interpreter operations a script may run before it is stopped
+    pub max_operations: u64,
+    /// Script sources by name
+    pub scripts: BTreeMap<String, String>,
+    /// Keys that run scripts
+    pub bindings: Vec<ScriptBinding>,
+}
+
+impl Default for ScriptConfig {
+    fn default() -> Self {
+        Self {
+            time_limit_ms: 50,
+            max_operations: 100_000,
+            scripts: BTreeMap::new(),
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl ScriptConfig {
+    /// Validate the limits and bindings, and compile every script
+    pub fn validate(&self) -> ConfigResult<()> {
+        if self.time_limit_ms == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "scripting.time_limit_ms".to_string(),
+                value: "0".to_string(),
+            });
+        }
+        if self.max_operations == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "scripting.max_operations".to_string(),
+                value: "0".to_string(),
+            });
+        }
+
+        for binding in &self.bindings {
+            if !self.scripts.contains_key(&binding.script) {
+                return Err(ConfigError::InvalidValue {
+                    field: "scripting.bindings.script".to_string(),
+                    value: binding.script.clone(),
+                });
+            }
+            if let Some(mode) = binding
+                .modes
+                .iter()
+                .find(|mode| !InteractionMode::is_valid_name(mode))
+            {
+                return Err(ConfigError::InvalidValue {
+                    field: "scripting.bindings.modes".to_string(),
+                    value: format!("{:?}", mode),
+                });
+            }
+        }
+
+        ScriptEngine::new(self)
+            .map(|_| ())
+            .map_err(|e| ConfigError::ValidationFailed {
+                reason: e.to_string(),
+            })
+    }
+}
+
+/// A key that runs a script
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptBinding {
+    /// Key that runs the script
+    pub key: char,
+    /// Name of the script in `[scripting.scripts]`
+    pub script: String,
+    /// Modes the binding applies in, all modes when empty
+    #[serde(default)]
+    pub modes: Vec<String>,
+}
+
+impl ScriptBinding {
+    /// Check if the binding applies in a mode
+    pub fn applies_to(&self, mode: &InteractionMode) -> bool {
+        self.modes.is_empty()
+            || self
+                .modes
+                .iter()
+                .any(|name| InteractionMode::from_name(name).as_ref() == Some(mode))
+    }
+}
+
+/// What a script can see of mouseless when it starts
+#[derive(Debug, Clone)]
+pub struct ScriptContext {
+    pub cursor: Position,
+    pub screens: Vec<ScreenBounds>,
+    pub marks: Marks,
+    pub mode: Option<InteractionMode>,
+    pub speed: MovementSpeed,
+    pub holding: bool,
+    /// Animation used for the cursor moves a script queues
+    pub animation: AnimationType,
+}
+
+impl ScriptContext {
+    /// A context with the cursor at `cursor` and nothing else known
+    pub fn new(cursor: Position) -> Self {
+        Self {
+            cursor,
+            screens: Vec::new(),
+            marks: Marks::new(),
+            mode: None,
+            speed: MovementSpeed::Normal,
+            holding: false,
+            animation: AnimationType::Instant,
+        }
+    }
+}
+
+/// State of the script being run, shared with the registered functions
+struct ScriptRun {
+    context: ScriptContext,
+    actions: Vec<Action>,
+}
+
+impl ScriptRun {
+    fn push(&mut self, action: Action) -> FnResult<()> {
+        if self.actions.len() >= MAX_SCRIPT_ACTIONS {
+            return Err(format!("Scripts may queue at most {} actions", MAX_SCRIPT_ACTIONS).into());
+        }
+        self.actions.push(action);
+        Ok(())
+    }
+
+    fn move_to(&mut self, position: Position) -> FnResult<()> {
+        // Later queries and relative moves see the cursor where it will be
+        self.context.cursor = position;
+        let animation = self.context.animation;
+        self.push(Action::MoveCursor(position, animation))
+    }
+}
+
+/// A sandboxed Rhai engine with the compiled scripts of a configuration
+///
+/// Scripts cannot import modules, call `eval` or touch files, and are
+/// stopped after `max_operations` interpreter operations or
+/// `time_limit_ms` of wall-clock time.
+pub struct ScriptEngine {
+    engine: Engine,
+    scripts: HashMap<String, AST>,
+    run: Arc<Mutex<Option<ScriptRun>>>,
+    deadline: Arc<Mutex<Option<Instant>>>,
+    time_limit: Duration,
+}
+
+impl ScriptEngine {
+    /// Create an engine and compile the configured scripts
+    pub fn new(config: &ScriptConfig) -> ScriptResult<Self> {
+        let run = Arc::new(Mutex::new(None));
+        let deadline = Arc::new(Mutex::new(None));
+
+        let mut engine = Engine::new();
+        engine
+            .set_module_resolver(DummyModuleResolver::new())
+            .disable_symbol("eval")
+            .set_max_operations(config.max_operations)
+            .set_max_call_levels(32)
+            .set_max_expr_depths(64, 32)
+            .set_max_string_size(10_000)
+            .set_max_array_size(10_000)
+            .set_max_map_size(1_000)
+            .on_print(|text| info!("📜 {}", text))
+            .on_debug(|text, source, position| {
+                debug!("📜 {} ({:?} at {})", text, source, position)
+            });
+
+        let progress_deadline = deadline.clone();
+        engine.on_progress(move |operations| {
+            if operations % TIME_CHECK_INTERVAL != 0 {
+                return None;
+            }
+            match *progress_deadline.lock().unwrap() {
+                Some(deadline) if Instant::now() >= deadline => Some(Dynamic::UNIT),
+                _ => None,
+            }
+        });
+
+        register_api(&mut engine, &run);
+
+        let scripts = config
+            .scripts
+            .iter()
+            .map(|(name, source)| {
+                engine
+                    .compile(source)
+                    .map(|ast| (name.clone(), ast))
+                    .map_err(|e| ScriptError::CompileFailed {
+                        name: name.clone(),
+                        reason: e.to_string(),
+                    })
+            })
+            .collect::<ScriptResult<HashMap<_, _>>>()?;
+
+        Ok(Self {
+            engine,
+            scripts,
+            run,
+            deadline,
+            time_limit: Duration::from_millis(config.time_limit_ms),
+        })
+    }
+
+    /// Check if a script is loaded under a name
+    pub fn contains(&self, name: &str) -> bool {
+        self.scripts.contains_key(name)
+    }
+
+    /// Run a script, returning the actions it queued
+    pub fn run(&mut self, name: &str, context: ScriptContext) -> ScriptResult<Vec<Action>> {
+        let ast = self
+            .scripts
+            .get(name)
+            .ok_or_else(|| ScriptError::UnknownScript {
+                name: name.to_string(),
+            })?;
+
+        *self.run.lock().unwrap() = Some(ScriptRun {
+            context,
+            actions: Vec::new(),
+        });
+        *self.deadline.lock().unwrap() = Some(Instant::now() + self.time_limit);
+
+        let result = self.engine.run_ast(ast);
+
+        *self.deadline.lock().unwrap() = None;
+        let run = self.run.lock().unwrap().take();
+
+        match result {
+            Ok(()) => {
+                let actions = run.map(|run| run.actions).unwrap_or_default();
+                debug!("Script {} queued {} actions", name, actions.len());
+                Ok(actions)
+            }
+            Err(e) => match *e {
+                EvalAltResult::ErrorTerminated(..) => Err(ScriptError::TimedOut {
+                    name: name.to_string(),
+                    limit_ms: self.time_limit.as_millis() as u64,
+                }),
+                e => Err(ScriptError::RunFailed {
+                    name: name.to_string(),
+                    reason: e.to_string(),
+                }),
+            },
+        }
+    }
+}
+
+/// Register the functions scripts can call
+fn register_api(engine: &mut Engine, run: &Arc<Mutex<Option<ScriptRun>>>) {
+    // Queries
+    let state = run.clone();
+    engine.register_fn("cursor", move || -> FnResult<Map> {
+        with_run(&state, |run| Ok(position_map(run.context.cursor)))
+    });
+    let state = run.clone();
+    engine.register_fn("screens", move || -> FnResult<Array> {
+        with_run(&state, |run| {
+            Ok(run
+                .context
+                .screens
+                .iter()
+                .map(|screen| Dynamic::from_map(screen_map(screen)))
+                .collect())
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("screen", move || -> FnResult<Map> {
+        with_run(&state, |run| {
+            let screens = &run.context.screens;
+            screens
+                .iter()
+                .find(|screen| screen.contains(run.context.cursor))
+                .or_else(|| screens.iter().find(|screen| screen.is_primary))
+                .or_else(|| screens.first())
+                .map(screen_map)
+                .ok_or_else(|| "No screens are known".into())
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("mark", move |name: &str| -> FnResult<Dynamic> {
+        with_run(&state, |run| {
+            Ok(run
+                .context
+                .marks
+                .get(name)
+                .map(|position| Dynamic::from_map(position_map(position)))
+                .unwrap_or(Dynamic::UNIT))
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("mode", move || -> FnResult<String> {
+        with_run(&state, |run| {
+            Ok(run
+                .context
+                .mode
+                .as_ref()
+                .map(|mode| mode.name().to_string())
+                .unwrap_or_default())
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("speed", move || -> FnResult<String> {
+        with_run(&state, |run| {
+            Ok(format!("{:?}", run.context.speed).to_lowercase())
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("holding", move || -> FnResult<bool> {
+        with_run(&state, |run| Ok(run.context.holding))
+    });
+
+    // Actions
+    let state = run.clone();
+    engine.register_fn("move_to", move |x: INT, y: INT| -> FnResult<()> {
+        with_run(&state, |run| {
+            run.move_to(Position::new(to_i32(x)?, to_i32(y)?))
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("move_to", move |position: Map| -> FnResult<()> {
+        with_run(&state, |run| run.move_to(map_position(&position)?))
+    });
+    let state = run.clone();
+    engine.register_fn("move_by", move |dx: INT, dy: INT| -> FnResult<()> {
+        with_run(&state, |run| {
+            let cursor = run.context.cursor;
+            run.move_to(Position::new(
+                cursor.x.saturating_add(to_i32(dx)?),
+                cursor.y.saturating_add(to_i32(dy)?),
+            ))
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("move_to_mark", move |name: &str| -> FnResult<()> {
+        with_run(&state, |run| {
+            let position = run
+                .context
+                .marks
+                .get(name)
+                .ok_or_else(|| format!("Unknown mark: {}", name))?;
+            run.move_to(position)
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("click", move || -> FnResult<()> {
+        with_run(&state, |run| run.push(Action::Click(MouseButton::Left)))
+    });
+    let state = run.clone();
+    engine.register_fn("click", move |button: &str| -> FnResult<()> {
+        with_run(&state, |run| run.push(Action::Click(parse_button(button)?)))
+    });
+    let state = run.clone();
+    engine.register_fn("double_click", move || -> FnResult<()> {
+        with_run(&state, |run| {
+            run.push(Action::Click(MouseButton::Left))?;
+            run.push(Action::Click(MouseButton::Left))
+        })
+    });
+    let state = run.clone();
+    engine.register_fn(
+        "scroll",
+        move |direction: &str, amount: INT| -> FnResult<()> {
+            with_run(&state, |run| {
+                run.push(Action::Scroll(parse_direction(direction)?, to_i32(amount)?))
+            })
+        },
+    );
+    let state = run.clone();
+    engine.register_fn("activate_mode", move |name: &str| -> FnResult<()> {
+        with_run(&state, |run| {
+            let mode = InteractionMode::from_name(name)
+                .ok_or_else(|| format!("Invalid mode name: {:?}", name))?;
+            run.context.mode = Some(mode.clone());
+            run.push(Action::ActivateMode(mode))
+        })
+    });
+    let state = run.clone();
+    engine.register_fn("deactivate_mode", move || -> FnResult<()> {
+        with_run(&state, |run| {
+            run.context.mode = None;
+            run.push(Action::DeactivateMode)
+        })
+    });
+}
+
+fn with_run<T>(
+    state: &Mutex<Option<ScriptRun>>,
+    f: impl FnOnce(&mut ScriptRun) -> FnResult<T>,
+) -> FnResult<T> {
+    let mut state = state.lock().unwrap();
+    let run = state
+        .as_mut()
+        .ok_or_else(|| Box::<EvalAltResult>::from("No script is running"))?;
+    f(run)
+}
+
+fn position_map(position: Position) -> Map {
+    let mut map = Map::new();
+    map.insert("x".into(), (position.x as INT).into());
+    map.insert("y".into(), (position.y as INT).into());
+    map
+}
+
+fn screen_map(screen: &ScreenBounds) -> Map {
+    let mut map = Map::new();
+    map.insert("id".into(), (screen.id as INT).into());
+    map.insert("x".into(), (screen.x as INT).into());
+    map.insert("y".into(), (screen.y as INT).into());
+    map.insert("width".into(), (screen.width as INT).into());
+    map.insert("height".into(), (screen.height as INT).into());
+    map.insert("primary".into(), screen.is_primary.into());
+    map
+}
+
+fn map_position(map: &Map) -> FnResult<Position> {
+    let coordinate = |name: &str| -> FnResult<i32> {
+        let value = map
+            .get(name)
+            .and_then(|value| value.as_int().ok())
+            .ok_or_else(|| format!("Position needs an integer {}", name))?;
+        to_i32(value)
+    };
+    Ok(Position::new(coordinate("x")?, coordinate("y")?))
+}
+
+fn to_i32(value: INT) -> FnResult<i32> {
+    i32::try_from(value).map_err(|_| format!("Number out of range: {}", value).into())
+}
+
+fn parse_button(name: &str) -> FnResult<MouseButton> {
+    match name.to_lowercase().as_str() {
+        "left" => Ok(MouseButton::Left),
+        "right" => Ok(MouseButton::Right),
+        "middle" => Ok(MouseButton::Middle),
+        _ => Err(format!("Unknown mouse button: {}", name).into()),
+    }
+}
+
+fn parse_direction(name: &str) -> FnResult<ScrollDirection> {
+    match name.to_lowercase().as_str() {
+        "up" => Ok(ScrollDirection::Up),
+        "down" => Ok(ScrollDirection::Down),
+        "left" => Ok(ScrollDirection::Left),
+        "right" => Ok(ScrollDirection::Right),
+        _ => Err(format!("Unknown scroll direction: {}", name).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(scripts: &[(&str, &str)]) -> ScriptResult<ScriptEngine> {
+        let config = ScriptConfig {
+            scripts: scripts
+                .iter()
+                .map(|(name, source)| (name.to_string(), source.to_string()))
+                .collect(),
+            ..ScriptConfig::default()
+        };
+        ScriptEngine::new(&config)
+    }
+
+    fn context() -> ScriptContext {
+        let mut marks = Marks::new();
+        marks.set("editor", Position::new(400, 300)).unwrap();
+        ScriptContext {
+            screens: vec![ScreenBounds {
+                id: 0,
+                x: 0,
+                y: 0,
+                width: 1920,
+                height: 1080,
+                is_primary: true,
+                scale_factor: 1.0,
+            }],
+            marks,
+            mode: Some(InteractionMode::Basic),
+            ..ScriptContext::new(Position::new(100, 200))
+        }
+    }
+
+    #[test]
+    fn test_script_queues_actions() {
+        let mut engine = load(&[(
+            "corner",
+            r#"
+                let back = cursor();
+                let s = screen();
+                move_to(s.x + s.width - 20, s.y + 20);
+                double_click();
+                move_to(back);
+                move_by(5, -5);
+                if mode() == "basic" { activate_mode("grid"); }
+            "#,
+        )])
+        .unwrap();
+
+        let actions = engine.run("corner", context()).unwrap();
+        assert_eq!(
+            actions,
+            vec![
+                Action::MoveCursor(Position::new(1900, 20), AnimationType::Instant),
+                Action::Click(MouseButton::Left),
+                Action::Click(MouseButton::Left),
+                Action::MoveCursor(Position::new(100, 200), AnimationType::Instant),
+                Action::MoveCursor(Position::new(105, 195), AnimationType::Instant),
+                Action::ActivateMode(InteractionMode::Grid),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_marks_and_errors() {
+        let mut engine = load(&[
+            ("mark", r#"move_to_mark("editor"); scroll("down", 3);"#),
+            ("missing", r#"click(); move_to_mark("browser");"#),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            engine.run("mark", context()).unwrap(),
+            vec![
+                Action::MoveCursor(Position::new(400, 300), AnimationType::Instant),
+                Action::Scroll(ScrollDirection::Down, 3),
+            ]
+        );
+        // A failing script queues nothing
+        assert!(matches!(
+            engine.run("missing", context()),
+            Err(ScriptError::RunFailed { .. })
+        ));
+        assert!(matches!(
+            engine.run("teleport", context()),
+            Err(ScriptError::UnknownScript { .. })
+        ));
+        assert!(matches!(
+            load(&[("broken", "move_to(1, ")]),
+            Err(ScriptError::CompileFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_sandbox_limits() {
+        let config = ScriptConfig {
+            time_limit_ms: 20,
+            max_operations: u64::MAX,
+            scripts: [("spin".to_string(), "loop { }".to_string())].into(),
+            ..ScriptConfig::default()
+        };
+        let mut spinning = ScriptEngine::new(&config).unwrap();
+        let started = Instant::now();
+        assert!(matches!(
+            spinning.run("spin", context()),
+            Err(ScriptError::TimedOut { limit_ms: 20, .. })
+        ));
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        let mut importing = load(&[("import", r#"import "os" as os;"#)]).unwrap();
+        assert!(importing.run("import", context()).is_err());
+        assert!(load(&[("eval", r#"eval("click()")"#)]).is_err());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let mut config: ScriptConfig = toml::from_str(
+            r#"
+            time_limit_ms = 20
+
+            [scripts]
+            corner = "click();"
+
+            [[bindings]]
+            key = "t"
+            script = "corner"
+            modes = ["grid"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.max_operations, 100_000);
+        assert!(config.validate().is_ok());
+        assert!(config.bindings[0].applies_to(&InteractionMode::Grid));
+        assert!(!config.bindings[0].applies_to(&InteractionMode::Basic));
+
+        config.bindings[0].script = "missing".to_string();
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,722 @@
+//! Scrolling module
+//!
+//! This module provides functionality for:
+//! - Smooth scrolling that glides a scroll over several frames
+//! - Continuous, accelerating scrolling while a scroll key is held
+//! - Inertia that carries a scroll on after the key is released
+//! - Page-wise scrolling by a fraction of the screen
+//! - Pixel-precise output where the backend supports it, wheel notches otherwise
+//! - Per-application scroll multipliers and natural scrolling
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
+
+use crate::{
+    animation_scheduler::AnimationClock,
+    error::{ConfigError, ConfigResult, MouseResult},
+    models::{Position, ScreenBounds, ScrollDirection},
+    screen::ScreenManager,
+    traits::MouseOperations,
+};
+
+/// Longest frame step, so a stalled thread doesn't scroll in one jump
+const MAX_TICK: Duration = Duration::from_millis(100);
+
+/// Page size when no screen is known, in pixels
+const DEFAULT_VIEWPORT: f64 = 1080.0;
+
+/// Scrolling configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScrollConfig {
+    /// Glide scrolls over several frames instead of jumping
+    pub smooth: bool,
+    /// Pixels scrolled by one wheel notch
+    pub pixels_per_notch: f64,
+    /// Invert scrolling so the content follows the direction of the key
+    pub natural_scroll: bool,
+    /// How long a scroll key must be held before continuous scrolling starts
+    pub hold_delay_ms: u64,
+    /// A hold ends when the key neither repeats nor is released for this long
+    pub hold_timeout_ms: u64,
+    /// Speed of continuous scrolling when it starts, in pixels per second
+    pub hold_speed: f64,
+    /// Acceleration of continuous scrolling, in pixels per second squared
+    pub hold_acceleration: f64,
+    /// Top speed of continuous scrolling, in pixels per second
+    pub max_hold_speed: f64,
+    /// Rate at which inertia slows a scroll down, per second
+    pub friction: f64,
+    /// Inertia stops below this speed, in pixels per second
+    pub min_speed: f64,
+    /// Fraction of the screen scrolled by a page scroll
+    pub page_fraction: f64,
+    /// Scroll multipliers by application name, e.g. the X11 window class `firefox`
+    pub app_multipliers: HashMap<String, f64>,
+}
+
+impl Default for ScrollConfig {
+    fn default() -> Self {
+        Self {
+            smooth: true,
+            pixels_per_notch: 40.0,
+            natural_scroll: false,
+            hold_delay_ms: 250,
+            hold_timeout_ms: 1000,
+            hold_speed: 600.0,
+            hold_acceleration: 1500.0,
+            max_hold_speed: 4000.0,
+            friction: 6.0,
+            min_speed: 15.0,
+            page_fraction: 0.875,
+            app_multipliers: HashMap::new(),
+        }
+    }
+}
+
+impl ScrollConfig {
+    /// Get the scroll multiplier of an application, matching names case-insensitively
+    pub fn multiplier_for(&self, application: Option<&str>) -> f64 {
+        application
+            .and_then(|name| {
+                self.app_multipliers
+                    .iter()
+                    .find(|(app, _)| app.eq_ignore_ascii_case(name))
+                    .map(|(_, multiplier)| *multiplier)
+            })
+            .unwrap_or(1.0)
+    }
+
+    /// Get the direction to scroll for a scroll key, applying natural scrolling
+    pub fn oriented(&self, direction: ScrollDirection) -> ScrollDirection {
+        if self.natural_scroll {
+            reversed(direction)
+        } else {
+            direction
+        }
+    }
+
+    /// Convert pixels to whole wheel notches, at least one
+    pub fn notches(&self, pixels: f64) -> i32 {
+        ((pixels / self.pixels_per_notch).round() as i32).max(1)
+    }
+
+    /// Validate the scroll settings
+    pub fn validate(&self) -> ConfigResult<()> {
+        let positive = [
+            ("scroll.pixels_per_notch", self.pixels_per_notch),
+            ("scroll.hold_speed", self.hold_speed),
+            ("scroll.max_hold_speed", self.max_hold_speed),
+            ("scroll.friction", self.friction),
+            ("scroll.min_speed", self.min_speed),
+            ("scroll.page_fraction", self.page_fraction),
+        ];
+        for (field, value) in positive {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(ConfigError::InvalidValue {
+                    field: field.to_string(),
+                    value: value.to_string(),
+                });
+            }
+        }
+
+        if !self.hold_acceleration.is_finite() || self.hold_acceleration < 0.0 {
+            return Err(ConfigError::InvalidValue {
+                field: "scroll.hold_acceleration".to_string(),
+                value: self.hold_acceleration.to_string(),
+            });
+        }
+
+        if self.page_fraction > 1.0 {
+            return Err(ConfigError::InvalidValue {
+                field: "scroll.page_fraction".to_string(),
+                value: self.page_fraction.to_string(),
+            });
+        }
+
+        if self.hold_timeout_ms == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "scroll.hold_timeout_ms".to_string(),
+                value: "0".to_string(),
+            });
+        }
+
+        for (app, multiplier) in &self.app_multipliers {
+            if !multiplier.is_finite() || *multiplier <= 0.0 {
+                return Err(ConfigError::InvalidValue {
+                    field: format!("scroll.app_multipliers.{}", app),
+                    value: multiplier.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Size of the screen under `position` along the axis of a scroll direction
+pub fn page_viewport(
+    screens: &[ScreenBounds],
+    position: Position,
+    direction: ScrollDirection,
+) -> f64 {
+    let placed = ScreenManager::place_on_screens(screens, position);
+    let Some(screen) = screens.iter().find(|s| Some(s.id) == placed.screen_id) else {
+        return DEFAULT_VIEWPORT;
+    };
+
+    match direction {
+        ScrollDirection::Up | ScrollDirection::Down => screen.height as f64,
+        ScrollDirection::Left | ScrollDirection::Right => screen.width as f64,
+    }
+}
+
+fn reversed(direction: ScrollDirection) -> ScrollDirection {
+    match direction {
+        ScrollDirection::Up => ScrollDirection::Down,
+        ScrollDirection::Down => ScrollDirection::Up,
+        ScrollDirection::Left => ScrollDirection::Right,
+        ScrollDirection::Right => ScrollDirection::Left,
+    }
+}
+
+/// A scroll key being held down
+#[derive(Debug, Clone, Copy)]
+struct ScrollHold {
+    direction: ScrollDirection,
+    pressed_at: Duration,
+    refreshed_at: Duration,
+}
+
+/// Unit vector of a scroll direction; positive is down and right
+fn direction_vector(direction: ScrollDirection) -> (f64, f64) {
+    match direction {
+        ScrollDirection::Up => (0.0, -1.0),
+        ScrollDirection::Down => (0.0, 1.0),
+        ScrollDirection::Left => (-1.0, 0.0),
+        ScrollDirection::Right => (1.0, 0.0),
+    }
+}
+
+/// Time-driven scroll engine with smooth glides, holds and inertia
+///
+/// Scrolls are tracked in pixels and sent to a `MouseOperations` backend on
+/// every `tick`, as pixels where the backend supports it and as whole wheel
+/// notches otherwise.
+pub struct SmoothScroller {
+    clock: Arc<dyn AnimationClock>,
+    config: ScrollConfig,
+    application: Option<String>,
+    /// Pixels per second on the horizontal and vertical axis
+    velocity: (f64, f64),
+    /// Pixels to scroll on the next tick without smoothing
+    pending: (f64, f64),
+    /// Pixels scrolled but not yet sent, being less than one unit
+    remainder: (f64, f64),
+    hold: Option<ScrollHold>,
+    last_tick: Duration,
+}
+
+impl SmoothScroller {
+    pub fn new(clock: Arc<dyn AnimationClock>, config: ScrollConfig) -> Self {
+        let last_tick = clock.now();
+        Self {
+            clock,
+            config,
+            application: None,
+            velocity: (0.0, 0.0),
+            pending: (0.0, 0.0),
+            remainder: (0.0, 0.0),
+            hold: None,
+            last_tick,
+        }
+    }
+
+    /// Set the scroll configuration
+    pub fn set_config(&mut self, config: ScrollConfig) {
+        self.config = config;
+    }
+
+    /// Get the scroll configuration
+    pub fn config(&self) -> &ScrollConfig {
+        &self.config
+    }
+
+    /// Set the application being scrolled, selecting its scroll multiplier
+    pub fn set_application(&mut self, application: Option<String>) {
+        self.application = application;
+    }
+
+    /// Check if the scroller still has scrolling to do
+    pub fn is_active(&self) -> bool {
+        self.hold.is_some() || self.velocity != (0.0, 0.0) || self.pending != (0.0, 0.0)
+    }
+
+    /// Scroll by wheel notches as a scroll key goes down
+    ///
+    /// The key counts as held until `release`; a repeat of the held key only
+    /// keeps the hold alive.
+    pub fn scroll(&mut self, direction: ScrollDirection, notches: i32) {
+        let now = self.clock.now();
+        if let Some(hold) = &mut self.hold {
+            if hold.direction == direction {
+                hold.refreshed_at = now;
+                return;
+            }
+        }
+
+        self.push(direction, notches as f64 * self.config.pixels_per_notch);
+        self.hold = Some(ScrollHold {
+            direction,
+            pressed_at: now,
+            refreshed_at: now,
+        });
+    }
+
+    /// End the hold of a scroll key, leaving the scroll to its inertia
+    pub fn release(&mut self, direction: ScrollDirection) {
+        if self.hold.map(|hold| hold.direction) == Some(direction) {
+            self.hold = None;
+            if !self.config.smooth {
+                self.velocity = (0.0, 0.0);
+            }
+        }
+    }
+
+    /// Scroll by a page of a screen `viewport` pixels high or wide
+    pub fn page(&mut self, direction: ScrollDirection, viewport: f64) {
+        self.push(direction, viewport * self.config.page_fraction);
+    }
+
+    /// Stop all scrolling at once
+    pub fn stop(&mut self) {
+        self.hold = None;
+        self.velocity = (0.0, 0.0);
+        self.pending = (0.0, 0.0);
+        self.remainder = (0.0, 0.0);
+    }
+
+    /// Queue a scroll of `distance` pixels
+    fn push(&mut self, direction: ScrollDirection, distance: f64) {
+        if !self.is_active() {
+            self.last_tick = self.clock.now();
+        }
+
+        let (ux, uy) = direction_vector(direction);
+        if self.config.smooth {
+            // Reversing stops the glide before pushing the other way
+            if self.velocity.0 * ux < 0.0 {
+                self.velocity.0 = 0.0;
+            }
+            if self.velocity.1 * uy < 0.0 {
+                self.velocity.1 = 0.0;
+            }
+
+            // With exponential friction an impulse of v glides v / friction pixels
+            let speed = distance * self.config.friction;
+            self.velocity.0 += ux * speed;
+            self.velocity.1 += uy * speed;
+        } else {
+            self.pending.0 += ux * distance;
+            self.pending.1 += uy * distance;
+        }
+    }
+
+    /// Advance scrolling to the current time and send it to `mouse`
+    pub fn tick(&mut self, mouse: &mut dyn MouseOperations) -> MouseResult<()> {
+        let now = self.clock.now();
+        let dt = now
+            .saturating_sub(self.last_tick)
+            .min(MAX_TICK)
+            .as_secs_f64();
+        self.last_tick = now;
+
+        if let Some(hold) = self.hold {
+            let timeout = Duration::from_millis(self.config.hold_timeout_ms);
+            if now.saturating_sub(hold.refreshed_at) > timeout {
+                debug!("Scroll hold {:?} timed out", hold.direction);
+                self.release(hold.direction);
+            }
+        }
+
+        // Continuous scrolling drives the held axis once the hold delay passed
+        let held = self.hold.and_then(|hold| {
+            let delay = Duration::from_millis(self.config.hold_delay_ms);
+            let held_for = now.saturating_sub(hold.pressed_at).checked_sub(delay)?;
+            let speed = (self.config.hold_speed
+                + self.config.hold_acceleration * held_for.as_secs_f64())
+            .min(self.config.max_hold_speed);
+            let (ux, uy) = direction_vector(hold.direction);
+            Some((ux * speed, uy * speed))
+        });
+
+        let pending = std::mem::take(&mut self.pending);
+        let (vx, dx) = self.advance_axis(self.velocity.0, held.map(|v| v.0), dt);
+        let (vy, dy) = self.advance_axis(self.velocity.1, held.map(|v| v.1), dt);
+        self.velocity = (vx, vy);
+
+        self.send((pending.0 + dx, pending.1 + dy), mouse)
+    }
+
+    /// Move one axis forward by `dt`, returning its new velocity and distance
+    fn advance_axis(&self, velocity: f64, held: Option<f64>, dt: f64) -> (f64, f64) {
+        if let Some(held) = held.filter(|v| *v != 0.0) {
+            // Never slow down a glide that is faster than the hold
+            let velocity = if velocity * held > 0.0 && velocity.abs() > held.abs() {
+                velocity
+            } else {
+                held
+            };
+            return (velocity, velocity * dt);
+        }
+
+        if velocity == 0.0 {
+            return (0.0, 0.0);
+        }
+
+        let friction = self.config.friction;
+        let decay = (-friction * dt).exp();
+        let distance = velocity * (1.0 - decay) / friction;
+        let velocity = velocity * decay;
+
+        if velocity.abs() < self.config.min_speed {
+            // Finish the glide so an impulse scrolls its full distance
+            (0.0, distance + velocity / friction)
+        } else {
+            (velocity, distance)
+        }
+    }
+
+    /// Send scrolled pixels to the backend in whole units
+    fn send(&mut self, distance: (f64, f64), mouse: &mut dyn MouseOperations) -> MouseResult<()> {
+        let mut scale = self.config.multiplier_for(self.application.as_deref());
+        if self.config.natural_scroll {
+            scale = -scale;
+        }
+        self.remainder.0 += distance.0 * scale;
+        self.remainder.1 += distance.1 * scale;
+
+        let pixels = mouse.supports_pixel_scroll();
+        let unit = if pixels {
+            1.0
+        } else {
+            self.config.pixels_per_notch
+        };
+
+        // Round off what is left once scrolling ends, so nothing is lost
+        let idle = !self.is_active();
+        let take_units = |remainder: &mut f64| {
+            let units = if idle {
+                (*remainder / unit).round()
+            } else {
+                (*remainder / unit).trunc()
+            };
+            *remainder = if idle { 0.0 } else { *remainder - units * unit };
+            units as i32
+        };
+        let horizontal = take_units(&mut self.remainder.0);
+        let vertical = take_units(&mut self.remainder.1);
+
+        for (units, negative, positive) in [
+            (horizontal, ScrollDirection::Left, ScrollDirection::Right),
+            (vertical, ScrollDirection::Up, ScrollDirection::Down),
+        ] {
+            if units == 0 {
+                continue;
+            }
+            let direction = if units < 0 { negative } else { positive };
+            if pixels {
+                mouse.scroll_pixels(direction, units.abs())?;
+            } else {
+                mouse.scroll(direction, units.abs())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        animation_scheduler::VirtualClock,
+        models::{AnimationType, MouseButton, MovementSpeed},
+    };
+
+    /// Records the scrolled amount per direction
+    struct MockMouse {
+        pixels: bool,
+        scrolled: Vec<(ScrollDirection, i32)>,
+    }
+
+    impl MockMouse {
+        fn new(pixels: bool) -> Self {
+            Self {
+                pixels,
+                scrolled: Vec::new(),
+            }
+        }
+
+        fn scrolled(&self, direction: ScrollDirection) -> i32 {
+            self.scrolled
+                .iter()
+                .filter(|(d, _)| *d == direction)
+                .map(|(_, amount)| amount)
+                .sum()
+        }
+    }
+
+    impl MouseOperations for MockMouse {
+        fn move_to(&mut self, _position: Position, _animation: AnimationType) -> MouseResult<()> {
+            Ok(())
+        }
+
+        fn click(&mut self, _button: MouseButton) -> MouseResult<()> {
+            Ok(())
+        }
+
+        fn scroll(&mut self, direction: ScrollDirection, amount: i32) -> MouseResult<()> {
+            assert!(!self.pixels);
+            self.scrolled.push((direction, amount));
+            Ok(())
+        }
+
+        fn supports_pixel_scroll(&self) -> bool {
+            self.pixels
+        }
+
+        fn scroll_pixels(&mut self, direction: ScrollDirection, pixels: i32) -> MouseResult<()> {
+            assert!(self.pixels);
+            self.scrolled.push((direction, pixels));
+            Ok(())
+        }
+
+        fn get_current_position(&self) -> MouseResult<Position> {
+            Ok(Position::new(0, 0))
+        }
+
+        fn get_screen_bounds(&self) -> MouseResult<Vec<ScreenBounds>> {
+            Ok(Vec::new())
+        }
+
+        fn set_movement_speed(&mut self, _speed: MovementSpeed) {}
+
+        fn get_movement_speed(&self) -> MovementSpeed {
+            MovementSpeed::Normal
+        }
+    }
+
+    fn create_scroller(config: ScrollConfig) -> (Arc<VirtualClock>, SmoothScroller) {
+        let clock = Arc::new(VirtualClock::new());
+        let scroller = SmoothScroller::new(clock.clone(), config);
+        (clock, scroller)
+    }
+
+    /// Tick at 8ms frames for `duration`
+    fn run_for(
+        clock: &VirtualClock,
+        scroller: &mut SmoothScroller,
+        mouse: &mut MockMouse,
+        duration: Duration,
+    ) {
+        let frame = Duration::from_millis(8);
+        let mut elapsed = Duration::ZERO;
+        while elapsed < duration {
+            clock.advance(frame);
+            elapsed += frame;
+            scroller.tick(mouse).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_tap_glides_its_full_distance() {
+        let (clock, mut scroller) = create_scroller(ScrollConfig::default());
+        let mut mouse = MockMouse::new(true);
+
+        scroller.scroll(ScrollDirection::Down, 3);
+        scroller.release(ScrollDirection::Down);
+        assert!(scroller.is_active());
+
+        // The glide is spread over several frames
+        run_for(&clock, &mut scroller, &mut mouse, Duration::from_millis(16));
+        let early = mouse.scrolled(ScrollDirection::Down);
+        assert!(early > 0 && early < 120);
+
+        run_for(&clock, &mut scroller, &mut mouse, Duration::from_secs(2));
+        assert!(!scroller.is_active());
+        assert_eq!(mouse.scrolled(ScrollDirection::Down), 120);
+    }
+
+    #[test]
+    fn test_notch_backend_gets_whole_notches() {
+        let (clock, mut scroller) = create_scroller(ScrollConfig::default());
+        let mut mouse = MockMouse::new(false);
+
+        scroller.scroll(ScrollDirection::Left, 3);
+        scroller.release(ScrollDirection::Left);
+        run_for(&clock, &mut scroller, &mut mouse, Duration::from_secs(2));
+
+        assert_eq!(mouse.scrolled(ScrollDirection::Left), 3);
+        assert_eq!(mouse.scrolled(ScrollDirection::Right), 0);
+    }
+
+    #[test]
+    fn test_hold_accelerates_and_coasts_after_release() {
+        let (clock, mut scroller) = create_scroller(ScrollConfig::default());
+        let mut mouse = MockMouse::new(true);
+
+        scroller.scroll(ScrollDirection::Down, 1);
+        run_for(
+            &clock,
+            &mut scroller,
+            &mut mouse,
+            Duration::from_millis(400),
+        );
+
+        let start = mouse.scrolled(ScrollDirection::Down);
+        run_for(
+            &clock,
+            &mut scroller,
+            &mut mouse,
+            Duration::from_millis(200),
+        );
+        let first = mouse.scrolled(ScrollDirection::Down) - start;
+
+        // Key repeats keep the hold alive without extra impulses
+        scroller.scroll(ScrollDirection::Down, 1);
+        let start = mouse.scrolled(ScrollDirection::Down);
+        run_for(
+            &clock,
+            &mut scroller,
+            &mut mouse,
+            Duration::from_millis(200),
+        );
+        let second = mouse.scrolled(ScrollDirection::Down) - start;
+        assert!(second > first, "{} should exceed {}", second, first);
+
+        // Inertia carries on after the release, then stops
+        scroller.release(ScrollDirection::Down);
+        let released = mouse.scrolled(ScrollDirection::Down);
+        run_for(&clock, &mut scroller, &mut mouse, Duration::from_secs(3));
+        assert!(mouse.scrolled(ScrollDirection::Down) > released);
+        assert!(!scroller.is_active());
+    }
+
+    #[test]
+    fn test_hold_times_out_without_release() {
+        let (clock, mut scroller) = create_scroller(ScrollConfig::default());
+        let mut mouse = MockMouse::new(true);
+
+        scroller.scroll(ScrollDirection::Up, 1);
+        run_for(&clock, &mut scroller, &mut mouse, Duration::from_secs(5));
+        assert!(!scroller.is_active());
+    }
+
+    #[test]
+    fn test_natural_scroll_and_app_multiplier() {
+        let mut config = ScrollConfig {
+            natural_scroll: true,
+            ..ScrollConfig::default()
+        };
+        config.app_multipliers.insert("firefox".to_string(), 2.0);
+        let (clock, mut scroller) = create_scroller(config);
+        let mut mouse = MockMouse::new(false);
+
+        scroller.set_application(Some("Firefox".to_string()));
+        scroller.scroll(ScrollDirection::Down, 3);
+        scroller.release(ScrollDirection::Down);
+        run_for(&clock, &mut scroller, &mut mouse, Duration::from_secs(2));
+
+        assert_eq!(mouse.scrolled(ScrollDirection::Up), 6);
+        assert_eq!(mouse.scrolled(ScrollDirection::Down), 0);
+    }
+
+    #[test]
+    fn test_page_scroll() {
+        let (clock, mut scroller) = create_scroller(ScrollConfig::default());
+        let mut mouse = MockMouse::new(true);
+
+        scroller.page(ScrollDirection::Down, 800.0);
+        run_for(&clock, &mut scroller, &mut mouse, Duration::from_secs(2));
+        assert_eq!(mouse.scrolled(ScrollDirection::Down), 700);
+    }
+
+    #[test]
+    fn test_instant_scroll_without_smoothing() {
+        let config = ScrollConfig {
+            smooth: false,
+            ..ScrollConfig::default()
+        };
+        let (clock, mut scroller) = create_scroller(config);
+        let mut mouse = MockMouse::new(false);
+
+        scroller.scroll(ScrollDirection::Right, 2);
+        scroller.release(ScrollDirection::Right);
+        run_for(&clock, &mut scroller, &mut mouse, Duration::from_millis(8));
+
+        assert_eq!(mouse.scrolled(ScrollDirection::Right), 2);
+        assert!(!scroller.is_active());
+    }
+
+    #[test]
+    fn test_oriented_and_page_viewport() {
+        let config = ScrollConfig {
+            natural_scroll: true,
+            ..ScrollConfig::default()
+        };
+        assert_eq!(
+            config.oriented(ScrollDirection::Left),
+            ScrollDirection::Right
+        );
+        assert_eq!(config.notches(60.0), 2);
+        assert_eq!(config.notches(5.0), 1);
+
+        let screens = vec![ScreenBounds {
+            id: 1,
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            is_primary: true,
+            scale_factor: 1.0,
+        }];
+        let position = Position::new(100, 100);
+        assert_eq!(
+            page_viewport(&screens, position, ScrollDirection::Down),
+            1080.0
+        );
+        assert_eq!(
+            page_viewport(&screens, position, ScrollDirection::Right),
+            1920.0
+        );
+        assert_eq!(
+            page_viewport(&[], position, ScrollDirection::Up),
+            DEFAULT_VIEWPORT
+        );
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(ScrollConfig::default().validate().is_ok());
+
+        let config = ScrollConfig {
+            friction: 0.0,
+            ..ScrollConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = ScrollConfig {
+            page_fraction: 1.5,
+            ..ScrollConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let mut config = ScrollConfig::default();
+        config.app_multipliers.insert("code".to_string(), -1.0);
+        assert!(config.validate().is_err());
+    }
+}
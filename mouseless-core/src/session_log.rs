@@ -0,0 +1,785 @@
+//! Session log module
+//!
+//! This module provides functionality for:
+//! - Writing key presses, mode events, actions and mouse operations to a JSONL file
+//! - Filtering keys and cursor positions out of the log for privacy
+//! - Replaying a log through a fresh `ModeManager` with a virtual clock and an
+//!   in-memory mouse, and diffing the actions it produces against the log
+//!
+//! Each line holds one entry with the milliseconds since the log was opened:
+//!
+//! ```text
+//! {"t":0,"kind":"setup","version":"0.1.0",...}
+//! {"t":812,"kind":"key","key":"g"}
+//! {"t":812,"kind":"action","action":{"ActivateMode":"Grid"},"source":"mode"}
+//! {"t":812,"kind":"mode","event":{"ModeActivated":"Grid"}}
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tracing::{debug, info};
+
+use crate::{
+    animation_scheduler::{AnimationClock, SharedClock, SystemClock, VirtualClock},
+    area_mode::AreaLayout,
+    config::{AppConfig, MovementConfig},
+    error::{ConfigError, ConfigResult, Result, SessionError, SessionResult},
+    executor::ActionExecutor,
+    keyboard_layout::KeyboardConfig,
+    macros::MacroConfig,
+    mode::{ModeEvent, ModeManager},
+    models::{Action, KeyInput, KeyModifier, MultiScreenLayout, Position, ScreenBounds},
+    recording_mouse::{MouseOp, RecordingMouse, VirtualMouse},
+    renderer::NullRenderer,
+    scripting::ScriptBinding,
+    traits::{KeyBindings, ModeController},
+};
+
+/// Name of the session log, next to the configuration file
+const SESSION_LOG_FILE_NAME: &str = "session.jsonl";
+
+/// Session log configuration
+///
+/// ```toml
+/// [session_log]
+/// enabled = true
+///
+/// [session_log.privacy]
+/// record_keys = true
+/// position_precision = 10
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionLogConfig {
+    /// Write a session log; off unless asked for
+    pub enabled: bool,
+    /// Log file, `session.jsonl` next to the configuration file when unset
+    pub file: Option<PathBuf>,
+    /// What is left out of the log
+    pub privacy: PrivacyFilter,
+}
+
+impl SessionLogConfig {
+    /// Validate the privacy settings
+    pub fn validate(&self) -> ConfigResult<()> {
+        self.privacy.validate()
+    }
+
+    /// Path of the session log for a configuration file
+    pub fn file_for(&self, config_path: &Path) -> PathBuf {
+        self.file
+            .clone()
+            .unwrap_or_else(|| config_path.with_file_name(SESSION_LOG_FILE_NAME))
+    }
+}
+
+/// What a session log leaves out
+///
+/// Logs are meant to be attached to bug reports, so the filter is applied
+/// before anything is written. Replays apply it to the actions they produce
+/// too, so a filtered log still diffs cleanly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrivacyFilter {
+    /// Write which keys were pressed; a log without keys cannot be replayed
+    pub record_keys: bool,
+    /// Round cursor positions down to a grid of this many pixels; 1 keeps them exact
+    pub position_precision: u32,
+}
+
+impl Default for PrivacyFilter {
+    fn default() -> Self {
+        Self {
+            record_keys: true,
+            position_precision: 1,
+        }
+    }
+}
+
+impl PrivacyFilter {
+    /// Validate the position precision
+    pub fn validate(&self) -> ConfigResult<()> {
+        if self.position_precision == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "session_log.privacy.position_precision".to_string(),
+                value: "0".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Round a position to the configured precision
+    pub fn position(&self, position: Position) -> Position {
+        let precision = self.position_precision.max(1) as i32;
+        Position {
+            x: position.x.div_euclid(precision) * precision,
+            y: position.y.div_euclid(precision) * precision,
+            ..position
+        }
+    }
+
+    /// Filter the positions in an action
+    pub fn action(&self, action: Action) -> Action {
+        match action {
+            Action::MoveCursor(position, animation) => {
+                Action::MoveCursor(self.position(position), animation)
+            }
+            action => action,
+        }
+    }
+
+    /// Filter an entry before it is written
+    pub fn entry(&self, entry: SessionEntry) -> SessionEntry {
+        match entry {
+            SessionEntry::Setup(mut setup) => {
+                setup.cursor = self.position(setup.cursor);
+                SessionEntry::Setup(setup)
+            }
+            SessionEntry::Key {
+                key,
+                modifiers,
+                release,
+            } => SessionEntry::Key {
+                key: key.filter(|_| self.record_keys),
+                modifiers,
+                release,
+            },
+            SessionEntry::Mode {
+                event: ModeEvent::InputProcessed { mode, action },
+            } => SessionEntry::Mode {
+                event: ModeEvent::InputProcessed {
+                    mode,
+                    action: self.action(action),
+                },
+            },
+            SessionEntry::Action { action, source } => SessionEntry::Action {
+                action: self.action(action),
+                source,
+            },
+            SessionEntry::Mouse(MouseOp::MoveTo {
+                position,
+                animation,
+            }) => SessionEntry::Mouse(MouseOp::MoveTo {
+                position: self.position(position),
+                animation,
+            }),
+            entry => entry,
+        }
+    }
+}
+
+/// Where an action in the log came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionSource {
+    /// Returned by the mode manager for a logged key; replays compare these
+    Mode,
+    /// Returned by a tick of the current mode, e.g. when a key sequence timed
+    /// out; replays tick the mode at the same time and compare these
+    Tick,
+    /// Run by the host itself, e.g. for the activation hotkey, a script or
+    /// the control socket; replays run these again
+    Host,
+}
+
+/// The configuration and screens that the entries after it ran with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSetup {
+    pub version: String,
+    pub key_bindings: KeyBindings,
+    pub movement: MovementConfig,
+    pub keyboard: KeyboardConfig,
+    pub area: AreaLayout,
+    pub multi_screen: MultiScreenLayout,
+    pub script_bindings: Vec<ScriptBinding>,
+    pub macros: MacroConfig,
+    pub screens: Vec<ScreenBounds>,
+    pub cursor: Position,
+    pub privacy: PrivacyFilter,
+}
+
+impl SessionSetup {
+    /// Describe the mode-related parts of a configuration
+    pub fn new(config: &AppConfig, screens: Vec<ScreenBounds>, cursor: Position) -> Self {
+        Self {
+            version: crate::VERSION.to_string(),
+            // The configuration was validated, so its layout resolves
+            key_bindings: config.key_bindings().unwrap_or_default(),
+            movement: config.movement.clone(),
+            keyboard: config.keyboard.clone(),
+            area: config.area.clone(),
+            multi_screen: config.multi_screen,
+            script_bindings: config.scripting.bindings.clone(),
+            // Where macros are stored is of no use to a replay
+            macros: MacroConfig {
+                file: None,
+                ..config.macros.clone()
+            },
+            screens,
+            cursor,
+            privacy: config.session_log.privacy,
+        }
+    }
+
+    /// Apply the setup to a mode manager
+    pub fn apply(&self, modes: &ModeManager) -> Result<()> {
+        modes.update_key_bindings(self.key_bindings.clone());
+        modes.set_movement_config(self.movement.clone());
+        modes.set_keyboard_layout(self.keyboard.resolve_layout()?)?;
+        modes.set_area_layout(self.area.clone())?;
+        modes.set_multi_screen_layout(self.multi_screen)?;
+        modes.set_script_bindings(self.script_bindings.clone());
+        modes.set_macro_config(self.macros.clone());
+        modes.set_screens(self.screens.clone())
+    }
+}
+
+/// One entry of a session log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SessionEntry {
+    Setup(Box<SessionSetup>),
+    /// A key handed to the mode manager; `None` when keys are filtered out
+    Key {
+        key: Option<char>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        modifiers: Vec<KeyModifier>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        release: bool,
+    },
+    Mode {
+        event: ModeEvent,
+    },
+    Action {
+        action: Action,
+        source: ActionSource,
+    },
+    Mouse(MouseOp),
+}
+
+/// A session log entry and when it was written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    /// Milliseconds since the log was opened
+    pub t: u64,
+    #[serde(flatten)]
+    pub entry: SessionEntry,
+}
+
+/// Writes a session log, one JSON entry per line
+pub struct SessionLog {
+    writer: Box<dyn Write + Send>,
+    privacy: PrivacyFilter,
+    clock: SharedClock,
+    start: Duration,
+}
+
+impl SessionLog {
+    /// Create a log file, replacing an older log at the same path
+    pub fn create<P: AsRef<Path>>(path: P, privacy: PrivacyFilter) -> SessionResult<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| SessionError::OpenFailed {
+                path: parent.display().to_string(),
+                reason: e.to_string(),
+            })?;
+        }
+        let file = File::create(path).map_err(|e| SessionError::OpenFailed {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+        info!("📝 Writing session log to {:?}", path);
+        Ok(Self::with_writer(
+            BufWriter::new(file),
+            privacy,
+            Arc::new(SystemClock::new()),
+        ))
+    }
+
+    /// Write a log to any writer, timed with a clock
+    pub fn with_writer<W: Write + Send + 'static>(
+        writer: W,
+        privacy: PrivacyFilter,
+        clock: SharedClock,
+    ) -> Self {
+        let start = clock.now();
+        Self {
+            writer: Box::new(writer),
+            privacy,
+            clock,
+            start,
+        }
+    }
+
+    /// Replace the privacy filter for the entries that follow
+    pub fn set_privacy(&mut self, privacy: PrivacyFilter) {
+        self.privacy = privacy;
+    }
+
+    /// Filter an entry and write it; every entry is flushed so a crash loses nothing
+    pub fn log(&mut self, entry: SessionEntry) -> SessionResult<()> {
+        let record = SessionRecord {
+            t: self.clock.now().saturating_sub(self.start).as_millis() as u64,
+            entry: self.privacy.entry(entry),
+        };
+        let line = serde_json::to_string(&record).map_err(|e| SessionError::WriteFailed {
+            reason: e.to_string(),
+        })?;
+
+        writeln!(self.writer, "{}", line)
+            .and_then(|_| self.writer.flush())
+            .map_err(|e| SessionError::WriteFailed {
+                reason: e.to_string(),
+            })
+    }
+
+    /// Write a key handed to the mode manager
+    pub fn log_key(&mut self, input: &KeyInput, release: bool) -> SessionResult<()> {
+        self.log(SessionEntry::Key {
+            key: Some(input.key),
+            modifiers: input.modifiers.clone(),
+            release,
+        })
+    }
+}
+
+/// Read a session log file
+pub fn read_session_log<P: AsRef<Path>>(path: P) -> SessionResult<Vec<SessionRecord>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| SessionError::OpenFailed {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    parse_session_log(BufReader::new(file))
+}
+
+/// Parse session log lines, skipping blank ones
+pub fn parse_session_log<R: BufRead>(reader: R) -> SessionResult<Vec<SessionRecord>> {
+    let mut records = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| SessionError::InvalidEntry {
+            line: index + 1,
+            reason: e.to_string(),
+        })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        records.push(
+            serde_json::from_str(&line).map_err(|e| SessionError::InvalidEntry {
+                line: index + 1,
+                reason: e.to_string(),
+            })?,
+        );
+    }
+    Ok(records)
+}
+
+/// An action the replay produced differently from the log
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReplayMismatch {
+    /// Position among the actions returned by the mode manager
+    pub index: usize,
+    /// Milliseconds into the session
+    pub t: u64,
+    /// Action in the log, if the log has that many
+    pub expected: Option<Action>,
+    /// Action the replay produced, if it produced that many
+    pub actual: Option<Action>,
+}
+
+/// Result of replaying a session log
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReplayReport {
+    /// Keys fed to the mode manager
+    pub keys: usize,
+    /// Actions the log says the mode manager returned
+    pub recorded: Vec<Action>,
+    /// Actions the replay's mode manager returned
+    pub replayed: Vec<Action>,
+    /// Mouse operations of the replay
+    pub mouse_ops: Vec<MouseOp>,
+    /// Where the replay differs from the log
+    pub mismatches: Vec<ReplayMismatch>,
+}
+
+impl ReplayReport {
+    /// Check if the replay produced exactly the logged actions
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Replay a session log and diff the actions against it
+///
+/// Keys go through a fresh mode manager whose clock is moved to each entry's
+/// time, so key sequence timeouts behave as they did. The mode is ticked
+/// where a tick produced an action in the session, so timers fire between the
+/// same keys. Actions the host ran itself are run again; actions the mode
+/// manager returned for keys and ticks are compared.
+pub async fn replay_session(records: &[SessionRecord]) -> Result<ReplayReport> {
+    let clock = Arc::new(VirtualClock::new());
+    let modes = ModeManager::new(KeyBindings::default());
+    modes.set_clock(clock.clone());
+    let mut mouse = RecordingMouse::new(VirtualMouse::default());
+    mouse.set_recording(true);
+    let mut executor = ActionExecutor::new(modes, mouse, NullRenderer::new());
+
+    let mut privacy = PrivacyFilter::default();
+    let mut report = ReplayReport::default();
+    let mut recorded_times = Vec::new();
+    let mut replayed_times = Vec::new();
+
+    for record in records {
+        clock.advance(Duration::from_millis(record.t).saturating_sub(clock.now()));
+
+        match &record.entry {
+            SessionEntry::Setup(setup) => {
+                setup.apply(executor.modes())?;
+                executor.set_default_animation(setup.movement.default_animation);
+                let mouse = executor.mouse_mut().inner_mut();
+                mouse.set_screens(setup.screens.clone());
+                mouse.set_position(setup.cursor);
+                privacy = setup.privacy;
+            }
+            SessionEntry::Key {
+                key,
+                modifiers,
+                release,
+            } => {
+                let key = key.ok_or_else(|| SessionError::NotReplayable {
+                    reason: "keys were filtered out of the log".to_string(),
+                })?;
+                let input = KeyInput {
+                    key,
+                    modifiers: modifiers.clone(),
+                    timestamp: SystemTime::UNIX_EPOCH + Duration::from_millis(record.t),
+                };
+                report.keys += 1;
+
+                let modes = executor.modes();
+                let action = if *release {
+                    modes.handle_key_release(input)
+                } else {
+                    modes.handle_input(input).await
+                };
+                // Keys that failed in the mode did not log an action either
+                let Ok(action) = action else { continue };
+
+                report.replayed.push(privacy.action(action.clone()));
+                replayed_times.push(record.t);
+                if let Err(e) = executor.execute(action).await {
+                    debug!("Replayed action failed: {}", e);
+                }
+            }
+            SessionEntry::Action {
+                action,
+                source: ActionSource::Mode,
+            } => {
+                report.recorded.push(action.clone());
+                recorded_times.push(record.t);
+            }
+            SessionEntry::Action {
+                action,
+                source: ActionSource::Tick,
+            } => {
+                report.recorded.push(action.clone());
+                recorded_times.push(record.t);
+
+                // A tick that produces nothing shows up as a missing action
+                let action = match executor.modes().tick() {
+                    Ok(Action::NoAction) | Err(_) => continue,
+                    Ok(action) => action,
+                };
+                report.replayed.push(privacy.action(action.clone()));
+                replayed_times.push(record.t);
+                if let Err(e) = executor.execute(action).await {
+                    debug!("Replayed tick action failed: {}", e);
+                }
+            }
+            SessionEntry::Action {
+                action,
+                source: ActionSource::Host,
+            } => {
+                if let Err(e) = executor.execute(action.clone()).await {
+                    debug!("Replayed host action failed: {}", e);
+                }
+            }
+            SessionEntry::Mode { .. } | SessionEntry::Mouse(_) => {}
+        }
+    }
+
+    let count = report.recorded.len().max(report.replayed.len());
+    for index in 0..count {
+        let expected = report.recorded.get(index).cloned();
+        let actual = report.replayed.get(index).cloned();
+        if expected != actual {
+            report.mismatches.push(ReplayMismatch {
+                index,
+                t: recorded_times
+                    .get(index)
+                    .or_else(|| replayed_times.get(index))
+                    .copied()
+                    .unwrap_or_default(),
+                expected,
+                actual,
+            });
+        }
+    }
+    report.mouse_ops = executor.mouse_mut().take_ops();
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AnimationType, InteractionMode, MouseButton};
+    use std::sync::Mutex;
+
+    /// A writer whose output stays readable after the log took it
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn screens() -> Vec<ScreenBounds> {
+        vec![ScreenBounds {
+            id: 0,
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            is_primary: true,
+            scale_factor: 1.0,
+        }]
+    }
+
+    fn key(key: char) -> KeyInput {
+        KeyInput {
+            key,
+            modifiers: Vec::new(),
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    /// Runs keys and ticks through a mode manager the way the daemon does, logging everything
+    struct SessionRecorder {
+        buffer: SharedBuffer,
+        clock: Arc<VirtualClock>,
+        log: SessionLog,
+        executor: ActionExecutor<ModeManager, RecordingMouse<VirtualMouse>, NullRenderer>,
+    }
+
+    impl SessionRecorder {
+        async fn start(mode: InteractionMode, privacy: PrivacyFilter) -> Self {
+            let buffer = SharedBuffer::default();
+            let clock = Arc::new(VirtualClock::new());
+            let mut log = SessionLog::with_writer(buffer.clone(), privacy, clock.clone());
+
+            let config = AppConfig {
+                session_log: SessionLogConfig {
+                    enabled: true,
+                    file: None,
+                    privacy,
+                },
+                ..AppConfig::default()
+            };
+            let setup = SessionSetup::new(&config, screens(), Position::new(500, 500));
+            let modes = ModeManager::new(KeyBindings::default());
+            modes.set_clock(clock.clone());
+            setup.apply(&modes).unwrap();
+            log.log(SessionEntry::Setup(Box::new(setup))).unwrap();
+
+            let mut mouse =
+                RecordingMouse::new(VirtualMouse::new(Position::new(500, 500), screens()));
+            mouse.set_recording(true);
+            let executor = ActionExecutor::new(modes, mouse, NullRenderer::new());
+
+            let mut recorder = Self {
+                buffer,
+                clock,
+                log,
+                executor,
+            };
+            recorder
+                .run(Action::ActivateMode(mode), ActionSource::Host)
+                .await;
+            recorder
+        }
+
+        async fn run(&mut self, action: Action, source: ActionSource) {
+            self.log
+                .log(SessionEntry::Action {
+                    action: action.clone(),
+                    source,
+                })
+                .unwrap();
+            self.executor.execute(action).await.unwrap();
+            for op in self.executor.mouse_mut().take_ops() {
+                self.log.log(SessionEntry::Mouse(op)).unwrap();
+            }
+        }
+
+        async fn press(&mut self, key_char: char) {
+            for release in [false, true] {
+                let input = key(key_char);
+                self.log.log_key(&input, release).unwrap();
+                let modes = self.executor.modes();
+                let action = if release {
+                    modes.handle_key_release(input)
+                } else {
+                    modes.handle_input(input).await
+                }
+                .unwrap();
+                self.run(action, ActionSource::Mode).await;
+            }
+        }
+
+        /// Let time pass, ticking the mode every 50ms
+        async fn wait(&mut self, millis: u64) {
+            for _ in 0..millis / 50 {
+                self.clock.advance(Duration::from_millis(50));
+                match self.executor.modes().tick().unwrap() {
+                    Action::NoAction => {}
+                    action => self.run(action, ActionSource::Tick).await,
+                }
+            }
+        }
+
+        fn records(&self) -> Vec<SessionRecord> {
+            let output = self.buffer.0.lock().unwrap().clone();
+            parse_session_log(output.as_slice()).unwrap()
+        }
+    }
+
+    /// Press keys in basic mode 150ms apart, without ticks
+    async fn record_session(keys: &[char], privacy: PrivacyFilter) -> Vec<SessionRecord> {
+        let mut recorder = SessionRecorder::start(InteractionMode::Basic, privacy).await;
+        for &key_char in keys {
+            recorder.clock.advance(Duration::from_millis(150));
+            recorder.press(key_char).await;
+        }
+        recorder.records()
+    }
+
+    #[tokio::test]
+    async fn test_replay_matches_recording() {
+        // Move right, enter grid mode and pick a cell
+        let records = record_session(&['l', 'g', 'a', 'a'], PrivacyFilter::default()).await;
+        assert!(matches!(records[0].entry, SessionEntry::Setup(_)));
+        assert!(records.iter().any(|record| matches!(
+            record.entry,
+            SessionEntry::Mouse(MouseOp::MoveRelative { .. })
+        )));
+
+        let report = replay_session(&records).await.unwrap();
+        assert_eq!(report.keys, 8);
+        assert_eq!(report.recorded.len(), 8);
+        assert!(report.is_match(), "{:?}", report.mismatches);
+        assert!(!report.mouse_ops.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_ticks_area_timeout() {
+        let mut recorder =
+            SessionRecorder::start(InteractionMode::Area, PrivacyFilter::default()).await;
+        // The first key times out and moves to its area, so the next key
+        // starts a new combination instead of completing the first one
+        recorder.press('q').await;
+        recorder.wait(500).await;
+        recorder.press('e').await;
+        recorder.wait(500).await;
+        let records = recorder.records();
+
+        let ticks = records
+            .iter()
+            .filter(|record| {
+                matches!(
+                    record.entry,
+                    SessionEntry::Action {
+                        action: Action::MoveCursor(..),
+                        source: ActionSource::Tick,
+                    }
+                )
+            })
+            .count();
+        assert_eq!(ticks, 2);
+
+        let report = replay_session(&records).await.unwrap();
+        assert_eq!(report.recorded.len(), 6);
+        assert!(report.is_match(), "{:?}", report.mismatches);
+    }
+
+    #[tokio::test]
+    async fn test_replay_reports_mismatches() {
+        let mut records = record_session(&['n', 'm'], PrivacyFilter::default()).await;
+        for record in &mut records {
+            if let SessionEntry::Action {
+                action: Action::Click(MouseButton::Right),
+                ..
+            } = &record.entry
+            {
+                record.entry = SessionEntry::Action {
+                    action: Action::Click(MouseButton::Middle),
+                    source: ActionSource::Mode,
+                };
+            }
+        }
+
+        let report = replay_session(&records).await.unwrap();
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(
+            report.mismatches[0].expected,
+            Some(Action::Click(MouseButton::Middle))
+        );
+        assert_eq!(
+            report.mismatches[0].actual,
+            Some(Action::Click(MouseButton::Right))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_privacy_filter() {
+        let privacy = PrivacyFilter {
+            record_keys: false,
+            position_precision: 100,
+        };
+        assert_eq!(
+            privacy.action(Action::MoveCursor(
+                Position::new(1234, 567),
+                AnimationType::Linear
+            )),
+            Action::MoveCursor(Position::new(1200, 500), AnimationType::Linear)
+        );
+
+        let records = record_session(&['l'], privacy).await;
+        assert!(records
+            .iter()
+            .all(|record| !matches!(record.entry, SessionEntry::Key { key: Some(_), .. })));
+        match &records[0].entry {
+            SessionEntry::Setup(setup) => assert_eq!(setup.cursor, Position::new(500, 500)),
+            entry => panic!("Expected a setup entry, got {:?}", entry),
+        }
+
+        assert!(matches!(
+            replay_session(&records).await,
+            Err(crate::MouselessError::SessionError(
+                SessionError::NotReplayable { .. }
+            ))
+        ));
+    }
+}
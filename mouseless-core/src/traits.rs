@@ -1,6 +1,10 @@
 use crate::{
-    error::{InputResult, MouseResult, Result, UIResult},
+    animation_scheduler::SharedClock,
+    error::{ConfigError, ConfigResult, InputResult, MouseError, MouseResult, Result, UIResult},
+    grid::GridCell,
+    keyboard_layout::{KeyRow, KeyboardLayout, PhysicalKey},
     models::*,
+    screen::ScreenManager,
 };
 use async_trait::async_trait;
 
@@ -31,12 +35,60 @@ pub trait MouseOperations {
     /// Move the mouse cursor to a specific position with animation
     fn move_to(&mut self, position: Position, animation: AnimationType) -> MouseResult<()>;
 
+    /// Move the cursor by a pixel offset, across screen edges, and return
+    /// the target it is headed for
+    fn move_relative(
+        &mut self,
+        dx: i32,
+        dy: i32,
+        animation: AnimationType,
+    ) -> MouseResult<Position> {
+        let current = self.get_current_position()?;
+        let target = ScreenManager::place_on_screens(
+            &self.get_screen_bounds()?,
+            Position::new(current.x + dx, current.y + dy),
+        );
+        self.move_to(target, animation)?;
+        Ok(target)
+    }
+
     /// Perform a mouse click
     fn click(&mut self, button: MouseButton) -> MouseResult<()>;
 
     /// Perform mouse scrolling
     fn scroll(&mut self, direction: ScrollDirection, amount: i32) -> MouseResult<()>;
 
+    /// Check if the backend can scroll by pixels rather than wheel notches
+    fn supports_pixel_scroll(&self) -> bool {
+        false
+    }
+
+    /// Scroll by pixels, on backends that support it
+    fn scroll_pixels(&mut self, direction: ScrollDirection, _pixels: i32) -> MouseResult<()> {
+        Err(MouseError::ScrollFailed {
+            direction: format!("{:?}", direction),
+            reason: "Pixel scrolling is not supported".to_string(),
+        })
+    }
+
+    /// End the hold of a scroll, on backends that keep scrolling while a key is held
+    fn release_scroll(&mut self, _direction: ScrollDirection) -> MouseResult<()> {
+        Ok(())
+    }
+
+    /// Check if the backend glides scrolls itself, applying its own scroll configuration
+    fn glides_scrolls(&self) -> bool {
+        false
+    }
+
+    /// Scroll by a page of the screen under the cursor, on backends that glide scrolls
+    fn scroll_page(&mut self, direction: ScrollDirection) -> MouseResult<()> {
+        Err(MouseError::ScrollFailed {
+            direction: format!("{:?}", direction),
+            reason: "Page scrolling is not supported".to_string(),
+        })
+    }
+
     /// Get the current cursor position
     fn get_current_position(&self) -> MouseResult<Position>;
 
@@ -68,15 +120,72 @@ pub trait ModeController: Send + Sync {
     /// Check if any mode is currently active
     fn is_active(&self) -> bool;
 
-    /// Get mode history for undo functionality
+    /// Get the recently used modes, most recent first
     fn get_mode_history(&self) -> Vec<InteractionMode>;
+
+    /// Toggle between fast and the default speed, returning the new speed tier
+    fn toggle_speed(&self) -> MovementSpeed;
+}
+
+/// What a mode wants drawn over the screen
+#[derive(Debug, Clone, Default)]
+pub enum ModeOverlay {
+    /// Nothing beyond the mode indicator
+    #[default]
+    None,
+    /// A grid and its cells, labelled as grid mode assigned them
+    Grid(GridConfig, Vec<GridCell>),
+    /// Labelled screen areas
+    Areas(Vec<Area>),
+    /// Prediction targets
+    Targets(Vec<PredictionTarget>),
+}
+
+/// Trait for an interaction mode registered with the mode manager
+///
+/// The built-in modes register through the same `ModeRegistry` API as modes
+/// from other crates, which are activated with `InteractionMode::Custom`.
+pub trait Mode: Send {
+    /// Name the mode is registered under
+    fn name(&self) -> &str;
+
+    /// Called when the mode becomes the current mode
+    fn activate(&mut self) {}
+
+    /// Called when the mode stops being the current mode
+    fn deactivate(&mut self) {}
+
+    /// Process a key press while the mode is current
+    fn process_input(&mut self, input: KeyInput, bindings: &KeyBindings) -> Result<Action>;
+
+    /// Process a key release while the mode is current
+    fn process_key_release(&mut self, _input: KeyInput, _bindings: &KeyBindings) -> Result<Action> {
+        Ok(Action::NoAction)
+    }
+
+    /// Describe what should be drawn over the screen
+    fn overlay(&self) -> ModeOverlay {
+        ModeOverlay::None
+    }
+
+    /// Advance timers such as key sequence timeouts; called periodically while current
+    fn tick(&mut self) -> Result<Action> {
+        Ok(Action::NoAction)
+    }
+
+    /// Use a different clock for timeouts, e.g. a manual one during replay
+    fn set_clock(&mut self, _clock: SharedClock) {}
 }
 
 /// Trait for UI rendering and overlay management
 #[async_trait]
 pub trait UIRenderer: Send + Sync {
-    /// Show grid overlay for grid mode
-    async fn show_grid_overlay(&self, grid_config: GridConfig) -> UIResult<()>;
+    /// Show grid overlay for grid mode, with the cells and labels grid mode uses
+    async fn show_grid_overlay(
+        &self,
+        grid_config: GridConfig,
+        cells: Vec<GridCell>,
+    ) -> UIResult<()>;
 
     /// Show area overlay for area mode
     async fn show_area_overlay(&self, areas: Vec<Area>) -> UIResult<()>;
@@ -103,6 +212,22 @@ pub trait UIRenderer: Send + Sync {
 
     /// Update theme settings
     async fn update_theme(&self, theme: Theme) -> UIResult<()>;
+
+    /// Highlight the area selected by a key in area mode
+    async fn highlight_area(&self, _key: char) -> UIResult<()> {
+        Ok(())
+    }
+}
+
+/// Hooks run by the action executor around every action
+pub trait ActionMiddleware: Send {
+    /// Inspect an action before it runs; return the action to run, or `None` to skip it
+    fn before(&mut self, action: Action) -> Option<Action> {
+        Some(action)
+    }
+
+    /// Inspect an action and its result after it ran
+    fn after(&mut self, _action: &Action, _result: &Result<ActionOutcome>) {}
 }
 
 /// Trait for prediction models
@@ -146,6 +271,10 @@ pub struct KeyBindings {
     pub scroll_down: char,
     pub scroll_left: char,
     pub scroll_right: char,
+    #[serde(default)]
+    pub page_up: Option<char>,
+    #[serde(default)]
+    pub page_down: Option<char>,
 
     // Mode keys
     pub grid_mode: char,
@@ -154,50 +283,146 @@ pub struct KeyBindings {
 
     // Utility keys
     pub speed_toggle: char,
+    #[serde(default)]
+    pub precision_toggle: Option<char>,
     pub hold_toggle: char,
     pub exit_key: char,
+    #[serde(default)]
+    pub undo: Option<char>,
+    #[serde(default)]
+    pub redo: Option<char>,
 
     // Screen switching keys
     pub screen_1: char,
     pub screen_2: char,
     pub screen_3: char,
+    #[serde(default)]
+    pub screen_next: Option<char>,
+    #[serde(default)]
+    pub screen_previous: Option<char>,
+    #[serde(default)]
+    pub screen_left: Option<char>,
+    #[serde(default)]
+    pub screen_right: Option<char>,
+    #[serde(default)]
+    pub screen_up: Option<char>,
+    #[serde(default)]
+    pub screen_down: Option<char>,
 }
 
-impl Default for KeyBindings {
-    fn default() -> Self {
-        Self {
-            // Movement (I/K/J/L)
-            move_up: 'i',
-            move_down: 'k',
-            move_left: 'j',
-            move_right: 'l',
-
-            // Clicks (N/M)
-            left_click: 'n',
-            right_click: 'm',
-            middle_click: Some(','),
-
-            // Scroll (U/O/Y/P)
-            scroll_up: 'u',
-            scroll_down: 'o',
-            scroll_left: 'y',
-            scroll_right: 'p',
+impl KeyBindings {
+    /// Derive the default bindings for a keyboard layout
+    ///
+    /// Bindings are defined by physical key position, so on any layout the
+    /// movement keys sit where I/K/J/L are on a QWERTY keyboard. Fails if the
+    /// layout has no key at one of these positions.
+    pub fn for_layout(layout: &KeyboardLayout) -> ConfigResult<Self> {
+        let key = |row: KeyRow, column: usize| {
+            layout.key_at(PhysicalKey::new(row, column)).ok_or_else(|| {
+                ConfigError::ValidationFailed {
+                    reason: format!(
+                        "Keyboard layout '{}' has no key at column {} of the {:?} row",
+                        layout.name,
+                        column + 1,
+                        row
+                    ),
+                }
+            })
+        };
+
+        Ok(Self {
+            // Movement (I/K/J/L on QWERTY)
+            move_up: key(KeyRow::Top, 7)?,
+            move_down: key(KeyRow::Home, 7)?,
+            move_left: key(KeyRow::Home, 6)?,
+            move_right: key(KeyRow::Home, 8)?,
+
+            // Clicks (N/M on QWERTY)
+            left_click: key(KeyRow::Bottom, 5)?,
+            right_click: key(KeyRow::Bottom, 6)?,
+            middle_click: Some(key(KeyRow::Bottom, 7)?),
+
+            // Scroll (U/O/Y/P on QWERTY)
+            scroll_up: key(KeyRow::Top, 6)?,
+            scroll_down: key(KeyRow::Top, 8)?,
+            scroll_left: key(KeyRow::Top, 5)?,
+            scroll_right: key(KeyRow::Top, 9)?,
+            // Page scrolling is opt-in
+            page_up: None,
+            page_down: None,
 
             // Modes
-            grid_mode: 'g',
-            area_mode: 'a',
-            prediction_mode: 'r',
+            grid_mode: key(KeyRow::Home, 4)?,
+            area_mode: key(KeyRow::Home, 0)?,
+            prediction_mode: key(KeyRow::Top, 3)?,
 
             // Utility
-            speed_toggle: 'f',
-            hold_toggle: 'b',
+            speed_toggle: key(KeyRow::Home, 3)?,
+            precision_toggle: None,
+            hold_toggle: key(KeyRow::Bottom, 4)?,
             exit_key: ' ', // Space key
+            // Undo is opt-in, its keys are checked before every mode's own keys
+            undo: None,
+            redo: None,
 
             // Screens
-            screen_1: '1',
-            screen_2: '2',
-            screen_3: '3',
-        }
+            screen_1: key(KeyRow::Number, 0)?,
+            screen_2: key(KeyRow::Number, 1)?,
+            screen_3: key(KeyRow::Number, 2)?,
+            screen_previous: Some(key(KeyRow::Number, 8)?),
+            screen_next: Some(key(KeyRow::Number, 9)?),
+            // Directional switching is opt-in
+            screen_left: None,
+            screen_right: None,
+            screen_up: None,
+            screen_down: None,
+        })
+    }
+
+    /// Get every bound key with the name of its binding
+    pub fn bound_keys(&self) -> Vec<(&'static str, char)> {
+        let keys = [
+            ("move_up", Some(self.move_up)),
+            ("move_down", Some(self.move_down)),
+            ("move_left", Some(self.move_left)),
+            ("move_right", Some(self.move_right)),
+            ("left_click", Some(self.left_click)),
+            ("right_click", Some(self.right_click)),
+            ("middle_click", self.middle_click),
+            ("scroll_up", Some(self.scroll_up)),
+            ("scroll_down", Some(self.scroll_down)),
+            ("scroll_left", Some(self.scroll_left)),
+            ("scroll_right", Some(self.scroll_right)),
+            ("page_up", self.page_up),
+            ("page_down", self.page_down),
+            ("grid_mode", Some(self.grid_mode)),
+            ("area_mode", Some(self.area_mode)),
+            ("prediction_mode", Some(self.prediction_mode)),
+            ("speed_toggle", Some(self.speed_toggle)),
+            ("precision_toggle", self.precision_toggle),
+            ("hold_toggle", Some(self.hold_toggle)),
+            ("exit_key", Some(self.exit_key)),
+            ("undo", self.undo),
+            ("redo", self.redo),
+            ("screen_1", Some(self.screen_1)),
+            ("screen_2", Some(self.screen_2)),
+            ("screen_3", Some(self.screen_3)),
+            ("screen_next", self.screen_next),
+            ("screen_previous", self.screen_previous),
+            ("screen_left", self.screen_left),
+            ("screen_right", self.screen_right),
+            ("screen_up", self.screen_up),
+            ("screen_down", self.screen_down),
+        ];
+        keys.into_iter()
+            .filter_map(|(name, key)| key.map(|key| (name, key)))
+            .collect()
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::for_layout(&KeyboardLayout::qwerty()).expect("the QWERTY layout has every key")
     }
 }
 
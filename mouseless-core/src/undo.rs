@@ -0,0 +1,148 @@
+//! Undo module
+//!
+//! This module provides functionality for:
+//! - Remembering the cursor position and mode before absolute moves and mode switches
+//! - Stepping back and forth through those states with bounded undo and redo stacks
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{InteractionMode, Position};
+
+/// Maximum number of states kept to undo
+pub const MAX_UNDO_HISTORY: usize = 50;
+
+/// Cursor position and mode at one point in time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UndoState {
+    pub position: Position,
+    /// The active mode, if mouseless was active
+    pub mode: Option<InteractionMode>,
+}
+
+impl UndoState {
+    /// Create a state
+    pub fn new(position: Position, mode: Option<InteractionMode>) -> Self {
+        Self { position, mode }
+    }
+}
+
+/// Undo and redo stacks of cursor and mode states
+///
+/// Recording a state clears the redo stack, like in an editor. The undo
+/// stack drops its oldest state when it grows past `MAX_UNDO_HISTORY`.
+#[derive(Debug, Clone, Default)]
+pub struct UndoStack {
+    undo: VecDeque<UndoState>,
+    redo: Vec<UndoState>,
+}
+
+impl UndoStack {
+    /// Create empty stacks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember the state before a change
+    pub fn record(&mut self, state: UndoState) {
+        self.push_undo(state);
+        self.redo.clear();
+    }
+
+    /// Step back, given the current state; returns the state to restore
+    pub fn undo(&mut self, current: UndoState) -> Option<UndoState> {
+        let state = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(state)
+    }
+
+    /// Step forward again, given the current state; returns the state to restore
+    pub fn redo(&mut self, current: UndoState) -> Option<UndoState> {
+        let state = self.redo.pop()?;
+        self.push_undo(current);
+        Some(state)
+    }
+
+    /// Number of states that can be undone
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Number of states that can be redone
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Forget every state
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    fn push_undo(&mut self, state: UndoState) {
+        self.undo.push_back(state);
+        if self.undo.len() > MAX_UNDO_HISTORY {
+            self.undo.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(x: i32, mode: InteractionMode) -> UndoState {
+        UndoState::new(Position::new(x, 0), Some(mode))
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut stack = UndoStack::new();
+        assert_eq!(stack.undo(state(0, InteractionMode::Basic)), None);
+
+        stack.record(state(0, InteractionMode::Basic));
+        stack.record(state(10, InteractionMode::Grid));
+
+        let current = state(20, InteractionMode::Grid);
+        assert_eq!(
+            stack.undo(current.clone()),
+            Some(state(10, InteractionMode::Grid))
+        );
+        assert_eq!(
+            stack.undo(state(10, InteractionMode::Grid)),
+            Some(state(0, InteractionMode::Basic))
+        );
+        assert_eq!(stack.undo_len(), 0);
+        assert_eq!(stack.redo_len(), 2);
+
+        assert_eq!(
+            stack.redo(state(0, InteractionMode::Basic)),
+            Some(state(10, InteractionMode::Grid))
+        );
+        assert_eq!(stack.redo(state(10, InteractionMode::Grid)), Some(current));
+        assert_eq!(stack.redo(state(20, InteractionMode::Grid)), None);
+
+        // A new change drops what could be redone
+        stack.undo(state(20, InteractionMode::Grid));
+        stack.record(state(30, InteractionMode::Area));
+        assert_eq!(stack.redo_len(), 0);
+    }
+
+    #[test]
+    fn test_undo_history_limit() {
+        let mut stack = UndoStack::new();
+        for x in 0..(MAX_UNDO_HISTORY as i32 + 5) {
+            stack.record(state(x, InteractionMode::Basic));
+        }
+        assert_eq!(stack.undo_len(), MAX_UNDO_HISTORY);
+
+        let mut current = state(-1, InteractionMode::Basic);
+        let mut oldest = None;
+        while let Some(state) = stack.undo(current.clone()) {
+            current = state.clone();
+            oldest = Some(state);
+        }
+        assert_eq!(oldest, Some(state(5, InteractionMode::Basic)));
+    }
+}
@@ -0,0 +1,929 @@
+//! Daemon module
+//!
+//! This module provides functionality for:
+//! - Wiring configuration, input, modes, the action executor and the mouse service
+//! - Running scripts bound to keys
+//! - Recording and playing back macros
+//! - Writing the session log for bug reports
+//! - Running the event loop without a UI window, optionally drawing the overlays as text
+//! - Answering requests on the control socket and, with the `dbus` feature, D-Bus
+//! - Reloading the configuration on SIGHUP and shutting down on SIGINT/SIGTERM
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, warn};
+
+use mouseless_core::{
+    Action, ActionExecutor, ActionOutcome, ActionSource, AppConfig, ConfigManager, ControlCommand,
+    ControlRequest, ControlServer, GridManager, InputHandler, InputProcessor, InteractionMode,
+    KeyBindings, KeyInput, KeyboardLayout, MacroRecorder, MacroStore, Marks, ModeController,
+    ModeEvent, ModeManager, ModeOverlay, MouseOperations, MouseService, MoveTarget, Position,
+    RecordingMouse, RpcError, ScreenBounds, ScreenChangeEvent, ScreenManager, ScreenWatcher,
+    ScriptConfig, ScriptContext, ScriptEngine, SessionEntry, SessionLog, SessionSetup,
+    TextRenderer, UIRenderer, MAX_MACRO_REPEAT,
+};
+
+#[cfg(feature = "dbus")]
+use mouseless_core::DbusService;
+
+use crate::terminal::{terminal_size, TerminalInput, TerminalKey};
+
+/// Options of a daemon run
+#[derive(Debug, Clone)]
+pub struct DaemonOptions {
+    /// Configuration file to load and reload on SIGHUP
+    pub config_path: PathBuf,
+    /// Read keys from the terminal when standard input is one
+    pub terminal_input: bool,
+    /// Draw the overlays as text on standard output
+    pub overlay: bool,
+    /// Control socket to listen on, none to run without one
+    pub control_socket: Option<PathBuf>,
+}
+
+/// Terminal size to draw the overlay in when it cannot be measured
+const DEFAULT_TERMINAL_SIZE: (usize, usize) = (80, 24);
+
+/// Most clicks a single control request may ask for
+const MAX_CLICK_COUNT: u32 = 10;
+
+/// How often the current mode's timers, e.g. key sequence timeouts, are checked
+const TICK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Why the event loop stopped
+enum Shutdown {
+    Interrupt,
+    Terminate,
+}
+
+/// An open session log and the mode events it has yet to write
+struct Session {
+    log: SessionLog,
+    events: broadcast::Receiver<ModeEvent>,
+}
+
+/// The headless mouseless daemon
+pub struct Daemon {
+    options: DaemonOptions,
+    config: AppConfig,
+    executor: ActionExecutor<ModeManager, RecordingMouse<MouseService>, TextRenderer>,
+    input: Option<InputHandler>,
+    marks: Marks,
+    scripts: ScriptEngine,
+    macros: MacroStore,
+    recorder: Option<MacroRecorder>,
+    playback: Option<JoinHandle<()>>,
+    playback_tx: mpsc::UnboundedSender<Action>,
+    playback_rx: mpsc::UnboundedReceiver<Action>,
+    session: Option<Session>,
+    action_tx: mpsc::UnboundedSender<Action>,
+    action_rx: mpsc::UnboundedReceiver<Action>,
+    control: Option<ControlServer>,
+    control_rx: mpsc::UnboundedReceiver<ControlCommand>,
+    #[cfg(feature = "dbus")]
+    dbus: Option<DbusService>,
+    screen_watcher: ScreenWatcher,
+    screen_follower: JoinHandle<()>,
+    screen_events: broadcast::Receiver<ScreenChangeEvent>,
+}
+
+impl Daemon {
+    /// Load the configuration and start all components
+    pub async fn start(options: DaemonOptions) -> Result<Self> {
+        let config = load_config(&options.config_path)?;
+
+        let modes = ModeManager::new(config.key_bindings()?);
+        match ScreenManager::detect_screens() {
+            Ok(screens) => modes.set_screens(screens)?,
+            Err(e) => warn!("⚠️ Failed to detect screens: {}", e),
+        }
+        let screen_events = ScreenManager::subscribe();
+        let screen_watcher = ScreenWatcher::start(ScreenWatcher::DEFAULT_INTERVAL);
+        let screen_follower = modes.follow_screen_changes();
+
+        let (control_tx, control_rx) = mpsc::unbounded_channel();
+        let control = match &options.control_socket {
+            Some(path) => Some(ControlServer::bind(
+                path,
+                control_tx.clone(),
+                modes.subscribe_to_events(),
+            )?),
+            None => None,
+        };
+
+        // D-Bus mode changes go through the same channel as the control socket
+        #[cfg(feature = "dbus")]
+        let dbus = match DbusService::start(control_tx, modes.clone()).await {
+            Ok(service) => Some(service),
+            Err(e) => {
+                warn!("⚠️ Running without D-Bus: {}", e);
+                None
+            }
+        };
+
+        // One line below the canvas is the mode indicator, one the prompt
+        let (columns, rows) = terminal_size().unwrap_or(DEFAULT_TERMINAL_SIZE);
+        let mut renderer = TextRenderer::new(columns, rows.saturating_sub(2));
+        if options.overlay {
+            renderer = renderer.with_output(std::io::stdout(), true);
+        }
+        let executor =
+            ActionExecutor::new(modes, RecordingMouse::new(MouseService::new()), renderer);
+        let (action_tx, action_rx) = mpsc::unbounded_channel();
+        let (playback_tx, playback_rx) = mpsc::unbounded_channel();
+
+        let mut daemon = Self {
+            options,
+            config: config.clone(),
+            executor,
+            input: None,
+            marks: Marks::new(),
+            scripts: ScriptEngine::new(&ScriptConfig::default())?,
+            macros: MacroStore::default(),
+            recorder: None,
+            playback: None,
+            playback_tx,
+            playback_rx,
+            session: None,
+            action_tx,
+            action_rx,
+            control,
+            control_rx,
+            #[cfg(feature = "dbus")]
+            dbus,
+            screen_watcher,
+            screen_follower,
+            screen_events,
+        };
+        daemon.apply_config(&config)?;
+        daemon.start_input().await;
+
+        info!("✅ Daemon started");
+        Ok(daemon)
+    }
+
+    /// Run until SIGINT or SIGTERM, then shut down
+    pub async fn run(mut self) -> Result<()> {
+        let mut terminate = signal(SignalKind::terminate())?;
+        let mut hangup = signal(SignalKind::hangup())?;
+
+        let (_terminal, mut keys) = if self.options.terminal_input && TerminalInput::is_available()
+        {
+            let (terminal, keys) = TerminalInput::start()?;
+            info!("⌨️ Reading keys from the terminal; Escape toggles mouseless mode");
+            (Some(terminal), Some(keys))
+        } else {
+            (None, None)
+        };
+
+        let mut ticks = tokio::time::interval(TICK_INTERVAL);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let reason = loop {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => break Shutdown::Interrupt,
+                _ = terminate.recv() => break Shutdown::Terminate,
+                _ = hangup.recv() => {
+                    if let Err(e) = self.reload().await {
+                        warn!("⚠️ Keeping current configuration: {:#}", e);
+                    }
+                }
+                Some(action) = self.action_rx.recv() => {
+                    self.handle_input_action(action, ActionSource::Host).await
+                }
+                // Played back actions are not recorded again
+                Some(action) = self.playback_rx.recv() => self.execute(action).await,
+                Some(command) = self.control_rx.recv() => {
+                    let result = self.handle_control(command.request).await;
+                    let _ = command.response_tx.send(result);
+                }
+                Some(key) = next_key(&mut keys) => self.handle_terminal_key(key).await,
+                event = self.screen_events.recv() => self.handle_screen_change(event).await,
+                _ = ticks.tick() => {
+                    // Most ticks change nothing, so they skip the redraw below
+                    let Some(action) = tick_action(self.executor.modes()) else {
+                        continue;
+                    };
+                    self.handle_input_action(action, ActionSource::Tick).await
+                }
+            }
+            self.flush_session();
+            self.refresh_overlay().await;
+        };
+
+        match reason {
+            Shutdown::Interrupt => info!("Received SIGINT"),
+            Shutdown::Terminate => info!("Received SIGTERM"),
+        }
+        self.shutdown().await;
+        Ok(())
+    }
+
+    /// Register the activation hotkey; a daemon without a display runs on without it
+    async fn start_input(&mut self) {
+        let mut input = match InputHandler::new() {
+            Ok(input) => input,
+            Err(e) => {
+                warn!("⚠️ Global hotkeys unavailable: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = input.update_activation_config(self.config.activation.clone()) {
+            warn!("⚠️ Failed to apply activation config: {}", e);
+        }
+        let bindings = self.executor.modes().get_key_bindings();
+        if let Err(e) = input.update_bindings(bindings).await {
+            warn!("⚠️ Failed to apply key bindings: {}", e);
+        }
+
+        let mut hotkey_actions = input.setup_action_channel();
+        let action_tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            while let Some(action) = hotkey_actions.recv().await {
+                if action_tx.send(action).is_err() {
+                    break;
+                }
+            }
+        });
+
+        if let Err(e) = input.register_activation_hotkey() {
+            warn!("⚠️ Failed to register activation hotkey: {}", e);
+        }
+        if let Err(e) = input.start_event_loop().await {
+            warn!("⚠️ Failed to start input event loop: {}", e);
+        }
+
+        self.input = Some(input);
+    }
+
+    /// Apply a configuration to the modes, the executor and the mouse service
+    fn apply_config(&mut self, config: &AppConfig) -> Result<()> {
+        let macros_path = config.macros.file_for(&self.options.config_path);
+        self.macros = MacroStore::load(&macros_path)
+            .with_context(|| format!("Failed to load macros from {:?}", macros_path))?;
+        self.scripts = ScriptEngine::new(&config.scripting)?;
+
+        let keyboard_layout = config.keyboard.resolve_layout()?;
+        let screens = match self.executor.mouse().get_screen_bounds() {
+            Ok(screens) => screens,
+            Err(e) => {
+                warn!("⚠️ Failed to detect screens: {}", e);
+                Vec::new()
+            }
+        };
+
+        let modes = self.executor.modes();
+        // Without explicit bindings the keys follow the keyboard layout
+        modes.update_key_bindings(match &config.keybindings {
+            Some(bindings) => bindings.clone(),
+            None => KeyBindings::for_layout(&keyboard_layout)?,
+        });
+        modes.set_movement_config(config.movement.clone());
+        modes.set_keyboard_layout(keyboard_layout.clone())?;
+        modes.set_area_layout(config.area.clone())?;
+        modes.set_multi_screen_layout(config.multi_screen)?;
+        modes.set_script_bindings(config.scripting.bindings.clone());
+        modes.set_macro_config(config.macros.clone());
+        match grid_manager(config, screens.clone(), keyboard_layout) {
+            Ok(grid) => modes.set_grid_manager(Some(grid)),
+            Err(e) => warn!("⚠️ Grid mode has no grid: {}", e),
+        }
+
+        self.executor.set_scroll_config(config.scroll.clone());
+        self.executor
+            .set_default_animation(config.movement.default_animation);
+
+        let mouse = self.executor.mouse_mut().inner_mut();
+        mouse.set_animation(config.movement.default_animation, config.animation_timing())?;
+        mouse.set_scroll_config(config.scroll.clone())?;
+        mouse.set_movement_speed(config.movement.default_speed);
+
+        // Marks from the file replace runtime marks of the same name
+        for (name, position) in config.marks.iter() {
+            self.marks.set(name, position)?;
+        }
+
+        let ui = self.executor.ui();
+        ui.set_screens(screens);
+
+        self.config = config.clone();
+        self.update_session_log();
+        Ok(())
+    }
+
+    /// Draw the overlay of the current mode in the terminal
+    async fn refresh_overlay(&self) {
+        if !self.options.overlay {
+            return;
+        }
+
+        let ui = self.executor.ui();
+        let result = match self.executor.modes().current_overlay() {
+            ModeOverlay::Grid(config, cells) => ui.show_grid_overlay(config, cells).await,
+            ModeOverlay::Areas(areas) => ui.show_area_overlay(areas).await,
+            ModeOverlay::Targets(targets) => ui.show_prediction_targets(targets).await,
+            ModeOverlay::None => ui.hide_all_overlays().await,
+        };
+        if let Err(e) = result {
+            warn!("⚠️ Failed to draw the overlay: {}", e);
+        }
+    }
+
+    /// Open or close the session log to match the configuration, and log the setup
+    fn update_session_log(&mut self) {
+        let settings = &self.config.session_log;
+        if !settings.enabled {
+            if self.session.take().is_some() {
+                info!("Session log closed");
+            }
+            self.executor.mouse_mut().set_recording(false);
+            return;
+        }
+
+        match &mut self.session {
+            Some(session) => session.log.set_privacy(settings.privacy),
+            None => {
+                let path = settings.file_for(&self.options.config_path);
+                match SessionLog::create(&path, settings.privacy) {
+                    Ok(log) => {
+                        self.session = Some(Session {
+                            log,
+                            events: self.executor.modes().subscribe_to_events(),
+                        });
+                        self.executor.mouse_mut().set_recording(true);
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Running without a session log: {}", e);
+                        return;
+                    }
+                }
+            }
+        }
+
+        let mouse = self.executor.mouse();
+        let setup = SessionSetup::new(
+            &self.config,
+            mouse.get_screen_bounds().unwrap_or_default(),
+            mouse
+                .get_current_position()
+                .unwrap_or_else(|_| Position::new(0, 0)),
+        );
+        self.log_session(SessionEntry::Setup(Box::new(setup)));
+    }
+
+    /// Write the mode events and mouse operations of the last step to the session log
+    fn flush_session(&mut self) {
+        let Some(session) = &mut self.session else {
+            return;
+        };
+
+        let mut entries = Vec::new();
+        loop {
+            match session.events.try_recv() {
+                Ok(event) => entries.push(SessionEntry::Mode { event }),
+                Err(broadcast::error::TryRecvError::Lagged(missed)) => {
+                    warn!("⚠️ Session log missed {} mode events", missed)
+                }
+                Err(_) => break,
+            }
+        }
+        entries.extend(
+            self.executor
+                .mouse_mut()
+                .take_ops()
+                .into_iter()
+                .map(SessionEntry::Mouse),
+        );
+
+        for entry in entries {
+            if let Err(e) = session.log.log(entry) {
+                warn!("⚠️ Session log closed: {}", e);
+                self.session = None;
+                self.executor.mouse_mut().set_recording(false);
+                return;
+            }
+        }
+    }
+
+    /// Write an entry to the session log, after what the last step left behind
+    fn log_session(&mut self, entry: SessionEntry) {
+        self.flush_session();
+        if let Some(session) = &mut self.session {
+            if let Err(e) = session.log.log(entry) {
+                warn!("⚠️ Session log closed: {}", e);
+                self.session = None;
+                self.executor.mouse_mut().set_recording(false);
+            }
+        }
+    }
+
+    /// Keep the marks and the overlay on their screens when screens change
+    async fn handle_screen_change(&mut self, event: Result<ScreenChangeEvent, RecvError>) {
+        match event {
+            Ok(event) => {
+                self.marks.apply_screen_change(&event);
+                debug!("Remapped {} marks after a screen change", self.marks.len());
+                self.executor.ui().set_screens(event.bounds());
+                self.refresh_overlay().await;
+            }
+            Err(RecvError::Lagged(missed)) => {
+                warn!("⚠️ Missed {} screen changes, marks may be off", missed)
+            }
+            Err(RecvError::Closed) => {}
+        }
+    }
+
+    /// Reload the configuration file, keeping the current settings if it is invalid
+    async fn reload(&mut self) -> Result<()> {
+        info!(
+            "🔄 Reloading configuration from {:?}",
+            self.options.config_path
+        );
+
+        let config = load_config(&self.options.config_path)?;
+        self.apply_config(&config)?;
+        if let Some(input) = &mut self.input {
+            if let Err(e) = input.update_activation_config(config.activation.clone()) {
+                warn!("⚠️ Failed to apply activation config: {}", e);
+            }
+            let bindings = self.executor.modes().get_key_bindings();
+            if let Err(e) = input.update_bindings(bindings).await {
+                warn!("⚠️ Failed to apply key bindings: {}", e);
+            }
+        }
+
+        info!("✅ Configuration reloaded");
+        Ok(())
+    }
+
+    /// Run an action that came from input, recording it while a macro is recorded
+    async fn handle_input_action(&mut self, action: Action, source: ActionSource) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(&action);
+        }
+        // Leaving mouseless mode also stops a macro being played
+        if action == Action::Exit {
+            self.stop_playback();
+        }
+        self.execute_from(action, source).await
+    }
+
+    async fn execute(&mut self, action: Action) {
+        self.execute_from(action, ActionSource::Host).await
+    }
+
+    async fn execute_from(&mut self, action: Action, source: ActionSource) {
+        self.log_session(SessionEntry::Action {
+            action: action.clone(),
+            source,
+        });
+        match self.run_action(action).await {
+            Ok(ActionOutcome::Exit) => info!("Mouseless mode left"),
+            Ok(ActionOutcome::Continue) => {}
+            Err(e) => warn!("⚠️ Action failed: {}", e),
+        }
+    }
+
+    async fn run_action(&mut self, action: Action) -> mouseless_core::Result<ActionOutcome> {
+        match action {
+            Action::RunScript(name) => self.run_script(&name).await,
+            Action::RecordMacro(register) => self.start_recording(register),
+            Action::StopMacroRecording => self.stop_recording(),
+            Action::PlayMacro(register, count) => self.play_macro(register, count),
+            action => self.run_single_action(action).await,
+        }
+    }
+
+    /// Run a script, then the actions it queued until one of them exits
+    async fn run_script(&mut self, name: &str) -> mouseless_core::Result<ActionOutcome> {
+        let context = self.script_context()?;
+        let actions = self.scripts.run(name, context)?;
+        debug!("Running {} actions of script {}", actions.len(), name);
+
+        for action in actions {
+            self.log_session(SessionEntry::Action {
+                action: action.clone(),
+                source: ActionSource::Host,
+            });
+            if self.run_single_action(action).await? == ActionOutcome::Exit {
+                return Ok(ActionOutcome::Exit);
+            }
+        }
+        Ok(ActionOutcome::Continue)
+    }
+
+    /// Start recording the actions from input into a macro register
+    fn start_recording(&mut self, register: char) -> mouseless_core::Result<ActionOutcome> {
+        self.stop_playback();
+
+        let mouse = self.executor.mouse();
+        self.recorder = Some(MacroRecorder::start(
+            register,
+            self.config.macros.positions,
+            mouse.get_current_position()?,
+            mouse.get_screen_bounds()?,
+        ));
+        info!("⏺️ Recording macro {}", register);
+        Ok(ActionOutcome::Continue)
+    }
+
+    /// Stop recording and save the macro to the macros file
+    fn stop_recording(&mut self) -> mouseless_core::Result<ActionOutcome> {
+        let Some(recorder) = self.recorder.take() else {
+            warn!("⚠️ No macro is being recorded");
+            return Ok(ActionOutcome::Continue);
+        };
+
+        let (register, recording) = recorder.finish();
+        info!(
+            "⏹️ Recorded {} steps into macro {}",
+            recording.steps.len(),
+            register
+        );
+        self.macros.set(register, recording)?;
+        self.macros.save()?;
+        Ok(ActionOutcome::Continue)
+    }
+
+    /// Play a macro in the background, feeding its steps back into the event loop
+    fn play_macro(&mut self, register: char, count: u32) -> mouseless_core::Result<ActionOutcome> {
+        let Some(recording) = self.macros.get(register) else {
+            warn!("⚠️ No macro in register {}", register);
+            return Ok(ActionOutcome::Continue);
+        };
+
+        let mouse = self.executor.mouse();
+        let steps = recording.resolve(mouse.get_current_position()?, &mouse.get_screen_bounds()?);
+        let count = count.clamp(1, MAX_MACRO_REPEAT);
+        info!("▶️ Playing macro {} {} times", register, count);
+
+        self.stop_playback();
+        let playback_tx = self.playback_tx.clone();
+        self.playback = Some(tokio::spawn(async move {
+            for _ in 0..count {
+                for (delay, action) in &steps {
+                    if !delay.is_zero() {
+                        tokio::time::sleep(*delay).await;
+                    }
+                    if playback_tx.send(action.clone()).is_err() {
+                        return;
+                    }
+                }
+            }
+        }));
+        Ok(ActionOutcome::Continue)
+    }
+
+    /// Stop the macro being played, if any
+    fn stop_playback(&mut self) {
+        if let Some(playback) = self.playback.take() {
+            playback.abort();
+        }
+    }
+
+    /// What scripts see of the daemon when they start
+    fn script_context(&self) -> mouseless_core::Result<ScriptContext> {
+        let mouse = self.executor.mouse();
+        let modes = self.executor.modes();
+        Ok(ScriptContext {
+            cursor: mouse.get_current_position()?,
+            screens: mouse.get_screen_bounds()?,
+            marks: self.marks.clone(),
+            mode: modes.get_current_mode(),
+            speed: modes.get_speed(),
+            holding: modes.is_holding(),
+            animation: self.config.movement.default_animation,
+        })
+    }
+
+    async fn run_single_action(&mut self, action: Action) -> mouseless_core::Result<ActionOutcome> {
+        if let Action::ActivateMode(mode) = &action {
+            let path = *self.config.movement.paths.for_mode(mode);
+            if let Err(e) = self.executor.mouse().inner().set_path_config(path) {
+                warn!("⚠️ Failed to set cursor path: {}", e);
+            }
+        }
+
+        self.executor.execute(action).await
+    }
+
+    /// Run an action that did not come from a mode, logging it first
+    async fn run_host_action(&mut self, action: Action) -> mouseless_core::Result<ActionOutcome> {
+        self.log_session(SessionEntry::Action {
+            action: action.clone(),
+            source: ActionSource::Host,
+        });
+        self.run_action(action).await
+    }
+
+    /// Answer a control socket request
+    async fn handle_control(&mut self, request: ControlRequest) -> Result<Value, RpcError> {
+        debug!("Control request: {:?}", request);
+
+        match request {
+            ControlRequest::ActivateMode { mode } => {
+                self.run_host_action(Action::ActivateMode(mode))
+                    .await
+                    .map_err(RpcError::failed)?;
+                Ok(json!({ "mode": self.executor.modes().get_current_mode() }))
+            }
+            ControlRequest::DeactivateMode => {
+                self.run_host_action(Action::DeactivateMode)
+                    .await
+                    .map_err(RpcError::failed)?;
+                Ok(json!({ "mode": Value::Null }))
+            }
+            ControlRequest::GetMode => {
+                Ok(json!({ "mode": self.executor.modes().get_current_mode() }))
+            }
+            ControlRequest::MoveTo {
+                target,
+                screen,
+                animation,
+            } => {
+                let position = self.move_target(target, screen)?;
+                let animation = animation.unwrap_or(self.config.movement.default_animation);
+                self.run_host_action(Action::MoveCursor(position, animation))
+                    .await
+                    .map_err(RpcError::failed)?;
+                Ok(json!(position))
+            }
+            ControlRequest::GetPosition => {
+                let position = self
+                    .executor
+                    .mouse()
+                    .get_current_position()
+                    .map_err(RpcError::failed)?;
+                Ok(json!(position))
+            }
+            ControlRequest::Click { button, count } => {
+                if !(1..=MAX_CLICK_COUNT).contains(&count) {
+                    return Err(RpcError::invalid_params(format!(
+                        "Click count must be between 1 and {}",
+                        MAX_CLICK_COUNT
+                    )));
+                }
+                for _ in 0..count {
+                    self.run_host_action(Action::Click(button))
+                        .await
+                        .map_err(RpcError::failed)?;
+                }
+                Ok(Value::Null)
+            }
+            ControlRequest::SetMark { name, position } => {
+                let position = match position {
+                    Some(position) => position,
+                    None => self
+                        .executor
+                        .mouse()
+                        .get_current_position()
+                        .map_err(RpcError::failed)?,
+                };
+                self.marks
+                    .set(&name, position)
+                    .map_err(RpcError::invalid_params)?;
+                Ok(json!({ "name": name, "position": position }))
+            }
+            ControlRequest::ListMarks => Ok(json!(self.marks)),
+            ControlRequest::ReloadConfig => {
+                self.reload()
+                    .await
+                    .map_err(|e| RpcError::failed(format!("{:#}", e)))?;
+                Ok(Value::Null)
+            }
+            ControlRequest::GetScreens => {
+                let screens = self
+                    .executor
+                    .mouse()
+                    .get_screen_bounds()
+                    .map_err(RpcError::failed)?;
+                Ok(json!(screens))
+            }
+            // Subscriptions are served by the control socket itself
+            ControlRequest::Subscribe => Err(RpcError::invalid_request(
+                "Subscriptions are handled by the control socket",
+            )),
+        }
+    }
+
+    /// Resolve the position of a control socket move
+    fn move_target(&self, target: MoveTarget, screen: Option<u8>) -> Result<Position, RpcError> {
+        match (target, screen) {
+            (MoveTarget::Mark { mark }, _) => self
+                .marks
+                .get(&mark)
+                .ok_or_else(|| RpcError::invalid_params(format!("Unknown mark: {}", mark))),
+            (MoveTarget::Position { x, y }, None) => Ok(Position::new(x, y)),
+            (MoveTarget::Position { x, y }, Some(number)) => {
+                let screens = self
+                    .executor
+                    .mouse()
+                    .get_screen_bounds()
+                    .map_err(RpcError::failed)?;
+                let screen = (number as usize)
+                    .checked_sub(1)
+                    .and_then(|index| screens.get(index))
+                    .ok_or_else(|| RpcError::invalid_params(format!("No screen {}", number)))?;
+                Ok(Position::with_screen(screen.x + x, screen.y + y, screen.id))
+            }
+        }
+    }
+
+    /// Run a terminal key; each key is a tap, so its release follows at once
+    async fn handle_terminal_key(&mut self, key: TerminalKey) {
+        let active = self.executor.modes().is_active();
+        let key = match key {
+            // Escape stands in for the activation hotkey
+            TerminalKey::Escape if active => {
+                return self
+                    .handle_input_action(Action::Exit, ActionSource::Host)
+                    .await
+            }
+            TerminalKey::Escape => {
+                return self
+                    .handle_input_action(
+                        Action::ActivateMode(InteractionMode::Basic),
+                        ActionSource::Host,
+                    )
+                    .await
+            }
+            TerminalKey::Char(key) => key,
+        };
+        if !active {
+            debug!("Ignoring key {:?} while inactive", key);
+            return;
+        }
+
+        let input = KeyInput {
+            key,
+            modifiers: Vec::new(),
+            timestamp: SystemTime::now(),
+        };
+        let modes = self.executor.modes().clone();
+        for release in [false, true] {
+            self.log_session(SessionEntry::Key {
+                key: Some(key),
+                modifiers: input.modifiers.clone(),
+                release,
+            });
+            let action = if release {
+                modes.handle_key_release(input.clone())
+            } else {
+                modes.handle_input(input.clone()).await
+            };
+            match action {
+                Ok(action) => self.handle_input_action(action, ActionSource::Mode).await,
+                Err(e) => warn!("⚠️ Failed to handle key {:?}: {}", key, e),
+            }
+        }
+    }
+
+    /// Leave the active mode and stop the cursor, screen watching and input
+    async fn shutdown(mut self) {
+        info!("🛑 Shutting down");
+
+        // Subscribers still see the mode being left
+        if self.executor.modes().is_active() {
+            self.execute(Action::Exit).await;
+        }
+        self.stop_playback();
+        self.control = None;
+        #[cfg(feature = "dbus")]
+        {
+            self.dbus = None;
+        }
+
+        self.flush_session();
+        self.session = None;
+
+        let mouse = self.executor.mouse().inner();
+        if let Err(e) = mouse.cancel_animation().await {
+            debug!("Failed to cancel animation: {}", e);
+        }
+        if let Err(e) = mouse.stop_scrolling().await {
+            debug!("Failed to stop scrolling: {}", e);
+        }
+
+        self.screen_follower.abort();
+        self.screen_watcher.stop();
+        self.input = None;
+
+        info!("👋 Daemon stopped");
+    }
+}
+
+/// Wait for the next terminal key, forever when there is no terminal
+async fn next_key(keys: &mut Option<mpsc::UnboundedReceiver<TerminalKey>>) -> Option<TerminalKey> {
+    match keys {
+        Some(keys) => keys.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Build the grid that grid mode selects cells of
+fn grid_manager(
+    config: &AppConfig,
+    screens: Vec<ScreenBounds>,
+    keyboard_layout: KeyboardLayout,
+) -> mouseless_core::Result<GridManager> {
+    GridManager::for_screens(
+        config.grid.clone(),
+        screens,
+        config.multi_screen,
+        keyboard_layout,
+    )
+}
+
+/// Advance the current mode's timers, returning the action a timeout caused
+fn tick_action(modes: &ModeManager) -> Option<Action> {
+    match modes.tick() {
+        Ok(Action::NoAction) => None,
+        Ok(action) => Some(action),
+        Err(e) => {
+            warn!("⚠️ Mode tick failed: {}", e);
+            None
+        }
+    }
+}
+
+fn load_config(path: &Path) -> Result<AppConfig> {
+    let mut manager = ConfigManager::new(path);
+    manager
+        .load()
+        .with_context(|| format!("Failed to load configuration from {:?}", path))?;
+    Ok(manager.get_config().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mouseless_core::VirtualClock;
+    use std::sync::Arc;
+
+    fn screen() -> ScreenBounds {
+        ScreenBounds {
+            id: 1,
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            is_primary: true,
+            scale_factor: 1.0,
+        }
+    }
+
+    fn key(key: char) -> KeyInput {
+        KeyInput {
+            key,
+            modifiers: Vec::new(),
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_area_timeout_on_tick() {
+        let clock = Arc::new(VirtualClock::new());
+        let mut modes = ModeManager::new(KeyBindings::default());
+        modes.set_clock(clock.clone());
+        modes.set_screens(vec![screen()]).unwrap();
+        modes.activate_mode(InteractionMode::Area).await.unwrap();
+
+        let action = modes.handle_input(key('q')).await.unwrap();
+        assert_eq!(action, Action::HighlightArea('q'));
+        assert_eq!(tick_action(&modes), None);
+
+        // Without a second key the cursor moves to the first key's area
+        clock.advance(Duration::from_millis(500));
+        assert!(matches!(tick_action(&modes), Some(Action::MoveCursor(..))));
+        assert_eq!(tick_action(&modes), None);
+    }
+
+    #[tokio::test]
+    async fn test_grid_mode_selects_cells() {
+        let config = AppConfig::default();
+        let layout = config.keyboard.resolve_layout().unwrap();
+        let grid = grid_manager(&config, vec![screen()], layout).unwrap();
+        let cell = grid.get_cells()[0].clone();
+
+        let mut modes = ModeManager::new(config.key_bindings().unwrap());
+        modes.set_grid_manager(Some(grid));
+        modes.activate_mode(InteractionMode::Grid).await.unwrap();
+
+        let mut action = Action::NoAction;
+        for c in cell.key_combination.chars() {
+            action = modes.handle_input(key(c)).await.unwrap();
+        }
+        assert!(
+            matches!(action, Action::MoveCursor(position, _) if position == cell.center_position)
+        );
+    }
+}
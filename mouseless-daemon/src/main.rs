@@ -0,0 +1,158 @@
+//! Mouseless daemon
+//!
+//! Runs mouseless without the Tauri UI: the configuration, global hotkeys,
+//! modes, action executor and mouse service, with no overlay window. Keys can
+//! also be typed into the terminal the daemon runs in, which can show the
+//! overlays as text, and scripts can drive it over the control socket.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::{bail, Result};
+use mouseless_core::{init, AppInfo, ConfigManager, ControlServer};
+use tracing::{error, info};
+
+mod daemon;
+mod terminal;
+
+use daemon::{Daemon, DaemonOptions};
+
+const USAGE: &str = "\
+Usage: mouseless-daemon [OPTIONS]
+
+Options:
+  -c, --config <PATH>  Configuration file [default: the mouseless config path]
+      --no-terminal    Do not read keys from the terminal
+      --overlay        Draw the overlays as text in the terminal; it is
+                       redrawn over the log, so RUST_LOG=warn suits it
+  -s, --socket <PATH>  Control socket [default: $MOUSELESS_SOCKET or
+                       $XDG_RUNTIME_DIR/mouseless.sock]
+      --no-socket      Do not listen on a control socket
+  -h, --help           Print help
+  -V, --version        Print version
+
+Signals:
+  SIGHUP               Reload the configuration
+  SIGINT, SIGTERM      Shut down";
+
+/// What the command line asks for
+enum Command {
+    Run(DaemonOptions),
+    Help,
+    Version,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command> {
+    let mut config_path = None;
+    let mut terminal_input = true;
+    let mut overlay = false;
+    let mut control_socket = Some(ControlServer::default_socket_path());
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-c" | "--config" => match args.next() {
+                Some(path) => config_path = Some(PathBuf::from(path)),
+                None => bail!("{} needs a path", arg),
+            },
+            "--no-terminal" => terminal_input = false,
+            "--overlay" => overlay = true,
+            "-s" | "--socket" => match args.next() {
+                Some(path) => control_socket = Some(PathBuf::from(path)),
+                None => bail!("{} needs a path", arg),
+            },
+            "--no-socket" => control_socket = None,
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            _ => bail!("Unknown argument: {}", arg),
+        }
+    }
+
+    let config_path = match config_path {
+        Some(path) => path,
+        None => ConfigManager::default_config_path()?,
+    };
+
+    Ok(Command::Run(DaemonOptions {
+        config_path,
+        terminal_input,
+        overlay,
+        control_socket,
+    }))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Ok(Command::Version) => {
+            println!("mouseless-daemon {}", AppInfo::default().version);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if let Err(e) = init() {
+        eprintln!("Failed to initialize core library: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    let app_info = AppInfo::default();
+    info!("Starting {} daemon v{}", app_info.name, app_info.version);
+
+    let result = match Daemon::start(options).await {
+        Ok(daemon) => daemon.run().await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            error!("❌ Daemon failed: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        match parse_args(args(&["--config", "/tmp/mouseless.toml", "--no-terminal"])) {
+            Ok(Command::Run(options)) => {
+                assert_eq!(options.config_path, PathBuf::from("/tmp/mouseless.toml"));
+                assert!(!options.terminal_input);
+                assert!(!options.overlay);
+                assert!(options.control_socket.is_some());
+            }
+            _ => panic!("expected a run command"),
+        }
+
+        match parse_args(args(&["-c", "mouseless.toml", "--no-socket"])) {
+            Ok(Command::Run(options)) => assert!(options.control_socket.is_none()),
+            _ => panic!("expected a run command"),
+        }
+
+        match parse_args(args(&["-c", "mouseless.toml", "--overlay"])) {
+            Ok(Command::Run(options)) => assert!(options.overlay),
+            _ => panic!("expected a run command"),
+        }
+
+        assert!(matches!(parse_args(args(&["-h"])), Ok(Command::Help)));
+        assert!(parse_args(args(&["--config"])).is_err());
+        assert!(parse_args(args(&["--verbose"])).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+//! Terminal input module
+//!
+//! This module provides functionality for:
+//! - Reading key presses from the controlling terminal
+//! - Switching the terminal out of line mode and restoring it on drop
+//! - Measuring the terminal the overlay is drawn in
+
+use std::io::{self, Read};
+use std::thread;
+
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// A key read from the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalKey {
+    /// A printable character
+    Char(char),
+    /// The escape key on its own, not the start of an escape sequence
+    Escape,
+}
+
+/// Key input from the terminal the daemon runs in
+///
+/// Terminals only report key presses, so every key is handled as a tap.
+/// Signal keys like Ctrl+C keep working.
+pub struct TerminalInput {
+    original: libc::termios,
+}
+
+impl TerminalInput {
+    /// Check if standard input is a terminal
+    pub fn is_available() -> bool {
+        unsafe { libc::isatty(libc::STDIN_FILENO) == 1 }
+    }
+
+    /// Switch the terminal to unbuffered input and start reading keys
+    pub fn start() -> io::Result<(Self, mpsc::UnboundedReceiver<TerminalKey>)> {
+        let original = unsafe {
+            let mut termios = std::mem::zeroed::<libc::termios>();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut termios) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            termios
+        };
+
+        let mut raw = original;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+        raw.c_cc[libc::VMIN] = 1;
+        raw.c_cc[libc::VTIME] = 0;
+        if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let (key_tx, key_rx) = mpsc::unbounded_channel();
+        thread::spawn(move || {
+            let mut stdin = io::stdin();
+            let mut buffer = [0u8; 16];
+            loop {
+                let read = match stdin.read(&mut buffer) {
+                    Ok(0) => break,
+                    Ok(read) => read,
+                    Err(e) => {
+                        warn!("Failed to read terminal input: {}", e);
+                        break;
+                    }
+                };
+
+                for key in parse_keys(&buffer[..read]) {
+                    if key_tx.send(key).is_err() {
+                        return;
+                    }
+                }
+            }
+            debug!("Terminal input closed");
+        });
+
+        Ok((Self { original }, key_rx))
+    }
+}
+
+/// Columns and rows of the terminal on standard output, if it is one
+pub fn terminal_size() -> Option<(usize, usize)> {
+    let mut size = unsafe { std::mem::zeroed::<libc::winsize>() };
+    if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } != 0 {
+        return None;
+    }
+    if size.ws_col == 0 || size.ws_row == 0 {
+        return None;
+    }
+    Some((size.ws_col as usize, size.ws_row as usize))
+}
+
+impl Drop for TerminalInput {
+    fn drop(&mut self) {
+        unsafe {
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original);
+        }
+    }
+}
+
+/// Turn one read from the terminal into keys
+///
+/// Escape sequences, e.g. of arrow keys, arrive in a single read and are
+/// dropped; a lone escape byte is the escape key.
+fn parse_keys(bytes: &[u8]) -> Vec<TerminalKey> {
+    if bytes == [0x1b] {
+        return vec![TerminalKey::Escape];
+    }
+    if bytes.first() == Some(&0x1b) {
+        return Vec::new();
+    }
+
+    String::from_utf8_lossy(bytes)
+        .chars()
+        .filter(|c| !c.is_control())
+        .map(TerminalKey::Char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            parse_keys(b"ik"),
+            vec![TerminalKey::Char('i'), TerminalKey::Char('k')]
+        );
+        assert_eq!(parse_keys(&[0x1b]), vec![TerminalKey::Escape]);
+        // Arrow up is an escape sequence, not escape followed by keys
+        assert!(parse_keys(b"\x1b[A").is_empty());
+        assert_eq!(parse_keys(b"\n "), vec![TerminalKey::Char(' ')]);
+    }
+}
@@ -0,0 +1,340 @@
+//! Command line module
+//!
+//! This module provides functionality for:
+//! - Parsing `mouselessctl` subcommands into control requests
+//! - Parsing modes, mouse buttons and animation names
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context, Result};
+use mouseless_core::{
+    AnimationType, ControlRequest, ControlServer, InteractionMode, MouseButton, MoveTarget,
+    Position,
+};
+
+pub const USAGE: &str = "\
+Usage: mouselessctl [OPTIONS] <COMMAND>
+
+Commands:
+  mode [MODE]                  Show the mode, or switch to basic, grid, area,
+                               prediction, a custom mode or off
+  move <X> <Y> [--screen N] [--anim ANIMATION]
+                               Move the cursor, relative to screen N if given
+  move --mark <NAME> [--anim ANIMATION]
+                               Move the cursor to a mark
+  click [BUTTON] [--count N]   Click left, right or middle
+  position                     Show the cursor position
+  mark set <NAME> [X Y]        Save the cursor position, or X Y, as a mark
+  mark list                    List the marks
+  screens                      List the screens
+  reload                       Reload the daemon configuration
+  events [--json]              Stream mode events
+  config check <PATH>          Validate a configuration file, without a daemon
+  replay <PATH>                Replay a session log and diff its actions,
+                               without a daemon
+
+Options:
+  -s, --socket <PATH>  Control socket [default: $MOUSELESS_SOCKET or
+                       $XDG_RUNTIME_DIR/mouseless.sock]
+      --json           Print results as JSON
+  -h, --help           Print help
+  -V, --version        Print version
+
+Animations: instant, linear, smooth, bounce, elastic";
+
+/// What the command line asks for
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Send a request to the daemon and print its result
+    Request(ControlRequest),
+    /// Stream mode events
+    Events,
+    /// Validate a configuration file offline
+    ConfigCheck(PathBuf),
+    /// Replay a session log offline
+    Replay(PathBuf),
+    Help,
+    Version,
+}
+
+/// A parsed command line
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cli {
+    pub socket: PathBuf,
+    pub json: bool,
+    pub command: Command,
+}
+
+impl Cli {
+    /// Parse the arguments after the program name
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut socket = None;
+        let mut json = false;
+        let mut words = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-s" | "--socket" => {
+                    socket = Some(PathBuf::from(
+                        args.next().ok_or_else(|| anyhow!("{} needs a path", arg))?,
+                    ))
+                }
+                "--json" => json = true,
+                "-h" | "--help" => return Ok(Self::with(Command::Help)),
+                "-V" | "--version" => return Ok(Self::with(Command::Version)),
+                _ => words.push(arg),
+            }
+        }
+
+        Ok(Self {
+            socket: socket.unwrap_or_else(ControlServer::default_socket_path),
+            json,
+            command: parse_command(&words)?,
+        })
+    }
+
+    fn with(command: Command) -> Self {
+        Self {
+            socket: ControlServer::default_socket_path(),
+            json: false,
+            command,
+        }
+    }
+}
+
+fn parse_command(words: &[String]) -> Result<Command> {
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+
+    let request = match words.as_slice() {
+        [] => bail!("Missing command"),
+        ["mode"] => ControlRequest::GetMode,
+        ["mode", "off"] => ControlRequest::DeactivateMode,
+        ["mode", mode] => ControlRequest::ActivateMode {
+            mode: parse_mode(mode)?,
+        },
+        ["move", rest @ ..] => parse_move(rest)?,
+        ["click", rest @ ..] => parse_click(rest)?,
+        ["position"] => ControlRequest::GetPosition,
+        ["mark", "set", name] => ControlRequest::SetMark {
+            name: name.to_string(),
+            position: None,
+        },
+        ["mark", "set", name, x, y] => ControlRequest::SetMark {
+            name: name.to_string(),
+            position: Some(Position::new(parse_number(x)?, parse_number(y)?)),
+        },
+        ["mark", "list"] => ControlRequest::ListMarks,
+        ["screens"] => ControlRequest::GetScreens,
+        ["reload"] => ControlRequest::ReloadConfig,
+        ["events"] => return Ok(Command::Events),
+        ["config", "check", path] => return Ok(Command::ConfigCheck(PathBuf::from(path))),
+        ["replay", path] => return Ok(Command::Replay(PathBuf::from(path))),
+        _ => bail!("Unknown command: {}", words.join(" ")),
+    };
+
+    Ok(Command::Request(request))
+}
+
+fn parse_move(args: &[&str]) -> Result<ControlRequest> {
+    let mut coordinates = Vec::new();
+    let mut mark = None;
+    let mut screen = None;
+    let mut animation = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .copied()
+                .ok_or_else(|| anyhow!("{} needs a value", arg))
+        };
+        match *arg {
+            "--mark" => mark = Some(value()?.to_string()),
+            "--screen" => {
+                let number = value()?;
+                screen = Some(
+                    number
+                        .parse::<u8>()
+                        .with_context(|| format!("Invalid screen number: {}", number))?,
+                );
+            }
+            "--anim" => animation = Some(parse_animation(value()?)?),
+            coordinate => coordinates.push(parse_number(coordinate)?),
+        }
+    }
+
+    let target = match (mark, coordinates.as_slice()) {
+        (Some(mark), []) => MoveTarget::Mark { mark },
+        (None, [x, y]) => MoveTarget::Position { x: *x, y: *y },
+        _ => bail!("move needs either X Y or --mark NAME"),
+    };
+
+    Ok(ControlRequest::MoveTo {
+        target,
+        screen,
+        animation,
+    })
+}
+
+fn parse_click(args: &[&str]) -> Result<ControlRequest> {
+    let mut button = MouseButton::Left;
+    let mut count = 1;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--count" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--count needs a value"))?;
+                count = value
+                    .parse()
+                    .with_context(|| format!("Invalid click count: {}", value))?;
+            }
+            name => button = parse_button(name)?,
+        }
+    }
+
+    Ok(ControlRequest::Click { button, count })
+}
+
+fn parse_number(value: &str) -> Result<i32> {
+    value
+        .parse()
+        .with_context(|| format!("Invalid coordinate: {}", value))
+}
+
+/// Parse a mode name, ignoring case
+pub fn parse_mode(name: &str) -> Result<InteractionMode> {
+    InteractionMode::from_name(name).ok_or_else(|| anyhow!("Unknown mode: {}", name))
+}
+
+/// Parse a mouse button name, ignoring case
+pub fn parse_button(name: &str) -> Result<MouseButton> {
+    match name.to_lowercase().as_str() {
+        "left" => Ok(MouseButton::Left),
+        "right" => Ok(MouseButton::Right),
+        "middle" => Ok(MouseButton::Middle),
+        _ => bail!("Unknown mouse button: {}", name),
+    }
+}
+
+/// Parse an animation name, ignoring case
+pub fn parse_animation(name: &str) -> Result<AnimationType> {
+    match name.to_lowercase().as_str() {
+        "instant" => Ok(AnimationType::Instant),
+        "linear" => Ok(AnimationType::Linear),
+        "smooth" => Ok(AnimationType::Smooth),
+        "bounce" => Ok(AnimationType::Bounce),
+        "elastic" => Ok(AnimationType::Elastic),
+        _ => bail!("Unknown animation: {}", name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Cli> {
+        Cli::parse(line.split_whitespace().map(String::from))
+    }
+
+    fn request(line: &str) -> ControlRequest {
+        match parse(line).unwrap().command {
+            Command::Request(request) => request,
+            command => panic!("expected a request, got {:?}", command),
+        }
+    }
+
+    #[test]
+    fn test_mode_commands() {
+        assert_eq!(request("mode"), ControlRequest::GetMode);
+        assert_eq!(request("mode off"), ControlRequest::DeactivateMode);
+        assert_eq!(
+            request("mode Grid"),
+            ControlRequest::ActivateMode {
+                mode: InteractionMode::Grid
+            }
+        );
+        assert_eq!(
+            request("mode ruler"),
+            ControlRequest::ActivateMode {
+                mode: InteractionMode::Custom("ruler".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_move_commands() {
+        assert_eq!(
+            request("move 100 200 --screen 2 --anim smooth"),
+            ControlRequest::MoveTo {
+                target: MoveTarget::Position { x: 100, y: 200 },
+                screen: Some(2),
+                animation: Some(AnimationType::Smooth),
+            }
+        );
+        assert_eq!(
+            request("move --mark editor"),
+            ControlRequest::MoveTo {
+                target: MoveTarget::Mark {
+                    mark: "editor".to_string()
+                },
+                screen: None,
+                animation: None,
+            }
+        );
+        assert!(parse("move 100").is_err());
+        assert!(parse("move 100 200 --mark editor").is_err());
+        assert!(parse("move 100 200 --anim wobble").is_err());
+    }
+
+    #[test]
+    fn test_click_commands() {
+        assert_eq!(
+            request("click right --count 2"),
+            ControlRequest::Click {
+                button: MouseButton::Right,
+                count: 2,
+            }
+        );
+        assert_eq!(
+            request("click"),
+            ControlRequest::Click {
+                button: MouseButton::Left,
+                count: 1,
+            }
+        );
+        assert!(parse("click thumb").is_err());
+    }
+
+    #[test]
+    fn test_other_commands() {
+        assert_eq!(
+            request("mark set editor 10 20"),
+            ControlRequest::SetMark {
+                name: "editor".to_string(),
+                position: Some(Position::new(10, 20)),
+            }
+        );
+        assert_eq!(request("screens"), ControlRequest::GetScreens);
+
+        let cli = parse("--socket /tmp/test.sock events --json").unwrap();
+        assert_eq!(cli.socket, PathBuf::from("/tmp/test.sock"));
+        assert!(cli.json);
+        assert_eq!(cli.command, Command::Events);
+
+        assert_eq!(
+            parse("config check /tmp/mouseless.toml").unwrap().command,
+            Command::ConfigCheck(PathBuf::from("/tmp/mouseless.toml"))
+        );
+        assert_eq!(
+            parse("replay /tmp/session.jsonl").unwrap().command,
+            Command::Replay(PathBuf::from("/tmp/session.jsonl"))
+        );
+        assert_eq!(parse("--help").unwrap().command, Command::Help);
+        assert!(parse("").is_err());
+        assert!(parse("teleport").is_err());
+    }
+}
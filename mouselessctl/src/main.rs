@@ -0,0 +1,147 @@
+//! Mouseless control client
+//!
+//! Sends requests to the control socket of a running mouseless daemon, e.g.
+//! from i3, sway or Hyprland key bindings, and checks configuration files
+//! and replays session logs without one.
+
+use std::process::ExitCode;
+
+use anyhow::{bail, Result};
+use mouseless_core::{
+    read_session_log, replay_session, AppInfo, ConfigManager, ControlClient, ControlRequest,
+    InteractionMode, Marks, Position, ReplayReport, ScreenBounds,
+};
+use serde_json::Value;
+
+mod cli;
+
+use cli::{Cli, Command, USAGE};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = match Cli::parse(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("mouselessctl: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        Command::Help => println!("{}", USAGE),
+        Command::Version => println!("mouselessctl {}", AppInfo::default().version),
+        Command::ConfigCheck(path) => {
+            ConfigManager::check(&path)?;
+            println!("{}: OK", path.display());
+        }
+        Command::Replay(path) => {
+            let records = read_session_log(&path)?;
+            let report = replay_session(&records).await?;
+            if cli.json {
+                println!("{}", serde_json::to_string(&report)?);
+            } else {
+                print_report(&report);
+            }
+            if !report.is_match() {
+                bail!(
+                    "{}: replay differs from the log in {} actions",
+                    path.display(),
+                    report.mismatches.len()
+                );
+            }
+        }
+        Command::Events => {
+            let mut client = ControlClient::connect(&cli.socket).await?;
+            client.call(&ControlRequest::Subscribe).await?;
+            loop {
+                let event = client.next_event().await?;
+                if cli.json {
+                    println!("{}", serde_json::to_string(&event)?);
+                } else {
+                    println!("{:?}", event);
+                }
+            }
+        }
+        Command::Request(request) => {
+            let mut client = ControlClient::connect(&cli.socket).await?;
+            let result = client.call(&request).await?;
+            if cli.json {
+                if !result.is_null() {
+                    println!("{}", result);
+                }
+            } else {
+                print_result(&request, result)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Print a replay summary and where it differs from the log
+fn print_report(report: &ReplayReport) {
+    println!(
+        "{} keys, {} actions recorded, {} replayed, {} mouse operations",
+        report.keys,
+        report.recorded.len(),
+        report.replayed.len(),
+        report.mouse_ops.len()
+    );
+    for mismatch in &report.mismatches {
+        println!(
+            "#{} at {}ms: expected {:?}, got {:?}",
+            mismatch.index, mismatch.t, mismatch.expected, mismatch.actual
+        );
+    }
+}
+
+/// Print the result of a query; other requests print nothing on success
+fn print_result(request: &ControlRequest, result: Value) -> Result<()> {
+    match request {
+        ControlRequest::GetMode => {
+            let mode: Option<InteractionMode> = serde_json::from_value(result["mode"].clone())?;
+            match mode {
+                Some(mode) => println!("{}", mode.name()),
+                None => println!("inactive"),
+            }
+        }
+        ControlRequest::GetPosition => {
+            let position: Position = serde_json::from_value(result)?;
+            println!("{} {}", position.x, position.y);
+        }
+        ControlRequest::ListMarks => {
+            let marks: Marks = serde_json::from_value(result)?;
+            for (name, position) in marks.iter() {
+                println!("{} {} {}", name, position.x, position.y);
+            }
+        }
+        ControlRequest::GetScreens => {
+            let screens: Vec<ScreenBounds> = serde_json::from_value(result)?;
+            for (index, screen) in screens.iter().enumerate() {
+                println!(
+                    "{}: {}x{}+{}+{} scale {}{}",
+                    index + 1,
+                    screen.width,
+                    screen.height,
+                    screen.x,
+                    screen.y,
+                    screen.scale_factor,
+                    if screen.is_primary { " primary" } else { "" }
+                );
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use mouseless_core::{GridManager, GridConfig, ScreenBounds, AnimationType};
+use mouseless_core::{GridManager, GridConfig, LabelPriority, ScreenBounds, AnimationType};
 
 fn main() {
     println!("Testing Grid Key Combinations");
@@ -20,6 +20,7 @@ fn main() {
         width: 1920,
         height: 1080,
         is_primary: true,
+        scale_factor: 1.0,
     };
     
     for (rows, columns, description) in test_cases {
@@ -34,6 +35,8 @@ fn main() {
             cell_padding: 2,
             border_width: 1,
             opacity: 0.8,
+            label_alphabet: None,
+            label_priority: LabelPriority::RowMajor,
         };
         
         match GridManager::new(config, screen_bounds.clone()) {